@@ -8,7 +8,7 @@
 
 use quarry::{
     cache_stats, clear_stdlib_cache, init_stdlib_cache, is_stdlib_struct, list_stdlib_structs,
-    mine_struct_info,
+    list_structs_under, mine_struct_info, structs_by_crate,
 };
 use std::collections::HashMap;
 use std::time::Instant;
@@ -97,8 +97,8 @@ fn bulk_analysis_demo() -> Result<(), Box<dyn std::error::Error>> {
     println!("🔄 Bulk Analysis Demo");
     println!("====================\n");
 
-    println!("ℹ️  Note: Some types may fail because they are enums (not yet supported)");
-    println!("   Enum support is planned for future releases.\n");
+    println!("ℹ️  Note: Some types may fail because they are enums");
+    println!("   Use mine_enum_info to analyze those.\n");
 
     // Define types to analyze across different crates
     let types_to_analyze = vec![
@@ -107,7 +107,7 @@ fn bulk_analysis_demo() -> Result<(), Box<dyn std::error::Error>> {
             "Core Types",
             vec![
                 "core::mem::manually_drop::ManuallyDrop",
-                "core::marker::PhantomData", 
+                "core::marker::PhantomData",
                 "core::time::Duration",
                 "core::ptr::non_null::NonNull",
             ],
@@ -185,7 +185,7 @@ fn bulk_analysis_demo() -> Result<(), Box<dyn std::error::Error>> {
             .iter()
             .map(|(name, info)| (name, info.fields.len()))
             .collect();
-        field_counts.sort_by(|a, b| b.1.cmp(&a.1));
+        field_counts.sort_by_key(|b| std::cmp::Reverse(b.1));
 
         println!("\n🏆 Types with most fields:");
         for (name, count) in field_counts.iter().take(3) {
@@ -213,27 +213,21 @@ fn type_discovery_demo() -> Result<(), Box<dyn std::error::Error>> {
     );
 
     // Categorize by crate
-    let mut crate_counts = HashMap::new();
-    for struct_name in &all_structs {
-        if let Some(crate_name) = struct_name.split("::").next() {
-            *crate_counts.entry(crate_name.to_string()).or_insert(0) += 1;
-        }
-    }
+    let crate_groups = structs_by_crate()?;
 
     println!("\n📊 Types by crate:");
-    let mut sorted_crates: Vec<_> = crate_counts.iter().collect();
-    sorted_crates.sort_by(|a, b| b.1.cmp(a.1));
+    let mut sorted_crates: Vec<_> = crate_groups.iter().collect();
+    sorted_crates.sort_by_key(|(_, structs)| std::cmp::Reverse(structs.len()));
 
-    for (crate_name, count) in sorted_crates {
-        println!("   • {}: {} types", crate_name, count);
+    for (crate_name, structs) in sorted_crates {
+        println!("   • {}: {} types", crate_name, structs.len());
     }
 
     // Show some examples from each major crate
     println!("\n📝 Sample types from each crate:");
     for crate_name in ["std", "alloc", "core"] {
-        let examples: Vec<_> = all_structs
-            .iter()
-            .filter(|s| s.starts_with(&format!("{}::", crate_name)))
+        let examples: Vec<_> = list_structs_under(crate_name)?
+            .into_iter()
             .take(3)
             .collect();
 
@@ -75,23 +75,14 @@ fn analyze_struct(struct_name: &str) -> Result<(), QuarryError> {
             if !info.fields.is_empty() {
                 println!("    Field details:");
                 for (i, field) in info.fields.iter().enumerate() {
-                    let visibility = if field.is_public {
-                        "🌐 public"
-                    } else {
-                        "🔒 private"
-                    };
-                    println!(
-                        "    {}. {} : {} ({})",
-                        i + 1,
-                        field.name,
-                        field.type_name,
-                        visibility
-                    );
+                    println!("    {}. {}", i + 1, field);
                 }
-            } else {
+            } else if info.is_opaque() {
                 println!(
                     "    No fields accessible (may be opaque or have complex internal structure)"
                 );
+            } else {
+                println!("    No fields declared");
             }
         }
         Err(e) => {
@@ -108,11 +99,11 @@ fn demonstrate_error_handling() {
     println!("Trying to analyze invalid struct names...\n");
 
     let invalid_names = vec![
-        "String",                   // Missing full path
-        "Vec",                      // Missing full path
-        "core::option::Option",     // This is an enum, not a struct
-        "NonExistent",              // Doesn't exist
-        "my::custom::Type",         // Not a stdlib type
+        "String",               // Missing full path
+        "Vec",                  // Missing full path
+        "core::option::Option", // This is an enum, not a struct
+        "NonExistent",          // Doesn't exist
+        "my::custom::Type",     // Not a stdlib type
     ];
 
     for name in invalid_names {
@@ -125,9 +116,15 @@ fn demonstrate_error_handling() {
                     if !name.contains("::") {
                         println!("    💡 Tip: Use full module path like 'alloc::string::String'");
                     } else if name.contains("Option") {
-                        println!("    💡 Note: Option is an enum, not a struct. Enum support is planned for future releases.");
+                        println!(
+                            "    💡 Note: Option is an enum, not a struct. Use mine_enum_info instead."
+                        );
                     }
                 }
+                QuarryError::Ambiguous { candidates, .. } => {
+                    println!("    ❌ Not a full path (expected)");
+                    println!("    💡 Candidates: {}", candidates.join(", "));
+                }
                 other => println!("    ❌ Other error: {}", other),
             },
         }
@@ -0,0 +1,136 @@
+//! Command-line interface for Quarry
+//!
+//! A thin wrapper around the library for ad-hoc exploration from the shell:
+//! - `quarry info <path>` prints a struct and its fields
+//! - `quarry list [--crate <name>]` lists the standard library structs
+//! - `quarry check` reports whether the toolchain requirements are met
+//!
+//! `quarry <path>` is shorthand for `quarry info <path>`, and `-v` enables
+//! debug logging. Build it with `cargo install quarry --features cli`.
+
+use quarry::{check_requirements, list_stdlib_structs, mine_struct_info, structs_by_crate};
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+Usage: quarry [-v] <command>
+
+Commands:
+    info <path>              Show the fields of a struct (e.g. alloc::string::String)
+    list [--crate <name>]    List standard library structs, optionally from one crate
+    check                    Check that the toolchain and rust-src are installed
+    <path>                   Shorthand for `info <path>`
+
+Options:
+    -v, --verbose            Print Quarry's debug logs
+    -h, --help               Print this help";
+
+/// A parsed command line
+enum Command {
+    Info(String),
+    List { crate_name: Option<String> },
+    Check,
+    Help,
+}
+
+fn main() -> ExitCode {
+    let mut verbose = false;
+    let args: Vec<String> = std::env::args()
+        .skip(1)
+        .filter(|arg| {
+            let is_verbose = arg == "-v" || arg == "--verbose";
+            verbose |= is_verbose;
+            !is_verbose
+        })
+        .collect();
+
+    // RUST_LOG still applies without -v; -v only changes the default filter
+    let default_filter = if verbose { "quarry=debug" } else { "warn" };
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or(default_filter))
+        .init();
+
+    let command = match parse_args(&args) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("error: {}\n\n{}", message, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    match run(command) {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Turn the arguments after the program name (minus `-v`) into a [`Command`]
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    let Some((first, rest)) = args.split_first() else {
+        return Ok(Command::Help);
+    };
+
+    match first.as_str() {
+        "-h" | "--help" | "help" => Ok(Command::Help),
+        "info" => match rest {
+            [path] => Ok(Command::Info(path.clone())),
+            [] => Err("`info` needs a struct path".to_string()),
+            _ => Err("`info` takes exactly one struct path".to_string()),
+        },
+        "list" => match rest {
+            [] => Ok(Command::List { crate_name: None }),
+            [flag, name] if flag == "--crate" => Ok(Command::List {
+                crate_name: Some(name.clone()),
+            }),
+            _ => Err("`list` only accepts `--crate <name>`".to_string()),
+        },
+        "check" if rest.is_empty() => Ok(Command::Check),
+        "check" => Err("`check` takes no arguments".to_string()),
+        path if path.contains("::") && rest.is_empty() => Ok(Command::Info(path.to_string())),
+        other => Err(format!("unknown command `{}`", other)),
+    }
+}
+
+/// Execute a command, printing its output to stdout
+fn run(command: Command) -> quarry::Result<ExitCode> {
+    match command {
+        Command::Help => println!("{}", USAGE),
+        Command::Info(path) => println!("{}", mine_struct_info(&path)?),
+        Command::List { crate_name: None } => {
+            for name in list_stdlib_structs()? {
+                println!("{}", name);
+            }
+        }
+        Command::List {
+            crate_name: Some(crate_name),
+        } => {
+            let Some(names) = structs_by_crate()?.remove(&crate_name) else {
+                eprintln!("error: no structs found in crate `{}`", crate_name);
+                return Ok(ExitCode::FAILURE);
+            };
+            for name in names {
+                println!("{}", name);
+            }
+        }
+        Command::Check => {
+            let requirements = check_requirements()?;
+            let status = |ok: bool| if ok { "ok" } else { "missing" };
+            println!(
+                "nightly toolchain: {}",
+                status(requirements.nightly_installed)
+            );
+            println!(
+                "rust-src:          {}",
+                status(requirements.rust_src_installed)
+            );
+            if let Some(version) = &requirements.nightly_version {
+                println!("version:           {}", version);
+            }
+            if !(requirements.nightly_installed && requirements.rust_src_installed) {
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
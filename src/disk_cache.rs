@@ -0,0 +1,228 @@
+//! Persistent on-disk cache for parsed standard library types
+//!
+//! Generating rustdoc JSON for the standard library takes a long time, so the parsed
+//! `StdlibTypes` are serialized to a file under the user's cache directory and reloaded
+//! by later processes. Cache files are keyed by the rustc version so that a toolchain
+//! update automatically invalidates them. Each file also records the toolchain's commit
+//! hash in a header, which is compared against the live toolchain on load so that a
+//! `rustup update` that keeps the same version string still triggers regeneration.
+
+use crate::stdlib::StdlibTypes;
+use crate::{QuarryConfig, QuarryError, Result};
+use log::debug;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Environment variable that overrides the cache directory
+const CACHE_DIR_ENV: &str = "QUARRY_CACHE_DIR";
+
+/// Find the directory used for persistent cache files
+///
+/// Resolution order:
+/// 1. `$QUARRY_CACHE_DIR`
+/// 2. `$XDG_CACHE_HOME/quarry`
+/// 3. `$HOME/.cache/quarry` (or `%LOCALAPPDATA%\quarry` on Windows)
+/// 4. `<temp dir>/quarry_cache`
+pub(crate) fn cache_dir() -> PathBuf {
+    if let Some(dir) = std::env::var_os(CACHE_DIR_ENV) {
+        return PathBuf::from(dir);
+    }
+    if let Some(dir) = std::env::var_os("XDG_CACHE_HOME") {
+        return PathBuf::from(dir).join("quarry");
+    }
+    if let Some(dir) = std::env::var_os("LOCALAPPDATA") {
+        return PathBuf::from(dir).join("quarry");
+    }
+    if let Some(home) = std::env::var_os("HOME") {
+        return PathBuf::from(home).join(".cache").join("quarry");
+    }
+    std::env::temp_dir().join("quarry_cache")
+}
+
+/// Identification of the toolchain whose stdlib was analyzed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ToolchainVersion {
+    /// The first line of `rustc --version` (e.g., "rustc 1.90.0-nightly (abc123 2025-06-01)")
+    pub(crate) version: String,
+    /// The full commit hash reported by `rustc --version --verbose`
+    pub(crate) commit_hash: String,
+}
+
+/// On-disk layout of a cache file: a toolchain header followed by the parsed types
+#[derive(Serialize)]
+struct CacheFileRef<'a> {
+    commit_hash: &'a str,
+    types: &'a StdlibTypes,
+}
+
+/// Owned counterpart of `CacheFileRef` used when loading
+#[derive(Deserialize)]
+struct CacheFile {
+    commit_hash: String,
+    types: StdlibTypes,
+}
+
+/// Query a toolchain's version and commit hash
+///
+/// Runs `rustc +<toolchain> --version --verbose`, whose output looks like:
+///
+/// ```text
+/// rustc 1.90.0-nightly (abc1234 2025-06-01)
+/// binary: rustc
+/// commit-hash: abc1234def5678...
+/// commit-date: 2025-06-01
+/// ...
+/// ```
+///
+/// Returns `None` if the toolchain cannot be queried, in which case the disk cache is skipped.
+pub(crate) fn toolchain_version(toolchain: &str) -> Option<ToolchainVersion> {
+    let output = std::process::Command::new("rustc")
+        .args([&format!("+{}", toolchain), "--version", "--verbose"])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        debug!(
+            "Failed to query rustc +{} version for disk cache key",
+            toolchain
+        );
+        return None;
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let version = stdout.lines().next()?.trim().to_string();
+    let commit_hash = stdout
+        .lines()
+        .find_map(|line| line.strip_prefix("commit-hash:"))
+        .map(|hash| hash.trim().to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+
+    debug!(
+        "rustc +{} version: {} (commit {})",
+        toolchain, version, commit_hash
+    );
+    Some(ToolchainVersion {
+        version,
+        commit_hash,
+    })
+}
+
+/// Build the cache file path for a rustc version string
+///
+/// Configurations that differ from the default get their own file, suffixed
+/// with `QuarryConfig::variant_suffix`: the target triple, the documented crate
+/// names when they are not the default set, and `public` when private items
+/// are excluded. Characters that are not safe in file names are replaced with
+/// `_`.
+fn cache_file_for_version(version: &str, config: &QuarryConfig) -> PathBuf {
+    let version = match config.variant_suffix() {
+        Some(suffix) => format!("{}-{}", version, suffix),
+        None => version.to_string(),
+    };
+    let key: String = version
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '.' || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    cache_dir().join(format!("stdlib-{}.json", key))
+}
+
+/// Load previously parsed types for the current toolchain, if a cache file exists
+///
+/// The commit hash stored in the file header must match the live toolchain;
+/// otherwise the file is stale and treated as a cache miss. Any problem reading
+/// or decoding the file is likewise logged and treated as a miss.
+pub(crate) fn load(config: &QuarryConfig) -> Option<StdlibTypes> {
+    let toolchain = toolchain_version(&config.toolchain)?;
+    let path = cache_file_for_version(&toolchain.version, config);
+    debug!("Looking for disk cache at: {:?}", path);
+
+    let content = match std::fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(e) => {
+            debug!("No usable disk cache: {}", e);
+            return None;
+        }
+    };
+
+    match serde_json::from_str::<CacheFile>(&content) {
+        Ok(file) if file.commit_hash != toolchain.commit_hash => {
+            debug!(
+                "Disk cache is stale (cached commit {}, live commit {}), regenerating",
+                file.commit_hash, toolchain.commit_hash
+            );
+            None
+        }
+        Ok(file) => {
+            debug!("Loaded {} types from disk cache", file.types.len());
+            Some(file.types)
+        }
+        Err(e) => {
+            debug!("Failed to decode disk cache, ignoring it: {}", e);
+            None
+        }
+    }
+}
+
+/// Save parsed types to the cache file for the current toolchain
+///
+/// Failing to write the cache is not fatal to the caller; errors are returned so they can be logged.
+pub(crate) fn store(config: &QuarryConfig, types: &StdlibTypes) -> Result<()> {
+    let Some(toolchain) = toolchain_version(&config.toolchain) else {
+        debug!("Skipping disk cache write: rustc version unavailable");
+        return Ok(());
+    };
+    let path = cache_file_for_version(&toolchain.version, config);
+    debug!("Writing {} types to disk cache at: {:?}", types.len(), path);
+
+    std::fs::create_dir_all(cache_dir()).map_err(QuarryError::Io)?;
+    let file = CacheFileRef {
+        commit_hash: &toolchain.commit_hash,
+        types,
+    };
+    let content = serde_json::to_string(&file)
+        .map_err(|e| QuarryError::Io(std::io::Error::new(std::io::ErrorKind::InvalidData, e)))?;
+    std::fs::write(&path, content).map_err(QuarryError::Io)?;
+
+    debug!("Disk cache written successfully");
+    Ok(())
+}
+
+/// Remove every persistent cache file written by Quarry
+///
+/// Only `stdlib-*.json` files are removed, so pointing `QUARRY_CACHE_DIR` at a
+/// shared directory is safe.
+pub(crate) fn clear() -> Result<()> {
+    let dir = cache_dir();
+    debug!("Clearing disk cache at: {:?}", dir);
+
+    let entries = match std::fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            debug!("Disk cache directory does not exist, nothing to clear");
+            return Ok(());
+        }
+        Err(e) => return Err(QuarryError::Io(e)),
+    };
+
+    let mut removed = 0;
+    for entry in entries {
+        let path = entry.map_err(QuarryError::Io)?.path();
+        let is_cache_file = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .is_some_and(|n| n.starts_with("stdlib-") && n.ends_with(".json"));
+        if is_cache_file {
+            std::fs::remove_file(&path).map_err(QuarryError::Io)?;
+            removed += 1;
+        }
+    }
+
+    debug!("Removed {} disk cache files", removed);
+    Ok(())
+}
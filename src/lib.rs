@@ -6,11 +6,11 @@
 //!
 //! ## Scope and Limitations
 //!
-//! **Current Focus**: Quarry currently analyzes **structs** only. Popular types like `Option<T>` 
-//! and `Result<T, E>` (which are enums) cannot be analyzed yet.
+//! **Current Focus**: Quarry analyzes **structs** (via `mine_struct_info`) and **enums**
+//! (via `mine_enum_info`), so popular types like `Option<T>` and `Result<T, E>` can be
+//! inspected variant by variant.
 //!
-//! **Planned Features**: Support for enums, traits, and other types is planned for future releases. 
-//! If you need enum analysis immediately, consider using `rustdoc` directly.
+//! **Planned Features**: Support for traits and other types is planned for future releases.
 //!
 //! ## Requirements
 //!
@@ -38,7 +38,7 @@
 //! // Access field information (including private fields)
 //! for field in result.fields {
 //!     println!("  Field: {} -> {} (public: {})",
-//!              field.name, field.type_name, field.is_public);
+//!              field.name, field.type_name, field.is_public());
 //! }
 //!
 //! // List all available types
@@ -60,10 +60,8 @@
 //!
 //! 2. **Initialize the logger in your code**:
 //!    ```rust,no_run
-//!    fn main() {
-//!        env_logger::init();
-//!        // ... your code using quarry
-//!    }
+//!    env_logger::init();
+//!    // ... your code using quarry
 //!    ```
 //!
 //! 3. **Run with debug environment variables**:
@@ -75,9 +73,14 @@
 
 use log::debug;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::Arc;
 use thiserror::Error;
 
+mod disk_cache;
+mod rustdoc_json;
 pub mod stdlib;
+mod struct_store;
 
 /// Errors that can occur when mining standard library type information
 #[derive(Debug, Error)]
@@ -85,231 +88,3577 @@ pub enum QuarryError {
     #[error("Type not found: {0}")]
     TypeNotFound(String),
 
-    #[error("Type is not a struct: {0}")]
-    NotAStruct(String),
-
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 
     #[error("Standard library analysis failed: {0}")]
     StdlibAnalysis(String),
+
+    /// The configured toolchain (the payload) is not installed
+    #[error("Toolchain {0} not found. Install it with: rustup toolchain install {0}")]
+    NightlyMissing(String),
+
+    /// The configured toolchain (the payload) lacks the rust-src component
+    #[error(
+        "Standard library source not found for toolchain {0}. Install it with: rustup component add rust-src --toolchain {0}"
+    )]
+    RustSrcMissing(String),
+
+    /// `cargo doc` exited unsuccessfully while generating rustdoc JSON
+    ///
+    /// `status` is the exit code, or `None` if the process was terminated by a
+    /// signal; `stderr` is the complete compiler output.
+    #[error(
+        "cargo doc failed (exit code {}): {stderr}",
+        .status.map_or_else(|| "none".to_string(), |code| code.to_string())
+    )]
+    RustdocFailed { status: Option<i32>, stderr: String },
+
+    /// [`QuarryConfig::offline`] is set and no cached analysis exists for the
+    /// configured toolchain (the payload)
+    #[error(
+        "No cached standard library analysis for toolchain {0} and offline mode forbids running cargo doc. Pre-seed the cache with import_cache_from_json, or run once online to populate the disk cache"
+    )]
+    OfflineCacheUnavailable(String),
+
+    /// A [`QuarryConfig`] field holds a value Quarry cannot use
+    #[error("Invalid configuration: {0}")]
+    InvalidConfig(String),
+
+    /// The rustdoc JSON uses a format version Quarry cannot parse
+    ///
+    /// `found` is the document's `format_version`; `supported` is
+    /// [`SUPPORTED_RUSTDOC_FORMAT_VERSIONS`].
+    #[error(
+        "Unsupported rustdoc JSON format version {found} (supported: {}..={}). Use a nightly toolchain whose rustdoc emits a supported format, or upgrade Quarry",
+        .supported.start(),
+        .supported.end()
+    )]
+    UnsupportedRustdocFormat {
+        found: u32,
+        supported: std::ops::RangeInclusive<u32>,
+    },
+
+    /// The input is not valid rustdoc JSON
+    ///
+    /// Either the text is not JSON at all or it lacks fields every supported
+    /// format version has. The payload pinpoints the line and column.
+    #[error("Malformed rustdoc JSON: {0}")]
+    MalformedRustdocJson(#[source] serde_json::Error),
+
+    /// A bare type name (no `::`) was given where a full path is required
+    ///
+    /// `candidates` holds the full paths of every cached type whose simple name
+    /// is `simple_name`, in sorted order, with std:: re-export paths preferred
+    /// over defining paths. It is never empty; a bare name matching nothing is
+    /// reported as [`QuarryError::TypeNotFound`] instead.
+    #[error(
+        "'{simple_name}' is not a full module path. Did you mean {}?",
+        .candidates.iter().map(|path| format!("'{}'", path)).collect::<Vec<_>>().join(" or ")
+    )]
+    Ambiguous {
+        simple_name: String,
+        candidates: Vec<String>,
+    },
+}
+
+pub type Result<T> = std::result::Result<T, QuarryError>;
+
+/// The rustdoc JSON `format_version`s Quarry knows how to parse
+///
+/// JSON outside this range is rejected with
+/// [`QuarryError::UnsupportedRustdocFormat`] rather than parsed into empty or
+/// wrong results.
+pub const SUPPORTED_RUSTDOC_FORMAT_VERSIONS: std::ops::RangeInclusive<u32> = 25..=57;
+
+/// Configuration for how Quarry analyzes the standard library
+///
+/// The default configuration uses the `nightly` toolchain. Construct a custom
+/// configuration with struct update syntax:
+///
+/// ```rust
+/// use quarry::QuarryConfig;
+///
+/// let config = QuarryConfig {
+///     toolchain: "nightly-2024-05-01".to_string(),
+///     ..Default::default()
+/// };
+/// assert_eq!(config.toolchain, "nightly-2024-05-01");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuarryConfig {
+    /// The rustup toolchain passed as `+<toolchain>` to `rustc` and `cargo`
+    ///
+    /// Must be a nightly toolchain, since rustdoc JSON output is unstable.
+    pub toolchain: String,
+
+    /// How long `cargo doc` may run before it is killed, or `None` to wait forever
+    ///
+    /// A timed-out run fails with [`QuarryError::RustdocFailed`]. Defaults to
+    /// ten minutes, well above the time a cold build of the std docs takes.
+    pub rustdoc_timeout: Option<std::time::Duration>,
+
+    /// Keep the temporary `cargo doc` output directory after parsing
+    ///
+    /// The directory holds several hundred megabytes of build output, so it is
+    /// removed once the JSON has been parsed unless this is set for debugging.
+    pub keep_temp: bool,
+
+    /// Parse rustdoc JSON left by an earlier run instead of running `cargo doc`
+    ///
+    /// After a successful build the stdlib JSON files are kept in the system
+    /// temp directory, stamped with the toolchain's commit hash. While this is
+    /// set (the default), a later cold start of the same toolchain parses them
+    /// directly. Clear it to force a fresh `cargo doc` run.
+    pub reuse_rustdoc_output: bool,
+
+    /// Never run `cargo doc`; fail instead when no cached analysis is available
+    ///
+    /// For sandboxed machines that cannot spawn `cargo`. The in-memory cache
+    /// (e.g. seeded with [`import_cache_from_json`]), the disk cache and reused
+    /// rustdoc JSON are still consulted; if none of them has the stdlib,
+    /// initialization fails with [`QuarryError::OfflineCacheUnavailable`].
+    pub offline: bool,
+
+    /// The standard library crates to document and parse
+    ///
+    /// Each entry must be one of `std`, `alloc`, `core` or `proc_macro`.
+    /// Defaults to the first three; documenting only `core` is considerably
+    /// faster for embedded users, and adding `proc_macro` covers types such as
+    /// `proc_macro::Span`. Any other value, or an empty list, fails
+    /// initialization with [`QuarryError::InvalidConfig`].
+    pub crates: Vec<String>,
+
+    /// The target triple passed to `cargo doc` as `--target`, or `None` for the host
+    ///
+    /// Set this to inspect layouts for a cross-compilation target such as
+    /// `thumbv7em-none-eabihf`, where cfg-gated fields differ from the host.
+    /// The toolchain must have the target's standard library sources, which
+    /// rust-src provides for every target.
+    pub target: Option<String>,
+
+    /// Include private items (pass `--document-private-items` to `cargo doc`)
+    ///
+    /// Defaults to `true`, which is what exposes private fields and internal
+    /// structs such as `RawVec`. Clear it to analyze only the public API
+    /// surface, which gives a smaller cache and a faster first run.
+    pub document_private_items: bool,
+}
+
+impl Default for QuarryConfig {
+    fn default() -> Self {
+        Self {
+            toolchain: "nightly".to_string(),
+            rustdoc_timeout: Some(std::time::Duration::from_secs(600)),
+            keep_temp: false,
+            reuse_rustdoc_output: true,
+            offline: false,
+            crates: stdlib::DEFAULT_STDLIB_CRATES
+                .iter()
+                .map(|name| name.to_string())
+                .collect(),
+            target: None,
+            document_private_items: true,
+        }
+    }
+}
+
+impl QuarryConfig {
+    /// Key identifying the analysis results this configuration produces
+    ///
+    /// Two configurations with the same key share cached results.
+    pub(crate) fn cache_key(&self) -> String {
+        match self.variant_suffix() {
+            Some(suffix) => format!("{}-{}", self.toolchain, suffix),
+            None => self.toolchain.clone(),
+        }
+    }
+
+    /// The requested crates in the fixed std, alloc, core, proc_macro order, without duplicates
+    pub(crate) fn documented_crates(&self) -> Vec<&'static str> {
+        stdlib::STDLIB_CRATES
+            .into_iter()
+            .filter(|name| self.crates.iter().any(|requested| requested == name))
+            .collect()
+    }
+
+    /// A key suffix naming the target triple, the documented crates and
+    /// whether private items are excluded
+    ///
+    /// Each part is only included when it differs from the default (the host,
+    /// std, alloc and core, and private items included), so `None` keeps the
+    /// default configuration's cache keys and file names unchanged.
+    pub(crate) fn variant_suffix(&self) -> Option<String> {
+        let mut parts = Vec::new();
+        if let Some(target) = &self.target {
+            parts.push(target.clone());
+        }
+        let crates = self.documented_crates();
+        if crates != stdlib::DEFAULT_STDLIB_CRATES {
+            parts.push(crates.join("-"));
+        }
+        if !self.document_private_items {
+            parts.push("public".to_string());
+        }
+        (!parts.is_empty()).then(|| parts.join("-"))
+    }
+}
+
+/// What [`check_requirements`] found about the environment
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Requirements {
+    /// Whether the nightly toolchain is installed
+    pub nightly_installed: bool,
+    /// Whether the nightly toolchain has the rust-src component
+    pub rust_src_installed: bool,
+    /// The nightly `rustc --version` line (e.g., "rustc 1.90.0-nightly (abc1234 2025-06-01)"),
+    /// or `None` if it could not be queried
+    pub nightly_version: Option<String>,
+}
+
+/// Aggregate numbers describing the cached standard library structs
+///
+/// Returned by [`cache_statistics`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CacheStatistics {
+    /// The total number of cached structs
+    pub total_structs: usize,
+    /// How many of them are tuple structs
+    pub tuple_structs: usize,
+    /// How many of them are unit structs
+    pub unit_structs: usize,
+    /// How many of them have named fields
+    pub named_structs: usize,
+    /// The mean number of fields per struct (0.0 for an empty cache)
+    pub average_fields: f64,
+    /// The largest number of fields on any single struct
+    pub max_fields: usize,
+    /// The full path of a struct with `max_fields` fields (the alphabetically
+    /// first on ties), or `None` for an empty cache
+    pub largest_struct: Option<String>,
+    /// The most frequent field `type_name`s with their counts, most common first
+    pub most_common_field_types: Vec<(String, usize)>,
+}
+
+/// How [`search_structs`] matches its query
+///
+/// The defaults search simple names case-insensitively, which suits
+/// interactive exploration.
+///
+/// # Examples
+///
+/// ```rust
+/// use quarry::SearchOptions;
+///
+/// let options = SearchOptions {
+///     match_full_path: true,
+///     ..Default::default()
+/// };
+/// assert!(!options.case_sensitive);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SearchOptions {
+    /// Compare letters exactly instead of ignoring case
+    pub case_sensitive: bool,
+    /// Match the query against the full path (e.g., "alloc::vec::Vec")
+    /// instead of only the simple name ("Vec")
+    pub match_full_path: bool,
+}
+
+/// How [`list_stdlib_structs_sorted`] orders its results
+///
+/// Every order falls back to the full path for ties, so the result is
+/// deterministic.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum SortOrder {
+    /// Alphabetically by full path, as [`list_stdlib_structs`] sorts
+    #[default]
+    ByPath,
+    /// By crate (the first path segment), then by simple name
+    ByCrateThenName,
+    /// Structs with the most fields first
+    ByFieldCountDesc,
+    /// By simple name, so same-named structs from different modules are adjacent
+    BySimpleName,
+}
+
+/// A generic parameter declared by a struct, such as `'a`, `T` or `const N: usize`
+///
+/// Bounds written inline (`T: Clone`) are not repeated here; rustdoc folds
+/// them into the struct's `where_predicates`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct GenericParam {
+    /// The parameter name; lifetimes keep their leading `'` (e.g., "'a")
+    pub name: String,
+    /// Whether this is a lifetime, type or const parameter
+    pub kind: GenericParamKind,
+    /// The default, if declared: a type for type parameters (e.g., "Global"
+    /// for the `A` of `Vec<T, A = Global>`) or an expression for const
+    /// parameters. Always `None` for lifetimes, which cannot have one.
+    pub default: Option<String>,
+}
+
+/// The kind of a [`GenericParam`]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum GenericParamKind {
+    /// A lifetime parameter (`'a`)
+    Lifetime,
+    /// A type parameter (`T`)
+    Type,
+    /// A const parameter (`const N: usize`)
+    Const {
+        /// The type of the constant (e.g., "usize")
+        ty: String,
+    },
+}
+
+impl GenericParam {
+    /// Whether this is a lifetime parameter
+    pub fn is_lifetime(&self) -> bool {
+        self.kind == GenericParamKind::Lifetime
+    }
+}
+
+/// Renders the parameter as declared, e.g. `'a`, `T`, `A = Global` or
+/// `const N: usize = 3`
+impl std::fmt::Display for GenericParam {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.kind {
+            GenericParamKind::Lifetime | GenericParamKind::Type => f.write_str(&self.name)?,
+            GenericParamKind::Const { ty } => write!(f, "const {}: {}", self.name, ty)?,
+        }
+        match &self.default {
+            Some(default) => write!(f, " = {}", default),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Complete information about a struct
+///
+/// `StructInfo` implements `Hash`, so it can be used as a map key or hashed to
+/// detect layout changes between two toolchains.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct StructInfo {
+    /// The full name of the struct (e.g., "std::string::String")
+    pub name: String,
+    /// The path the struct is actually defined under (e.g.,
+    /// "alloc::string::String")
+    ///
+    /// Lookups through a `std::` alias report the requested alias as `name`;
+    /// this keeps the defining path. Equal to `name` otherwise.
+    pub canonical_name: String,
+    /// The simple name without module path (e.g., "String")
+    pub simple_name: String,
+    /// The module path (e.g., "std::string")
+    pub module_path: String,
+    /// List of fields in the struct
+    pub fields: Vec<FieldInfo>,
+    /// Whether the struct is a tuple struct
+    pub is_tuple_struct: bool,
+    /// Whether the struct is a unit struct
+    pub is_unit_struct: bool,
+    /// The declared generic parameters, lifetimes included, in declaration
+    /// order (e.g., `T` and `A` for `Vec<T, A>`)
+    pub generic_params: Vec<GenericParam>,
+    /// Where-clause predicates rendered as strings (e.g., "S: BuildHasher")
+    pub where_predicates: Vec<String>,
+    /// The visibility of the struct itself
+    pub visibility: Visibility,
+    /// The struct's doc comment, or `None` if it is undocumented
+    pub docs: Option<String>,
+    /// Where the struct is defined in the source, if rustdoc recorded it
+    pub span: Option<SourceSpan>,
+    /// `#[repr(...)]` hints such as "C", "transparent", "packed" or "align(8)";
+    /// empty for the default Rust representation
+    pub reprs: Vec<String>,
+    /// Traits implemented through `#[derive(...)]` (e.g., "Clone", "Debug")
+    pub derives: Vec<String>,
+    /// The functions of the struct's inherent `impl` blocks, in declaration
+    /// order; trait methods are not included
+    pub methods: Vec<MethodInfo>,
+    /// The associated constants of the struct's inherent `impl` blocks, in
+    /// declaration order
+    pub associated_constants: Vec<AssocConst>,
+    /// The traits the struct has an `impl` block for, derived ones included,
+    /// with their generic arguments (e.g., "Clone", "From<&str>")
+    ///
+    /// Auto traits (`Send`, `Sync`, ...) and blanket impls such as
+    /// `impl<T> From<T> for T` are left out, since every type gets them.
+    pub implemented_traits: Vec<String>,
+    /// Whether the struct is `#[non_exhaustive]`, which prevents construction
+    /// with a struct literal outside its defining crate
+    pub is_non_exhaustive: bool,
+    /// The struct's `#[stable]`/`#[unstable]` annotation, if any
+    pub stability: Option<Stability>,
+    /// The struct's `#[deprecated]` annotation, if any
+    pub deprecation: Option<Deprecation>,
+    /// The `#[cfg(...)]` predicate gating the struct (e.g., `unix` or
+    /// `any(unix, target_os = "wasi")`), or `None` if it is unconditional
+    pub cfg: Option<String>,
+}
+
+/// Information about a struct field
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct FieldInfo {
+    /// The name of the field
+    pub name: String,
+    /// The type of the field as a string
+    ///
+    /// Identical type names across the cache share one allocation, so cloning
+    /// this is cheap. It dereferences to `&str`.
+    pub type_name: Arc<str>,
+    /// The structured type of the field, or `None` if rustdoc used a shape Quarry doesn't model
+    pub ty: Option<Type>,
+    /// The visibility of the field
+    pub visibility: Visibility,
+    /// The name of the struct this field belongs to
+    pub struct_name: String,
+    /// The field's doc comment, or `None` if it is undocumented
+    pub docs: Option<String>,
+    /// The field's `#[stable]`/`#[unstable]` annotation, if any
+    pub stability: Option<Stability>,
+    /// The field's `#[deprecated]` annotation, if any
+    pub deprecation: Option<Deprecation>,
+    /// The `#[cfg(...)]` predicate gating the field, or `None` if it is unconditional
+    ///
+    /// A gated field only exists on matching platforms, which explains why it
+    /// can appear or disappear between analyses for different targets.
+    pub cfg: Option<String>,
+}
+
+/// A function defined in one of a struct's inherent `impl` blocks
+///
+/// Covers both methods (`fn len(&self)`) and associated functions without a
+/// `self` receiver (`fn new()`); the latter are flagged with
+/// [`is_associated_function`](Self::is_associated_function).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct MethodInfo {
+    /// The function name (e.g., "push_str")
+    pub name: String,
+    /// The visibility of the function
+    pub visibility: Visibility,
+    /// Whether the function is a `const fn`
+    pub is_const: bool,
+    /// Whether the function is an `unsafe fn`
+    pub is_unsafe: bool,
+    /// Whether the function is an `async fn`
+    pub is_async: bool,
+    /// Whether the function takes no `self` receiver, so it is called as
+    /// `Type::name(...)` (e.g., `String::new`)
+    pub is_associated_function: bool,
+}
+
+impl MethodInfo {
+    /// Whether the function is fully public (`pub`)
+    pub fn is_public(&self) -> bool {
+        self.visibility.is_public()
+    }
+}
+
+/// An associated constant defined in one of a struct's inherent `impl` blocks
+///
+/// For example `Duration::SECOND` in `impl Duration { pub const SECOND: Duration = ...; }`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct AssocConst {
+    /// The constant's name (e.g., "SECOND")
+    pub name: String,
+    /// The constant's type as a string (e.g., "Duration")
+    pub type_name: String,
+    /// The value expression as rustdoc recorded it (e.g., "5"), or `None` if it
+    /// was not recorded
+    ///
+    /// This is raw source text, not an evaluated value. rustdoc keeps literals
+    /// but abbreviates most other expressions, often down to `_`.
+    pub value: Option<String>,
+}
+
+/// The field-level differences between two versions of a struct
+///
+/// Produced by [`StructInfo::diff`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct StructDiff {
+    /// Fields present only in the newer version
+    pub added_fields: Vec<FieldInfo>,
+    /// Fields present only in the older version
+    pub removed_fields: Vec<FieldInfo>,
+    /// Fields whose `type_name` differs between the two versions
+    pub type_changes: Vec<FieldTypeChange>,
+    /// Fields whose visibility differs between the two versions
+    pub visibility_changes: Vec<FieldVisibilityChange>,
+}
+
+impl StructDiff {
+    /// Whether the two versions have identical fields
+    pub fn is_empty(&self) -> bool {
+        self.added_fields.is_empty()
+            && self.removed_fields.is_empty()
+            && self.type_changes.is_empty()
+            && self.visibility_changes.is_empty()
+    }
+}
+
+/// A field whose type changed between two versions of a struct
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FieldTypeChange {
+    /// The name of the field
+    pub name: String,
+    /// The field's type in the older version
+    pub old_type: String,
+    /// The field's type in the newer version
+    pub new_type: String,
+}
+
+/// A field whose visibility changed between two versions of a struct
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FieldVisibilityChange {
+    /// The name of the field
+    pub name: String,
+    /// The field's visibility in the older version
+    pub old_visibility: Visibility,
+    /// The field's visibility in the newer version
+    pub new_visibility: Visibility,
+}
+
+/// The structured form of a type as it appears in a field declaration
+///
+/// Its `Display` output is the same string stored in [`FieldInfo::type_name`].
+///
+/// # Examples
+///
+/// ```rust
+/// use quarry::Type;
+///
+/// let ty = Type::Path {
+///     name: "Vec".to_string(),
+///     args: vec![Type::Primitive("u8".to_string())],
+/// };
+/// assert_eq!(ty.to_string(), "Vec<u8>");
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum Type {
+    /// A named type with its generic arguments (e.g., `Vec<u8>`)
+    Path {
+        /// The path, with `crate::` stripped (e.g., "Vec" or "ptr::NonNull")
+        name: String,
+        /// Generic type and const arguments, in order
+        args: Vec<Type>,
+    },
+    /// A primitive type (e.g., `usize`, `str`)
+    Primitive(String),
+    /// A generic type parameter (e.g., `T`)
+    Generic(String),
+    /// A tuple type (e.g., `(i32, String)`); the unit type is an empty tuple
+    Tuple(Vec<Type>),
+    /// A reference (e.g., `&'a mut T`)
+    Reference {
+        /// The lifetime name including the leading `'`, if written
+        lifetime: Option<String>,
+        /// Whether this is a `&mut` reference
+        mutable: bool,
+        /// The referenced type
+        inner: Box<Type>,
+    },
+    /// A raw pointer (e.g., `*const T` or `*mut T`)
+    RawPointer {
+        /// Whether this is a `*mut` pointer
+        mutable: bool,
+        /// The pointee type
+        inner: Box<Type>,
+    },
+    /// A trait object (e.g., `dyn Error + Send + 'static`)
+    DynTrait {
+        /// The traits, each a `Type::Path`
+        traits: Vec<Type>,
+        /// The lifetime bound including the leading `'`, if written
+        lifetime: Option<String>,
+    },
+    /// An `impl Trait` type (e.g., `impl Iterator<Item = u8> + 'a`)
+    ImplTrait {
+        /// The trait bounds, each a `Type::Path`
+        traits: Vec<Type>,
+        /// The lifetime bound including the leading `'`, if written
+        lifetime: Option<String>,
+    },
+    /// An associated type projection (e.g., `<T as Iterator>::Item`)
+    QualifiedPath {
+        /// The type the associated item is projected from
+        self_type: Box<Type>,
+        /// The trait, a `Type::Path`; `None` for inherent associated types
+        trait_: Option<Box<Type>>,
+        /// The associated item's name
+        name: String,
+    },
+    /// A slice (e.g., `[u8]`)
+    Slice(Box<Type>),
+    /// A fixed-size array (e.g., `[u8; 16]`)
+    Array {
+        /// The element type
+        inner: Box<Type>,
+        /// The length expression as written (e.g., "16" or "N")
+        len: String,
+    },
+    /// A const generic argument (e.g., the `N` of `Foo<T, N>` or the `4` of
+    /// `Simd<f32, 4>`), as written or as evaluated by rustdoc
+    Const(String),
+}
+
+impl std::fmt::Display for Type {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Type::Path { name, args } => {
+                write!(f, "{}", name)?;
+                if !args.is_empty() {
+                    write!(f, "<{}>", join_types(args))?;
+                }
+                Ok(())
+            }
+            Type::Primitive(name) | Type::Generic(name) | Type::Const(name) => {
+                write!(f, "{}", name)
+            }
+            Type::Tuple(elements) => write!(f, "({})", join_types(elements)),
+            Type::Reference {
+                lifetime,
+                mutable,
+                inner,
+            } => {
+                write!(f, "&")?;
+                if let Some(lifetime) = lifetime {
+                    write!(f, "{} ", lifetime)?;
+                }
+                if *mutable {
+                    write!(f, "mut ")?;
+                }
+                write!(f, "{}", inner)
+            }
+            Type::RawPointer { mutable, inner } => {
+                let qualifier = if *mutable { "mut" } else { "const" };
+                write!(f, "*{} {}", qualifier, inner)
+            }
+            Type::DynTrait { traits, lifetime } => {
+                write!(f, "dyn {}", join_bounds(traits, lifetime.as_deref()))
+            }
+            Type::ImplTrait { traits, lifetime } => {
+                write!(f, "impl {}", join_bounds(traits, lifetime.as_deref()))
+            }
+            Type::QualifiedPath {
+                self_type,
+                trait_: Some(trait_),
+                name,
+            } => write!(f, "<{} as {}>::{}", self_type, trait_, name),
+            Type::QualifiedPath {
+                self_type,
+                trait_: None,
+                name,
+            } => write!(f, "{}::{}", self_type, name),
+            Type::Slice(inner) => write!(f, "[{}]", inner),
+            Type::Array { inner, len } => write!(f, "[{}; {}]", inner, len),
+        }
+    }
+}
+
+/// Render a list of types separated by `, `
+fn join_types(types: &[Type]) -> String {
+    types
+        .iter()
+        .map(Type::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Render trait bounds followed by an optional lifetime, separated by ` + `
+fn join_bounds(traits: &[Type], lifetime: Option<&str>) -> String {
+    traits
+        .iter()
+        .map(Type::to_string)
+        .chain(lifetime.map(str::to_string))
+        .collect::<Vec<_>>()
+        .join(" + ")
+}
+
+/// Best-effort JSON Schema node for a field type
+///
+/// Primitives map to their JSON types; strings, sequences, maps, `Option` and
+/// smart pointers map to the shape serde would produce. Other named types
+/// become `$ref`s into `$defs`, and types with no JSON meaning (generics,
+/// trait objects, projections) become `{}`, which accepts anything.
+fn type_schema(ty: &Type) -> serde_json::Value {
+    use serde_json::json;
+
+    match ty {
+        Type::Primitive(name) => match name.as_str() {
+            "bool" => json!({ "type": "boolean" }),
+            "f32" | "f64" => json!({ "type": "number" }),
+            "char" | "str" => json!({ "type": "string" }),
+            _ if name.starts_with('i') || name.starts_with('u') => json!({ "type": "integer" }),
+            _ => json!({}),
+        },
+        Type::Tuple(elements) if elements.is_empty() => json!({ "type": "null" }),
+        Type::Tuple(elements) => json!({
+            "type": "array",
+            "prefixItems": elements.iter().map(type_schema).collect::<Vec<_>>(),
+        }),
+        Type::Reference { inner, .. } | Type::RawPointer { inner, .. } => type_schema(inner),
+        Type::Slice(inner) | Type::Array { inner, .. } => {
+            json!({ "type": "array", "items": type_schema(inner) })
+        }
+        Type::Path { name, args } => {
+            let simple = name.rsplit("::").next().unwrap_or(name);
+            match (simple, args.as_slice()) {
+                ("String" | "PathBuf" | "OsString", _) => json!({ "type": "string" }),
+                ("Option", [inner]) => json!({ "anyOf": [type_schema(inner), { "type": "null" }] }),
+                ("Box" | "Rc" | "Arc" | "Cell" | "RefCell" | "Mutex" | "RwLock", [inner]) => {
+                    type_schema(inner)
+                }
+                ("Vec" | "VecDeque" | "LinkedList" | "BinaryHeap", [inner]) => {
+                    json!({ "type": "array", "items": type_schema(inner) })
+                }
+                ("HashSet" | "BTreeSet", [inner, ..]) => {
+                    json!({ "type": "array", "items": type_schema(inner), "uniqueItems": true })
+                }
+                ("HashMap" | "BTreeMap", [_, value, ..]) => {
+                    json!({ "type": "object", "additionalProperties": type_schema(value) })
+                }
+                _ => json!({ "$ref": format!("#/$defs/{}", name) }),
+            }
+        }
+        Type::Generic(_)
+        | Type::Const(_)
+        | Type::DynTrait { .. }
+        | Type::ImplTrait { .. }
+        | Type::QualifiedPath { .. } => json!({}),
+    }
+}
+
+/// TypeScript type for a field, as used by [`StructInfo::to_typescript`]
+///
+/// Primitives map to `number`, `boolean` or `string`, `String` and `str` to
+/// `string`, and references to their referent. Any other type falls back to
+/// its Rust rendering with every run of non-identifier characters replaced by
+/// `_` (`Vec<u8>` becomes `Vec_u8`).
+fn typescript_type(field: &FieldInfo) -> String {
+    fn mapped(ty: &Type) -> Option<&'static str> {
+        match ty {
+            Type::Primitive(name) => match name.as_str() {
+                "bool" => Some("boolean"),
+                "char" | "str" => Some("string"),
+                "f32" | "f64" => Some("number"),
+                _ if name.starts_with('i') || name.starts_with('u') => Some("number"),
+                _ => None,
+            },
+            Type::Path { name, args } if args.is_empty() => {
+                (name.rsplit("::").next() == Some("String")).then_some("string")
+            }
+            Type::Reference { inner, .. } => mapped(inner),
+            _ => None,
+        }
+    }
+
+    let rust_name = match &field.ty {
+        Some(ty) => {
+            if let Some(ts) = mapped(ty) {
+                return ts.to_string();
+            }
+            // References are transparent, so name the referent
+            let mut ty = ty;
+            while let Type::Reference { inner, .. } = ty {
+                ty = inner;
+            }
+            ty.to_string()
+        }
+        None => field.type_name.to_string(),
+    };
+
+    let mut sanitized = String::new();
+    for c in rust_name.chars() {
+        if c.is_ascii_alphanumeric() || c == '_' {
+            sanitized.push(c);
+        } else if !sanitized.is_empty() && !sanitized.ends_with('_') {
+            sanitized.push('_');
+        }
+    }
+    let sanitized = sanitized.trim_end_matches('_');
+    if sanitized.is_empty() {
+        "unknown".to_string()
+    } else {
+        sanitized.to_string()
+    }
+}
+
+/// A source location as recorded by rustdoc
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct SourceSpan {
+    /// The source file, relative to the library root (e.g., "alloc/src/string.rs")
+    pub filename: String,
+    /// The (line, column) where the item begins, both 1-based
+    pub begin: (u32, u32),
+    /// The (line, column) where the item ends, both 1-based
+    pub end: (u32, u32),
+}
+
+/// Visibility of an item as recorded by rustdoc
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum Visibility {
+    /// Visible everywhere (`pub`)
+    Public,
+    /// Visible within the defining crate (`pub(crate)`)
+    Crate,
+    /// Visible within the given module path (`pub(in path)` or `pub(super)`)
+    Restricted(String),
+    /// Private to the defining module (no visibility modifier)
+    Private,
+}
+
+impl Visibility {
+    /// Whether this is fully public (`pub`) visibility
+    pub fn is_public(&self) -> bool {
+        matches!(self, Visibility::Public)
+    }
+}
+
+impl std::fmt::Display for Visibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Visibility::Public => f.write_str("public"),
+            Visibility::Crate => f.write_str("crate"),
+            Visibility::Restricted(path) => write!(f, "restricted to {}", path),
+            Visibility::Private => f.write_str("private"),
+        }
+    }
+}
+
+/// A standard library stability annotation
+///
+/// Only crates built with the internal `staged_api` feature carry these, so
+/// outside of `std`, `alloc` and `core` this is normally absent.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct Stability {
+    /// Whether the item is `#[stable]` or `#[unstable]`
+    pub level: StabilityLevel,
+    /// The Rust version the item was stabilized in (e.g., "1.0.0"); `None` for unstable items
+    pub since: Option<String>,
+}
+
+/// A `#[deprecated]` annotation
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct Deprecation {
+    /// The version the item was deprecated in (e.g., "1.33.0"), if given
+    pub since: Option<String>,
+    /// The explanation or suggested replacement, if given
+    pub note: Option<String>,
+}
+
+/// Whether an item can be used on the stable toolchain
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum StabilityLevel {
+    /// Usable on the stable toolchain (`#[stable]`)
+    Stable,
+    /// Only usable on nightly behind a feature gate (`#[unstable]`)
+    Unstable,
+}
+
+/// Which cached structs reference which, built by [`build_dependency_graph`]
+///
+/// There is an edge from `A` to `B` when a field of struct `A` mentions struct
+/// `B` anywhere in its type, including inside generic arguments, references
+/// and arrays (e.g., a `Vec<B>` field). Nodes are full paths. A struct that
+/// refers to itself has an edge to itself.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DependencyGraph {
+    /// Each struct mapped to the structs its fields reference
+    dependencies: BTreeMap<String, BTreeSet<String>>,
+    /// Each struct mapped to the structs whose fields reference it
+    dependents: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl DependencyGraph {
+    /// Add a struct, with no edges, if it is not in the graph yet
+    pub(crate) fn add_node(&mut self, name: &str) {
+        self.dependencies.entry(name.to_string()).or_default();
+        self.dependents.entry(name.to_string()).or_default();
+    }
+
+    /// Record that a field of `from` references `to`
+    pub(crate) fn add_edge(&mut self, from: &str, to: &str) {
+        self.add_node(from);
+        self.add_node(to);
+        self.dependencies
+            .get_mut(from)
+            .unwrap()
+            .insert(to.to_string());
+        self.dependents
+            .get_mut(to)
+            .unwrap()
+            .insert(from.to_string());
+    }
+
+    /// The structs referenced by the fields of `name`, in sorted order
+    ///
+    /// Empty if `name` references no other struct or is not in the graph.
+    pub fn dependencies_of(&self, name: &str) -> Vec<&str> {
+        self.dependencies
+            .get(name)
+            .map(|set| set.iter().map(String::as_str).collect())
+            .unwrap_or_default()
+    }
+
+    /// The structs whose fields reference `name`, in sorted order
+    ///
+    /// Empty if nothing references `name` or it is not in the graph.
+    pub fn dependents_of(&self, name: &str) -> Vec<&str> {
+        self.dependents
+            .get(name)
+            .map(|set| set.iter().map(String::as_str).collect())
+            .unwrap_or_default()
+    }
+
+    /// Every struct in the graph, in sorted order
+    pub fn nodes(&self) -> impl Iterator<Item = &str> {
+        self.dependencies.keys().map(String::as_str)
+    }
+
+    /// Every edge as a `(from, to)` pair, sorted by `from` and then `to`
+    pub fn edges(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.dependencies
+            .iter()
+            .flat_map(|(from, tos)| tos.iter().map(move |to| (from.as_str(), to.as_str())))
+    }
+
+    /// Render the graph as a GraphViz DOT document
+    ///
+    /// Each struct becomes a node labeled with its simple name (the full path
+    /// is kept as a tooltip), and each reference a directed edge. Pipe the
+    /// output into `dot -Tsvg` to draw it. For the whole standard library this
+    /// is large; see [`to_dot_for_crate`](Self::to_dot_for_crate).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use quarry::build_dependency_graph;
+    ///
+    /// let dot = build_dependency_graph()?.to_dot();
+    /// std::fs::write("structs.dot", dot)?;
+    /// # Ok::<(), quarry::QuarryError>(())
+    /// ```
+    pub fn to_dot(&self) -> String {
+        self.render_dot(|_| true)
+    }
+
+    /// Render the part of the graph within one crate as a GraphViz DOT document
+    ///
+    /// Like [`to_dot`](Self::to_dot), but only structs whose path starts with
+    /// `crate_name` (e.g., "alloc") and the edges between them are included.
+    pub fn to_dot_for_crate(&self, crate_name: &str) -> String {
+        self.render_dot(|name| name.split("::").next() == Some(crate_name))
+    }
+
+    /// Render the nodes accepted by `include`, and the edges between them
+    fn render_dot(&self, include: impl Fn(&str) -> bool) -> String {
+        let node_id = stdlib::diagram_node_id;
+        let escape = |text: &str| text.replace('\\', "\\\\").replace('"', "\\\"");
+
+        let mut dot = String::from("digraph dependencies {\n");
+        for name in self.nodes().filter(|name| include(name)) {
+            let simple = name.rsplit("::").next().unwrap_or(name);
+            dot.push_str(&format!(
+                "    {} [label=\"{}\", tooltip=\"{}\"];\n",
+                node_id(name),
+                escape(simple),
+                escape(name)
+            ));
+        }
+        for (from, to) in self
+            .edges()
+            .filter(|(from, to)| include(from) && include(to))
+        {
+            dot.push_str(&format!("    {} -> {};\n", node_id(from), node_id(to)));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// A step of standard library cache initialization, reported by
+/// [`init_stdlib_cache_with_progress`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InitStage {
+    /// Asking the toolchain where the standard library sources live
+    LocatingSource,
+    /// Running `cargo doc` on the standard library (the slow part)
+    RunningCargoDoc,
+    /// Parsing the rustdoc JSON of the named crate (e.g., `std` or `core`)
+    ParsingCrate(String),
+    /// The cache is ready
+    Done,
+}
+
+/// The kind of a type definition
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum TypeKind {
+    /// A `struct`
+    Struct,
+    /// An `enum`
+    Enum,
+    /// A `union`
+    Union,
+    /// A `type` alias
+    TypeAlias,
+}
+
+impl std::fmt::Display for TypeKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            TypeKind::Struct => "struct",
+            TypeKind::Enum => "enum",
+            TypeKind::Union => "union",
+            TypeKind::TypeAlias => "type alias",
+        })
+    }
+}
+
+/// Information about a type definition of any kind
+///
+/// Returned by [`mine_type_info`] so callers can look up a path without knowing
+/// in advance whether it names a struct, enum, union or type alias.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum TypeInfo {
+    /// A struct definition
+    Struct(StructInfo),
+    /// An enum definition
+    Enum(EnumInfo),
+    /// A union definition
+    Union(UnionInfo),
+    /// A type alias definition
+    TypeAlias(AliasInfo),
+}
+
+impl TypeInfo {
+    /// The full name of the type (e.g., "core::option::Option")
+    pub fn name(&self) -> &str {
+        match self {
+            TypeInfo::Struct(info) => &info.name,
+            TypeInfo::Enum(info) => &info.name,
+            TypeInfo::Union(info) => &info.name,
+            TypeInfo::TypeAlias(info) => &info.name,
+        }
+    }
+
+    /// The kind of the type
+    pub fn kind(&self) -> TypeKind {
+        match self {
+            TypeInfo::Struct(_) => TypeKind::Struct,
+            TypeInfo::Enum(_) => TypeKind::Enum,
+            TypeInfo::Union(_) => TypeKind::Union,
+            TypeInfo::TypeAlias(_) => TypeKind::TypeAlias,
+        }
+    }
+}
+
+/// Complete information about a union
+///
+/// Mirrors [`StructInfo`], except that unions always have named fields.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct UnionInfo {
+    /// The full name of the union (e.g., "core::mem::manually_drop::ManuallyDrop")
+    pub name: String,
+    /// The simple name without module path
+    pub simple_name: String,
+    /// The module path
+    pub module_path: String,
+    /// List of fields in the union
+    pub fields: Vec<FieldInfo>,
+    /// Where-clause predicates rendered as strings (e.g., "T: Copy")
+    pub where_predicates: Vec<String>,
+    /// The visibility of the union itself
+    pub visibility: Visibility,
+    /// The union's doc comment, or `None` if it is undocumented
+    pub docs: Option<String>,
+    /// Where the union is defined in the source, if rustdoc recorded it
+    pub span: Option<SourceSpan>,
+    /// `#[repr(...)]` hints such as "C" or "transparent"; empty for the default representation
+    pub reprs: Vec<String>,
+    /// Traits implemented through `#[derive(...)]` (e.g., "Clone", "Copy")
+    pub derives: Vec<String>,
+    /// The union's `#[stable]`/`#[unstable]` annotation, if any
+    pub stability: Option<Stability>,
+    /// The union's `#[deprecated]` annotation, if any
+    pub deprecation: Option<Deprecation>,
+}
+
+/// Complete information about a type alias
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AliasInfo {
+    /// The full name of the alias (e.g., "std::io::error::Result")
+    pub name: String,
+    /// The simple name without module path (e.g., "Result")
+    pub simple_name: String,
+    /// The module path (e.g., "std::io::error")
+    pub module_path: String,
+    /// The aliased type as a string (e.g., "result::Result<T, Error>")
+    pub type_name: String,
+    /// The structured aliased type, or `None` if rustdoc used a shape Quarry doesn't model
+    pub ty: Option<Type>,
+    /// The full path of the aliased type's definition (e.g., "core::result::Result"),
+    /// or `None` if the alias does not name a path type
+    pub target: Option<String>,
+    /// The visibility of the alias
+    pub visibility: Visibility,
+    /// The alias's doc comment, or `None` if it is undocumented
+    pub docs: Option<String>,
+    /// Where the alias is defined in the source, if rustdoc recorded it
+    pub span: Option<SourceSpan>,
+}
+
+/// Complete information about an enum
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct EnumInfo {
+    /// The full name of the enum (e.g., "core::option::Option")
+    pub name: String,
+    /// The simple name without module path (e.g., "Option")
+    pub simple_name: String,
+    /// The module path (e.g., "core::option")
+    pub module_path: String,
+    /// List of variants in declaration order
+    pub variants: Vec<VariantInfo>,
+}
+
+/// Information about a single enum variant
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct VariantInfo {
+    /// The name of the variant (e.g., "Some")
+    pub name: String,
+    /// The shape of the variant
+    pub kind: VariantKind,
+    /// Fields of a tuple or struct variant (empty for unit variants)
+    pub fields: Vec<FieldInfo>,
+}
+
+/// The shape of an enum variant
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum VariantKind {
+    /// A variant without fields (e.g., `None`)
+    Unit,
+    /// A variant with positional fields (e.g., `Some(T)`)
+    Tuple,
+    /// A variant with named fields (e.g., `Foo { x: u32 }`)
+    Struct,
+}
+
+/// Split a full path into its module path and simple name
+fn split_path(name: &str) -> (String, String) {
+    if let Some(pos) = name.rfind("::") {
+        (name[..pos].to_string(), name[pos + 2..].to_string())
+    } else {
+        (String::new(), name.to_string())
+    }
+}
+
+impl StructInfo {
+    /// Create a new StructInfo with the given name and extract module path components
+    pub fn new(name: &str) -> Self {
+        let (module_path, simple_name) = split_path(name);
+
+        Self {
+            name: name.to_string(),
+            canonical_name: name.to_string(),
+            simple_name,
+            module_path,
+            fields: Vec::new(),
+            is_tuple_struct: false,
+            is_unit_struct: false,
+            generic_params: Vec::new(),
+            where_predicates: Vec::new(),
+            visibility: Visibility::Private,
+            docs: None,
+            span: None,
+            reprs: Vec::new(),
+            derives: Vec::new(),
+            methods: Vec::new(),
+            associated_constants: Vec::new(),
+            implemented_traits: Vec::new(),
+            is_non_exhaustive: false,
+            stability: None,
+            deprecation: None,
+            cfg: None,
+        }
+    }
+
+    /// Look up an inherent method or associated function by name
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use quarry::mine_struct_info;
+    ///
+    /// let info = mine_struct_info("alloc::string::String")?;
+    /// if let Some(new) = info.get_method("new") {
+    ///     assert!(new.is_const && new.is_associated_function);
+    /// }
+    /// # Ok::<(), quarry::QuarryError>(())
+    /// ```
+    pub fn get_method(&self, name: &str) -> Option<&MethodInfo> {
+        self.methods.iter().find(|method| method.name == name)
+    }
+
+    /// Whether the struct has an `impl` block for a trait
+    ///
+    /// `trait_name` is compared with [`implemented_traits`](Self::implemented_traits)
+    /// ignoring generic arguments, either as written there (e.g., "fmt::Display")
+    /// or by its last segment ("Display"), so `"From"` matches `From<&str>`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use quarry::mine_struct_info;
+    ///
+    /// let info = mine_struct_info("alloc::string::String")?;
+    /// assert!(info.implements("Clone"));
+    /// assert!(info.implements("Display"));
+    /// # Ok::<(), quarry::QuarryError>(())
+    /// ```
+    pub fn implements(&self, trait_name: &str) -> bool {
+        self.implemented_traits.iter().any(|implemented| {
+            let path = implemented.split('<').next().unwrap_or(implemented);
+            path == trait_name || path.rsplit("::").next() == Some(trait_name)
+        })
+    }
+
+    /// Whether the struct itself is fully public (`pub`)
+    ///
+    /// Useful for filtering out private implementation details such as `RawVec`.
+    pub fn is_public(&self) -> bool {
+        self.visibility.is_public()
+    }
+
+    /// Look up a field by name
+    ///
+    /// The match is exact and case-sensitive. Tuple struct fields are named by
+    /// position ("0", "1", ...).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use quarry::mine_struct_info;
+    ///
+    /// let info = mine_struct_info("alloc::string::String")?;
+    /// if let Some(field) = info.get_field("vec") {
+    ///     println!("String stores its bytes in a {}", field.type_name);
+    /// }
+    /// # Ok::<(), quarry::QuarryError>(())
+    /// ```
+    pub fn get_field(&self, name: &str) -> Option<&FieldInfo> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    /// Look up the struct that a field's type names
+    ///
+    /// The field's type is reduced to its base path (`Vec<u8>` becomes `Vec`),
+    /// which is resolved against the standard library cache the way rustdoc
+    /// wrote it: relative to this struct's module and crate, or through a
+    /// std:: re-export for full paths. This makes walking a struct's fields
+    /// recursively a matter of calling `resolve_field` again on the result.
+    ///
+    /// # Arguments
+    ///
+    /// * `field_name` - The name of the field (`"0"`, `"1"`, ... for tuple structs)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(StructInfo))` - The struct the field's type names
+    /// * `Ok(None)` - If the type is not a known struct, e.g. a primitive, a
+    ///   generic parameter, a reference or an enum
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use quarry::mine_struct_info;
+    ///
+    /// let string = mine_struct_info("alloc::string::String")?;
+    /// // String.vec is a Vec<u8>
+    /// let vec = string.resolve_field("vec")?.expect("Vec is a struct");
+    /// assert_eq!(vec.name, "alloc::vec::Vec");
+    /// # Ok::<(), quarry::QuarryError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `QuarryError::TypeNotFound` if the struct has no field named
+    /// `field_name`, or an error if the standard library cache cannot be
+    /// initialized.
+    pub fn resolve_field(&self, field_name: &str) -> Result<Option<StructInfo>> {
+        debug!("Resolving field '{}' of '{}'", field_name, self.name);
+        let field = self.get_field(field_name).ok_or_else(|| {
+            QuarryError::TypeNotFound(format!(
+                "Field '{}' not found in struct '{}'",
+                field_name, self.name
+            ))
+        })?;
+        stdlib::resolve_field_struct(self, field)
+    }
+
+    /// Check whether this struct transitively contains itself
+    ///
+    /// Field types are followed to the structs they name, including generic
+    /// arguments, so `Option<Box<Node<T>>>` reaches `Node`. The struct is
+    /// recursive when some chain of such references leads back to it. Each
+    /// struct on the way is visited once, so unrelated cycles deeper in the
+    /// standard library do not affect the result. References are resolved the
+    /// same way as in [`build_dependency_graph`].
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(true)` - If the struct reaches itself through its fields
+    /// * `Ok(false)` - If it does not, or if it has no structured field types
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use quarry::mine_struct_info;
+    ///
+    /// // Node holds `next: Option<NonNull<Node<T>>>`
+    /// let node = mine_struct_info("alloc::collections::linked_list::Node")?;
+    /// assert!(node.is_recursive()?);
+    ///
+    /// let string = mine_struct_info("alloc::string::String")?;
+    /// assert!(!string.is_recursive()?);
+    /// # Ok::<(), quarry::QuarryError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the standard library cache cannot be initialized.
+    pub fn is_recursive(&self) -> Result<bool> {
+        debug!("Checking whether '{}' is recursive", self.name);
+        stdlib::is_recursive_struct(self)
+    }
+
+    /// The names of the fields, in declaration order
+    pub fn field_names(&self) -> impl Iterator<Item = &str> {
+        self.fields.iter().map(|field| field.name.as_str())
+    }
+
+    /// The fully public (`pub`) fields, in declaration order
+    ///
+    /// Uses [`FieldInfo::is_public`], so `pub(crate)` and other restricted
+    /// fields are not included.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use quarry::mine_struct_info;
+    ///
+    /// let info = mine_struct_info("core::ops::range::Range")?;
+    /// for field in info.public_fields() {
+    ///     println!("pub {}: {}", field.name, field.type_name);
+    /// }
+    /// # Ok::<(), quarry::QuarryError>(())
+    /// ```
+    pub fn public_fields(&self) -> impl Iterator<Item = &FieldInfo> {
+        self.fields.iter().filter(|field| field.is_public())
+    }
+
+    /// The fields that are not fully public, in declaration order
+    ///
+    /// The complement of [`public_fields`](Self::public_fields): private,
+    /// `pub(crate)` and other restricted fields.
+    pub fn private_fields(&self) -> impl Iterator<Item = &FieldInfo> {
+        self.fields.iter().filter(|field| !field.is_public())
+    }
+
+    /// The number of fully public fields
+    pub fn public_field_count(&self) -> usize {
+        self.public_fields().count()
+    }
+
+    /// The number of fields that are not fully public
+    pub fn private_field_count(&self) -> usize {
+        self.private_fields().count()
+    }
+
+    /// Whether the struct declares any type or const parameters
+    ///
+    /// Lifetime parameters do not count: a struct such as `Chars<'a>` has a
+    /// single monomorphic layout, while `Vec<T>` has one per `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use quarry::mine_struct_info;
+    ///
+    /// assert!(mine_struct_info("alloc::vec::Vec")?.is_generic());
+    /// assert!(!mine_struct_info("alloc::string::String")?.is_generic());
+    /// # Ok::<(), quarry::QuarryError>(())
+    /// ```
+    pub fn is_generic(&self) -> bool {
+        self.generic_param_count() > 0
+    }
+
+    /// The number of type and const parameters, not counting lifetimes
+    ///
+    /// Use [`generic_param_count_with_lifetimes`](Self::generic_param_count_with_lifetimes)
+    /// to count lifetimes as well.
+    pub fn generic_param_count(&self) -> usize {
+        self.generic_param_count_with_lifetimes(false)
+    }
+
+    /// The number of generic parameters, counting lifetimes when `include_lifetimes` is true
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use quarry::mine_struct_info;
+    ///
+    /// // pub struct Drain<'a, T, A = Global>
+    /// let drain = mine_struct_info("alloc::vec::drain::Drain")?;
+    /// assert_eq!(drain.generic_param_count_with_lifetimes(false), 2);
+    /// assert_eq!(drain.generic_param_count_with_lifetimes(true), 3);
+    /// # Ok::<(), quarry::QuarryError>(())
+    /// ```
+    pub fn generic_param_count_with_lifetimes(&self, include_lifetimes: bool) -> usize {
+        self.generic_params
+            .iter()
+            .filter(|param| include_lifetimes || !param.is_lifetime())
+            .count()
+    }
+
+    /// Whether the struct is a named struct for which no fields were found
+    ///
+    /// A unit struct (`struct Marker;`) and an empty tuple struct
+    /// (`struct Empty();`) genuinely have no fields, but a struct declared with
+    /// braces that parsed with none usually hides them: every field sits behind
+    /// a `cfg` for another platform, or rustdoc stripped them (for example when
+    /// private items are not documented). This tells the two situations apart,
+    /// which `fields.is_empty()` alone cannot. A struct written as `struct S {}`
+    /// is reported as opaque too, since rustdoc records it the same way.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use quarry::list_structs_where;
+    ///
+    /// let opaque = list_structs_where(|info| info.is_opaque())?;
+    /// println!("{} structs could not be fully parsed", opaque.len());
+    /// # Ok::<(), quarry::QuarryError>(())
+    /// ```
+    pub fn is_opaque(&self) -> bool {
+        !self.is_unit_struct && !self.is_tuple_struct && self.fields.is_empty()
+    }
+
+    /// Sort the fields into a canonical order
+    ///
+    /// Named fields are sorted by name and tuple struct fields by their
+    /// position ("0", "1", ..., compared numerically), so two equivalent structs
+    /// compare equal with `==` and hash identically even if rustdoc listed
+    /// their fields in different orders.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use quarry::mine_struct_info;
+    ///
+    /// let mut info = mine_struct_info("alloc::vec::Vec")?;
+    /// info.normalize();
+    /// let names: Vec<_> = info.field_names().collect();
+    /// println!("{:?}", names);
+    /// # Ok::<(), quarry::QuarryError>(())
+    /// ```
+    pub fn normalize(&mut self) {
+        if self.is_tuple_struct {
+            self.fields
+                .sort_by_key(|field| field.name.parse::<usize>().unwrap_or(usize::MAX));
+        } else {
+            self.fields.sort_by(|a, b| a.name.cmp(&b.name));
+        }
+    }
+
+    /// Return a copy of the struct with its fields in canonical order
+    ///
+    /// See [`StructInfo::normalize`].
+    pub fn normalized(&self) -> StructInfo {
+        let mut info = self.clone();
+        info.normalize();
+        info
+    }
+
+    /// Compare this struct against another version of it
+    ///
+    /// Fields are matched by name. `self` is treated as the old version and
+    /// `other` as the new one, so fields only present in `other` are reported
+    /// as added. Changes to the struct itself (its name, attributes or docs)
+    /// are not part of the diff.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The newer version of the struct, typically mined with a
+    ///   different toolchain
+    ///
+    /// # Returns
+    ///
+    /// A [`StructDiff`] listing added and removed fields, type changes and
+    /// visibility changes, each in field declaration order
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use quarry::{QuarryConfig, mine_struct_info, mine_struct_info_with_config};
+    ///
+    /// let config = QuarryConfig {
+    ///     toolchain: "nightly-2025-01-01".to_string(),
+    ///     ..QuarryConfig::default()
+    /// };
+    /// let old = mine_struct_info_with_config("alloc::string::String", &config)?;
+    /// let new = mine_struct_info("alloc::string::String")?;
+    ///
+    /// let diff = old.diff(&new);
+    /// for change in &diff.type_changes {
+    ///     println!("{}: {} -> {}", change.name, change.old_type, change.new_type);
+    /// }
+    /// # Ok::<(), quarry::QuarryError>(())
+    /// ```
+    pub fn diff(&self, other: &StructInfo) -> StructDiff {
+        let mut diff = StructDiff::default();
+
+        for field in &self.fields {
+            match other.get_field(&field.name) {
+                None => diff.removed_fields.push(field.clone()),
+                Some(new) => {
+                    if new.type_name != field.type_name {
+                        diff.type_changes.push(FieldTypeChange {
+                            name: field.name.clone(),
+                            old_type: field.type_name.to_string(),
+                            new_type: new.type_name.to_string(),
+                        });
+                    }
+                    if new.visibility != field.visibility {
+                        diff.visibility_changes.push(FieldVisibilityChange {
+                            name: field.name.clone(),
+                            old_visibility: field.visibility.clone(),
+                            new_visibility: new.visibility.clone(),
+                        });
+                    }
+                }
+            }
+        }
+        diff.added_fields = other
+            .fields
+            .iter()
+            .filter(|field| self.get_field(&field.name).is_none())
+            .cloned()
+            .collect();
+
+        diff
+    }
+
+    /// Describe the struct as a JSON Schema (draft 2020-12) document
+    ///
+    /// Named structs become `{"type": "object", "properties": {...}}` with one
+    /// property per field, tuple structs become arrays with `prefixItems`, and
+    /// unit structs become `null`, mirroring how serde serializes them. Each
+    /// field's schema is derived from its structured `ty`:
+    ///
+    /// - Integers, floats, `bool`, `char`/`str` and `String` map to `integer`,
+    ///   `number`, `boolean` and `string`
+    /// - `Vec`, sets, slices and arrays map to `array`; `HashMap`/`BTreeMap`
+    ///   to `object`; `Option<T>` to `T` or `null`
+    /// - Other named types become `{"$ref": "#/$defs/<path>"}`; fields whose
+    ///   type is unknown or has no JSON meaning accept anything (`{}`)
+    ///
+    /// Every field except `Option`s is `required`. Private fields are included
+    /// but marked with `"x-private": true`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use quarry::mine_struct_info;
+    ///
+    /// let info = mine_struct_info("core::time::Duration")?;
+    /// let schema = info.to_json_schema();
+    /// println!("{}", serde_json::to_string_pretty(&schema).unwrap());
+    /// # Ok::<(), quarry::QuarryError>(())
+    /// ```
+    pub fn to_json_schema(&self) -> serde_json::Value {
+        use serde_json::{Map, Value, json};
+
+        let field_schema = |field: &FieldInfo| {
+            let mut schema = field.ty.as_ref().map_or_else(|| json!({}), type_schema);
+            if let Value::Object(node) = &mut schema {
+                if let Some(docs) = &field.docs {
+                    node.insert("description".to_string(), json!(docs));
+                }
+                if !field.is_public() {
+                    node.insert("x-private".to_string(), json!(true));
+                }
+            }
+            schema
+        };
+
+        let mut schema = Map::new();
+        schema.insert(
+            "$schema".to_string(),
+            json!("https://json-schema.org/draft/2020-12/schema"),
+        );
+        schema.insert("title".to_string(), json!(self.simple_name));
+        if let Some(docs) = &self.docs {
+            schema.insert("description".to_string(), json!(docs));
+        }
+
+        if self.is_unit_struct {
+            schema.insert("type".to_string(), json!("null"));
+        } else if self.is_tuple_struct {
+            let items: Vec<Value> = self.fields.iter().map(field_schema).collect();
+            schema.insert("type".to_string(), json!("array"));
+            schema.insert("prefixItems".to_string(), json!(items));
+            schema.insert("items".to_string(), json!(false));
+        } else {
+            let properties: Map<String, Value> = self
+                .fields
+                .iter()
+                .map(|field| (field.name.clone(), field_schema(field)))
+                .collect();
+            let is_option = |field: &&FieldInfo| match &field.ty {
+                Some(Type::Path { name, .. }) => name.rsplit("::").next() == Some("Option"),
+                _ => false,
+            };
+            let required: Vec<&str> = self
+                .fields
+                .iter()
+                .filter(|field| !is_option(field))
+                .map(|field| field.name.as_str())
+                .collect();
+            schema.insert("type".to_string(), json!("object"));
+            schema.insert("properties".to_string(), Value::Object(properties));
+            schema.insert("required".to_string(), json!(required));
+        }
+
+        Value::Object(schema)
+    }
+
+    /// Build a `syn` AST node for the struct
+    ///
+    /// The `syn` counterpart of [`to_rust_source`](Self::to_rust_source), for
+    /// code generators built on `syn` and `quote`. Each field's `type_name` is
+    /// parsed with `syn::parse_str::<syn::Type>`; types that do not parse, such
+    /// as the `unknown` placeholder, are replaced with `()`. Fields are `pub`
+    /// when [`FieldInfo::is_public`] holds. Derives, reprs, generic parameters
+    /// and where-clause predicates are carried over when they parse.
+    ///
+    /// Requires the `syn` cargo feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use quarry::mine_struct_info;
+    ///
+    /// let item: syn::ItemStruct = mine_struct_info("alloc::string::String")?.to_syn();
+    /// assert_eq!(item.ident, "String");
+    /// # Ok::<(), quarry::QuarryError>(())
+    /// ```
+    #[cfg(feature = "syn")]
+    pub fn to_syn(&self) -> syn::ItemStruct {
+        use proc_macro2::Span;
+        use syn::parse::Parser;
+
+        let ident = |name: &str| match name.strip_prefix("r#") {
+            Some(raw) => syn::Ident::new_raw(raw, Span::call_site()),
+            None => syn::Ident::new(name, Span::call_site()),
+        };
+        let field = |field: &FieldInfo, named: bool| syn::Field {
+            attrs: Vec::new(),
+            vis: if field.is_public() {
+                syn::parse_quote!(pub)
+            } else {
+                syn::Visibility::Inherited
+            },
+            mutability: syn::FieldMutability::None,
+            ident: named.then(|| ident(&field.name)),
+            colon_token: named.then(Default::default),
+            ty: syn::parse_str(&field.type_name).unwrap_or_else(|_| syn::parse_quote!(())),
+        };
+
+        let mut attrs = Vec::new();
+        if !self.derives.is_empty() {
+            attrs.push(format!("#[derive({})]", self.derives.join(", ")));
+        }
+        if !self.reprs.is_empty() {
+            attrs.push(format!("#[repr({})]", self.reprs.join(", ")));
+        }
+        if self.is_non_exhaustive {
+            attrs.push("#[non_exhaustive]".to_string());
+        }
+
+        let mut generics = syn::Generics {
+            params: self
+                .generic_params
+                .iter()
+                .filter_map(|param| syn::parse_str::<syn::GenericParam>(&param.to_string()).ok())
+                .collect(),
+            ..Default::default()
+        };
+        if !generics.params.is_empty() {
+            generics.lt_token = Some(Default::default());
+            generics.gt_token = Some(Default::default());
+        }
+        let predicates: syn::punctuated::Punctuated<_, syn::Token![,]> = self
+            .where_predicates
+            .iter()
+            .filter_map(|predicate| syn::parse_str::<syn::WherePredicate>(predicate).ok())
+            .collect();
+        if !predicates.is_empty() {
+            generics.where_clause = Some(syn::WhereClause {
+                where_token: Default::default(),
+                predicates,
+            });
+        }
+
+        let (fields, semi_token) = if self.is_unit_struct {
+            (syn::Fields::Unit, Some(Default::default()))
+        } else if self.is_tuple_struct {
+            let unnamed = self.fields.iter().map(|f| field(f, false)).collect();
+            let fields = syn::FieldsUnnamed {
+                paren_token: Default::default(),
+                unnamed,
+            };
+            (syn::Fields::Unnamed(fields), Some(Default::default()))
+        } else {
+            let named = self.fields.iter().map(|f| field(f, true)).collect();
+            let fields = syn::FieldsNamed {
+                brace_token: Default::default(),
+                named,
+            };
+            (syn::Fields::Named(fields), None)
+        };
+
+        syn::ItemStruct {
+            attrs: attrs
+                .iter()
+                .filter_map(|attr| syn::Attribute::parse_outer.parse_str(attr).ok())
+                .flatten()
+                .collect(),
+            vis: if self.is_public() {
+                syn::parse_quote!(pub)
+            } else {
+                syn::Visibility::Inherited
+            },
+            struct_token: Default::default(),
+            ident: ident(&self.simple_name),
+            generics,
+            fields,
+            semi_token,
+        }
+    }
+
+    /// Render the struct as a TypeScript interface
+    ///
+    /// Produces an interface named after the struct with one property per
+    /// public field, e.g.:
+    ///
+    /// ```text
+    /// interface String {
+    ///     vec: Vec_u8;
+    /// }
+    /// ```
+    ///
+    /// Integer and float primitives become `number`, `bool` becomes `boolean`,
+    /// and `char`, `str` and `String` become `string`, looking through
+    /// references (`&str` is `string` too). Every other type keeps its Rust
+    /// name, with anything TypeScript does not allow in an identifier replaced
+    /// by `_` (`Vec<u8>` becomes `Vec_u8`), so that it can refer to another
+    /// generated interface. Tuple struct fields are named `0`, `1`, ...
+    ///
+    /// Private fields are left out; use
+    /// [`to_typescript_with_private`](Self::to_typescript_with_private) to
+    /// include them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use quarry::mine_struct_info;
+    ///
+    /// let info = mine_struct_info("core::time::Duration")?;
+    /// std::fs::write("Duration.ts", info.to_typescript())?;
+    /// # Ok::<(), quarry::QuarryError>(())
+    /// ```
+    pub fn to_typescript(&self) -> String {
+        self.to_typescript_with_private(false)
+    }
+
+    /// Render the struct as a TypeScript interface, optionally with private fields
+    ///
+    /// Like [`to_typescript`](Self::to_typescript), but fields that are not
+    /// public are also included when `include_private` is true.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use quarry::mine_struct_info;
+    ///
+    /// let info = mine_struct_info("alloc::string::String")?;
+    /// // String's only field, `vec`, is private
+    /// assert!(info.to_typescript_with_private(true).contains("vec: Vec_u8;"));
+    /// # Ok::<(), quarry::QuarryError>(())
+    /// ```
+    pub fn to_typescript_with_private(&self, include_private: bool) -> String {
+        let mut ts = format!("interface {} {{\n", self.simple_name);
+        for field in self
+            .fields
+            .iter()
+            .filter(|field| include_private || field.is_public())
+        {
+            ts.push_str(&format!(
+                "    {}: {};\n",
+                field.name,
+                typescript_type(field)
+            ));
+        }
+        ts.push('}');
+        ts
+    }
+
+    /// Render the struct's fields as a Markdown table
+    ///
+    /// Produces a GitHub-flavored Markdown table with one row per field, in
+    /// declaration order, e.g.:
+    ///
+    /// ```text
+    /// | Field | Type | Visibility |
+    /// |-------|------|------------|
+    /// | `vec` | `Vec<u8>` | private |
+    /// ```
+    ///
+    /// Field names and types are set in code spans, with any `|` escaped as
+    /// `\|` so that it does not end the cell. Visibility is rendered as by
+    /// [`Visibility`]'s `Display` (`public`, `crate`, `restricted to ...` or
+    /// `private`). A struct without fields yields the header rows only.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use quarry::mine_struct_info;
+    ///
+    /// let info = mine_struct_info("core::time::Duration")?;
+    /// println!("## {}\n\n{}", info.simple_name, info.to_markdown_table());
+    /// # Ok::<(), quarry::QuarryError>(())
+    /// ```
+    pub fn to_markdown_table(&self) -> String {
+        let escape = |text: &str| text.replace('|', "\\|");
+
+        let mut table = String::from("| Field | Type | Visibility |\n");
+        table.push_str("|-------|------|------------|\n");
+        for field in &self.fields {
+            table.push_str(&format!(
+                "| `{}` | `{}` | {} |\n",
+                escape(&field.name),
+                escape(&field.type_name),
+                escape(&field.visibility.to_string())
+            ));
+        }
+        table
+    }
+
+    /// Render the struct as an approximate Rust definition
+    ///
+    /// Produces a skeleton for documentation and code generation, e.g.:
+    ///
+    /// ```text
+    /// #[derive(Clone)]
+    /// pub struct String {
+    ///     vec: Vec<u8>,
+    /// }
+    /// ```
+    ///
+    /// Fields are `pub` when [`FieldInfo::is_public`] holds and typed with their
+    /// `type_name`. Generic parameters are listed after the name with their
+    /// defaults but without their bounds, which appear in the where clause
+    /// instead. The output is not
+    /// guaranteed to compile, since field types may name private items.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use quarry::mine_struct_info;
+    ///
+    /// let info = mine_struct_info("alloc::string::String")?;
+    /// println!("{}", info.to_rust_source());
+    /// # Ok::<(), quarry::QuarryError>(())
+    /// ```
+    pub fn to_rust_source(&self) -> String {
+        let mut source = String::new();
+        if !self.derives.is_empty() {
+            source.push_str(&format!("#[derive({})]\n", self.derives.join(", ")));
+        }
+        if !self.reprs.is_empty() {
+            source.push_str(&format!("#[repr({})]\n", self.reprs.join(", ")));
+        }
+        if self.is_non_exhaustive {
+            source.push_str("#[non_exhaustive]\n");
+        }
+
+        let vis = |public: bool| if public { "pub " } else { "" };
+        source.push_str(&format!(
+            "{}struct {}",
+            vis(self.is_public()),
+            self.simple_name
+        ));
+        if !self.generic_params.is_empty() {
+            let params: Vec<String> = self.generic_params.iter().map(|p| p.to_string()).collect();
+            source.push_str(&format!("<{}>", params.join(", ")));
+        }
+
+        let where_clause = if self.where_predicates.is_empty() {
+            String::new()
+        } else {
+            format!(" where {}", self.where_predicates.join(", "))
+        };
+
+        if self.is_unit_struct {
+            source.push_str(&where_clause);
+            source.push(';');
+        } else if self.is_tuple_struct {
+            let fields: Vec<String> = self
+                .fields
+                .iter()
+                .map(|field| format!("{}{}", vis(field.is_public()), field.type_name))
+                .collect();
+            source.push_str(&format!("({}){};", fields.join(", "), where_clause));
+        } else {
+            source.push_str(&where_clause);
+            source.push_str(" {\n");
+            for field in &self.fields {
+                source.push_str(&format!(
+                    "    {}{}: {},\n",
+                    vis(field.is_public()),
+                    field.name,
+                    field.type_name
+                ));
+            }
+            source.push('}');
+        }
+        source
+    }
+}
+
+impl FieldInfo {
+    /// Whether the field is fully public (`pub`)
+    ///
+    /// Crate-visible and restricted fields are not considered public.
+    pub fn is_public(&self) -> bool {
+        self.visibility.is_public()
+    }
+
+    /// Whether the field is a `core::marker::PhantomData`
+    ///
+    /// `PhantomData` fields are zero-sized markers that carry no data, so
+    /// generators of layout-faithful copies usually want to special-case them.
+    /// The check uses the structured [`ty`](Self::ty) when present, accepting
+    /// the path however the source wrote it (`PhantomData<T>`,
+    /// `marker::PhantomData<T>`, `std::marker::PhantomData<T>`, ...), and
+    /// falls back to matching the start of `type_name` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use quarry::mine_struct_info;
+    ///
+    /// let info = mine_struct_info("core::ptr::NonNull")?;
+    /// let real_fields = info.fields.iter().filter(|field| !field.is_phantom_data());
+    /// println!("{} fields carry data", real_fields.count());
+    /// # Ok::<(), quarry::QuarryError>(())
+    /// ```
+    pub fn is_phantom_data(&self) -> bool {
+        let is_phantom_path = |path: &str| {
+            let path = path.trim_start_matches("::");
+            path == "PhantomData"
+                || path == "marker::PhantomData"
+                || path.ends_with("::marker::PhantomData")
+        };
+
+        match &self.ty {
+            Some(Type::Path { name, .. }) => is_phantom_path(name),
+            Some(_) => false,
+            None => {
+                let name = self.type_name.split('<').next().unwrap_or_default();
+                is_phantom_path(name.trim())
+            }
+        }
+    }
+}
+
+/// Renders a one-line summary such as `vec: Vec<u8> (private)`
+impl std::fmt::Display for FieldInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {} ({})", self.name, self.type_name, self.visibility)
+    }
+}
+
+/// Renders a short multi-line summary: a header with the full name, the kind of
+/// struct and its field count, followed by one indented line per field
+///
+/// ```text
+/// alloc::string::String (struct, 1 field)
+///     vec: Vec<u8> (private)
+/// ```
+impl std::fmt::Display for StructInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = if self.is_unit_struct {
+            "unit struct"
+        } else if self.is_tuple_struct {
+            "tuple struct"
+        } else {
+            "struct"
+        };
+        let plural = if self.fields.len() == 1 { "" } else { "s" };
+        write!(
+            f,
+            "{} ({}, {} field{})",
+            self.name,
+            kind,
+            self.fields.len(),
+            plural
+        )?;
+        for field in &self.fields {
+            write!(f, "\n    {}", field)?;
+        }
+        Ok(())
+    }
+}
+
+impl UnionInfo {
+    /// Create a new UnionInfo with the given name and extract module path components
+    pub fn new(name: &str) -> Self {
+        let (module_path, simple_name) = split_path(name);
+
+        Self {
+            name: name.to_string(),
+            simple_name,
+            module_path,
+            fields: Vec::new(),
+            where_predicates: Vec::new(),
+            visibility: Visibility::Private,
+            docs: None,
+            span: None,
+            reprs: Vec::new(),
+            derives: Vec::new(),
+            stability: None,
+            deprecation: None,
+        }
+    }
+}
+
+impl AliasInfo {
+    /// Create a new AliasInfo with the given name and extract module path components
+    pub fn new(name: &str) -> Self {
+        let (module_path, simple_name) = split_path(name);
+
+        Self {
+            name: name.to_string(),
+            simple_name,
+            module_path,
+            type_name: "unknown".to_string(),
+            ty: None,
+            target: None,
+            visibility: Visibility::Private,
+            docs: None,
+            span: None,
+        }
+    }
+}
+
+impl EnumInfo {
+    /// Create a new EnumInfo with the given name and extract module path components
+    pub fn new(name: &str) -> Self {
+        let (module_path, simple_name) = split_path(name);
+
+        Self {
+            name: name.to_string(),
+            simple_name,
+            module_path,
+            variants: Vec::new(),
+        }
+    }
+}
+
+/// Mine struct information from the Rust standard library
+///
+/// This function queries the standard library cache for information about a specific struct.
+/// It requires the full module path to ensure unambiguous type resolution (e.g.,
+/// "alloc::string::String" rather than just "String").
+///
+/// # Arguments
+///
+/// * `name` - The full module path of the struct (e.g., "alloc::string::String")
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use quarry::mine_struct_info;
+///
+/// // Standard library struct with full path
+/// let string_info = mine_struct_info("alloc::string::String")?;
+/// println!("Struct: {}", string_info.name);
+/// println!("Fields: {}", string_info.fields.len());
+///
+/// // Vec from alloc crate
+/// let vec_info = mine_struct_info("alloc::vec::Vec")?;
+/// println!("Is tuple struct: {}", vec_info.is_tuple_struct);
+///
+/// // HashMap from std collections
+/// let map_info = mine_struct_info("std::collections::HashMap")?;
+/// for field in &map_info.fields {
+///     println!("  Field: {} -> {}", field.name, field.type_name);
+/// }
+/// # Ok::<(), quarry::QuarryError>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns `QuarryError::TypeNotFound` if the specified struct is not found in the
+/// standard library cache. Make sure you're using the complete module path: a
+/// bare name such as `"HashMap"` that matches cached types fails with
+/// `QuarryError::Ambiguous`, listing the full paths to choose from. A path
+/// naming an enum, union or type alias also fails with `TypeNotFound`, and
+/// the message reports the kind that was found; use [`mine_type_info`] to
+/// look up types of any kind.
+pub fn mine_struct_info(name: &str) -> Result<StructInfo> {
+    mine_struct_info_with_config(name, &QuarryConfig::default())
+}
+
+/// Mine struct information using a custom configuration
+///
+/// Behaves like [`mine_struct_info`], but analyzes the standard library of the
+/// toolchain selected in `config` instead of the default `nightly`.
+///
+/// The in-memory cache holds results for one configuration at a time, so
+/// alternating between configurations triggers re-initialization.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use quarry::{mine_struct_info_with_config, QuarryConfig};
+///
+/// let config = QuarryConfig {
+///     toolchain: "nightly-2024-05-01".to_string(),
+///     ..Default::default()
+/// };
+/// let info = mine_struct_info_with_config("alloc::string::String", &config)?;
+/// println!("Fields: {}", info.fields.len());
+/// # Ok::<(), quarry::QuarryError>(())
+/// ```
+///
+/// # Errors
+///
+/// Same as [`mine_struct_info`].
+pub fn mine_struct_info_with_config(name: &str, config: &QuarryConfig) -> Result<StructInfo> {
+    debug!(
+        "Mining struct information for: '{}' (toolchain: {})",
+        name, config.toolchain
+    );
+
+    match stdlib::mine_stdlib_struct_info(name, config) {
+        Ok(info) => {
+            debug!(
+                "Successfully found '{}' with {} fields",
+                name,
+                info.fields.len()
+            );
+            Ok(info)
+        }
+        Err(e) => {
+            debug!("Failed to find struct '{}': {:?}", name, e);
+            Err(e)
+        }
+    }
+}
+
+/// Mine struct information as a shared handle to the cached entry
+///
+/// Behaves like [`mine_struct_info`], but returns the cache's own
+/// `Arc<StructInfo>` instead of a deep copy, so repeated lookups of popular
+/// types cost a reference count increment rather than cloning every field.
+/// Structs found through a std:: alias are still copied once, since the
+/// result reports the requested alias name.
+///
+/// # Arguments
+///
+/// * `name` - The full module path of the struct (e.g., "alloc::string::String")
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use quarry::mine_struct_info_arc;
+///
+/// let first = mine_struct_info_arc("alloc::string::String")?;
+/// let second = mine_struct_info_arc("alloc::string::String")?;
+/// assert!(std::sync::Arc::ptr_eq(&first, &second));
+/// # Ok::<(), quarry::QuarryError>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns the same errors as [`mine_struct_info`].
+pub fn mine_struct_info_arc(name: &str) -> Result<Arc<StructInfo>> {
+    stdlib::mine_stdlib_struct_info_arc(name, &QuarryConfig::default())
+}
+
+/// Inspect struct information without cloning it
+///
+/// Looks up a struct like [`mine_struct_info`] and passes a reference to the
+/// cached entry into `f`, returning whatever `f` returns. This avoids copying
+/// every field name and type string when only a small piece of information is
+/// needed.
+///
+/// The cache stays locked while `f` runs, so keep `f` short and do not call
+/// other Quarry functions from inside it. When `name` is a std:: alias, the
+/// cached entry reports the defining path (e.g. "alloc::string::String") as
+/// its `name`.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use quarry::with_struct_info;
+///
+/// let field_count = with_struct_info("alloc::string::String", |info| info.fields.len())?;
+/// println!("String has {} fields", field_count);
+/// # Ok::<(), quarry::QuarryError>(())
+/// ```
+///
+/// # Errors
+///
+/// Same as [`mine_struct_info`].
+pub fn with_struct_info<R>(name: &str, f: impl FnOnce(&StructInfo) -> R) -> Result<R> {
+    debug!("Borrowing struct information for: '{}'", name);
+    stdlib::with_stdlib_struct_info(name, &QuarryConfig::default(), f)
+}
+
+/// Mine struct information for several types at once
+///
+/// Equivalent to calling [`mine_struct_info`] for each name, but the cache is
+/// locked only once for the whole batch. Each name gets its own result, so a
+/// missing type does not abort the rest of the batch.
+///
+/// # Arguments
+///
+/// * `names` - Full module paths or std:: aliases of the structs to look up
+///
+/// # Returns
+///
+/// One `(name, result)` pair per input name, in the same order as `names`.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use quarry::mine_struct_infos;
+///
+/// let results = mine_struct_infos(&["alloc::string::String", "alloc::vec::Vec", "NoSuchType"]);
+/// for (name, result) in &results {
+///     match result {
+///         Ok(info) => println!("{}: {} fields", name, info.fields.len()),
+///         Err(e) => println!("{}: {}", name, e),
+///     }
+/// }
+/// ```
+pub fn mine_struct_infos(names: &[&str]) -> Vec<(String, Result<StructInfo>)> {
+    debug!("Mining struct information for {} names", names.len());
+    stdlib::mine_stdlib_struct_infos(names, &QuarryConfig::default())
+}
+
+/// Mine struct information from an arbitrary crate
+///
+/// Runs the same rustdoc JSON analysis used for the standard library on the
+/// crate described by `manifest_path`, including private items. The returned
+/// map is keyed by full struct path, rooted at the crate name
+/// (e.g. "my_crate::config::Settings").
+///
+/// Unlike the standard library, crate results are not cached: every call
+/// regenerates the documentation.
+///
+/// # Arguments
+///
+/// * `manifest_path` - Path to the crate's `Cargo.toml`
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use quarry::mine_crate_structs;
+/// use std::path::Path;
+///
+/// let structs = mine_crate_structs(Path::new("path/to/my_crate/Cargo.toml"))?;
+/// for (name, info) in &structs {
+///     println!("{}: {} fields", name, info.fields.len());
+/// }
+/// # Ok::<(), quarry::QuarryError>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns `QuarryError::Io` with `ErrorKind::NotFound` if the manifest does
+/// not exist, and `QuarryError::RustdocFailed` with cargo's exit status and
+/// stderr if `cargo doc` fails. A run that exceeds
+/// `QuarryConfig::rustdoc_timeout` is killed and also reported as
+/// `RustdocFailed`, with no status. Returns `QuarryError::Io` if the output
+/// cannot be read.
+pub fn mine_crate_structs(manifest_path: &std::path::Path) -> Result<HashMap<String, StructInfo>> {
+    debug!("Mining crate structs from: {:?}", manifest_path);
+
+    let structs = stdlib::mine_crate_structs(manifest_path, &QuarryConfig::default())?;
+    debug!(
+        "Found {} structs in crate at {:?}",
+        structs.len(),
+        manifest_path
+    );
+    Ok(structs)
+}
+
+/// Parse a pre-generated rustdoc JSON file
+///
+/// Use this when rustdoc JSON is already produced elsewhere (e.g. as part of a
+/// CI build), so Quarry does not need to run `cargo doc` or have `rust-src`
+/// available. The file can document any crate; the returned map is keyed by
+/// full struct path, exactly as the internal stdlib parser produces it.
+///
+/// # Arguments
+///
+/// * `path` - Path to a rustdoc JSON file (e.g. `target/doc/my_crate.json`)
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use quarry::parse_rustdoc_json_file;
+/// use std::path::Path;
+///
+/// let structs = parse_rustdoc_json_file(Path::new("target/doc/my_crate.json"))?;
+/// println!("Found {} structs", structs.len());
+/// # Ok::<(), quarry::QuarryError>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns `QuarryError::Io` if the file cannot be read, and
+/// `QuarryError::MalformedRustdocJson` if it is not valid rustdoc JSON.
+pub fn parse_rustdoc_json_file(path: &std::path::Path) -> Result<HashMap<String, StructInfo>> {
+    debug!("Parsing rustdoc JSON file: {:?}", path);
+    stdlib::parse_rustdoc_json_file(path)
+}
+
+/// Parse rustdoc JSON that is already held in a string
+///
+/// Behaves like [`parse_rustdoc_json_file`] but skips the filesystem, which
+/// makes it the natural entry point for tests: a small hand-written JSON
+/// fixture can be embedded with `include_str!` and parsed on a stable
+/// toolchain, without nightly or `cargo doc`.
+///
+/// # Arguments
+///
+/// * `json` - The contents of a rustdoc JSON file
+///
+/// # Returns
+///
+/// A map of struct information keyed by full struct path.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use quarry::parse_rustdoc_json_str;
+///
+/// let json = std::fs::read_to_string("tests/fixtures/basic.json")?;
+/// let structs = parse_rustdoc_json_str(&json)?;
+/// let plain = &structs["fixture::Plain"];
+/// assert_eq!(plain.fields.len(), 2);
+/// # Ok::<(), quarry::QuarryError>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns `QuarryError::MalformedRustdocJson` if the string is not valid
+/// rustdoc JSON, and `QuarryError::UnsupportedRustdocFormat` if it was produced
+/// by an unsupported rustdoc.
+pub fn parse_rustdoc_json_str(json: &str) -> Result<HashMap<String, StructInfo>> {
+    debug!("Parsing rustdoc JSON from a string of {} bytes", json.len());
+    stdlib::parse_rustdoc_json_str(json)
+}
+
+/// Seed the standard library cache from a pre-generated rustdoc JSON file
+///
+/// After this call, [`mine_struct_info`], [`mine_enum_info`] and the other
+/// default-configuration queries are answered from the loaded types instead of
+/// analyzing the installed standard library. Calling it again with another
+/// file merges that file's types into the cache, so the `std`, `alloc`, and
+/// `core` JSON files can be loaded one after another.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use quarry::{load_cache_from_json, mine_struct_info};
+/// use std::path::Path;
+///
+/// load_cache_from_json(Path::new("ci-docs/alloc.json"))?;
+/// load_cache_from_json(Path::new("ci-docs/core.json"))?;
+///
+/// let info = mine_struct_info("alloc::string::String")?;
+/// println!("Fields: {}", info.fields.len());
+/// # Ok::<(), quarry::QuarryError>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns `QuarryError::Io` if the file cannot be read, and
+/// `QuarryError::MalformedRustdocJson` if it is not valid rustdoc JSON.
+pub fn load_cache_from_json(path: &std::path::Path) -> Result<()> {
+    debug!("Loading stdlib cache from rustdoc JSON file: {:?}", path);
+    stdlib::load_cache_from_json(path)
+}
+
+/// Export the standard library cache to a JSON file
+///
+/// Writes everything Quarry knows about the analyzed standard library (every
+/// struct, enum, union and type alias, plus the re-export tables used for
+/// `std::` paths) to `path`. Generate it once on a machine with nightly and
+/// rust-src, then load it with [`import_cache_from_json`] anywhere, including
+/// machines with only a stable toolchain.
+///
+/// The cache is initialized first if needed.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use quarry::export_cache_to_json;
+/// use std::path::Path;
+///
+/// export_cache_to_json(Path::new("quarry-stdlib.json"))?;
+/// # Ok::<(), quarry::QuarryError>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the cache cannot be initialized, or `QuarryError::Io` if
+/// the file cannot be written.
+pub fn export_cache_to_json(path: &std::path::Path) -> Result<()> {
+    stdlib::export_cache_to_json(path)
+}
+
+/// Replace the standard library cache with a file written by [`export_cache_to_json`]
+///
+/// Subsequent default-configuration queries such as [`mine_struct_info`] are
+/// answered from the imported types without running `cargo doc`. Unlike
+/// [`load_cache_from_json`], which merges raw rustdoc output, this replaces the
+/// cache with the exported snapshot.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use quarry::{import_cache_from_json, mine_struct_info};
+/// use std::path::Path;
+///
+/// import_cache_from_json(Path::new("quarry-stdlib.json"))?;
+/// let info = mine_struct_info("alloc::string::String")?;
+/// # Ok::<(), quarry::QuarryError>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns `QuarryError::Io` if the file cannot be read or is not a valid export.
+pub fn import_cache_from_json(path: &std::path::Path) -> Result<()> {
+    stdlib::import_cache_from_json(path)
+}
+
+/// Export the standard library cache to a compact binary file
+///
+/// The binary counterpart of [`export_cache_to_json`]: the same content,
+/// encoded with `bincode`, which is considerably smaller and faster to load.
+/// Load it with [`import_cache_bin`].
+///
+/// Requires the `bincode` cargo feature.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use quarry::export_cache_bin;
+/// use std::path::Path;
+///
+/// export_cache_bin(Path::new("quarry-stdlib.bin"))?;
+/// # Ok::<(), quarry::QuarryError>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the cache cannot be initialized, or `QuarryError::Io` if
+/// the file cannot be written.
+#[cfg(feature = "bincode")]
+pub fn export_cache_bin(path: &std::path::Path) -> Result<()> {
+    stdlib::export_cache_bin(path)
+}
+
+/// Replace the standard library cache with a file written by [`export_cache_bin`]
+///
+/// The binary counterpart of [`import_cache_from_json`]; the imported types
+/// are seeded into the cache the same way.
+///
+/// Requires the `bincode` cargo feature.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use quarry::{import_cache_bin, mine_struct_info};
+/// use std::path::Path;
+///
+/// import_cache_bin(Path::new("quarry-stdlib.bin"))?;
+/// let info = mine_struct_info("alloc::string::String")?;
+/// # Ok::<(), quarry::QuarryError>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns `QuarryError::Io` if the file cannot be read or is not a valid export.
+#[cfg(feature = "bincode")]
+pub fn import_cache_bin(path: &std::path::Path) -> Result<()> {
+    stdlib::import_cache_bin(path)
+}
+
+/// Mine enum information from the Rust standard library
+///
+/// This is the enum counterpart of [`mine_struct_info`]. It requires the full
+/// module path of the enum (e.g., "core::option::Option") and reports every
+/// variant together with its shape and fields.
+///
+/// # Arguments
+///
+/// * `name` - The full module path of the enum (e.g., "core::option::Option")
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use quarry::{mine_enum_info, VariantKind};
+///
+/// let option_info = mine_enum_info("core::option::Option")?;
+/// for variant in &option_info.variants {
+///     match variant.kind {
+///         VariantKind::Unit => println!("  {}", variant.name),
+///         VariantKind::Tuple => println!("  {}(..) with {} fields", variant.name, variant.fields.len()),
+///         VariantKind::Struct => println!("  {} {{ .. }}", variant.name),
+///     }
+/// }
+/// # Ok::<(), quarry::QuarryError>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns `QuarryError::TypeNotFound` if no enum with the given path exists in
+/// the standard library cache, including when the path names a struct instead.
+pub fn mine_enum_info(name: &str) -> Result<EnumInfo> {
+    debug!("Mining enum information for: '{}'", name);
+
+    match stdlib::mine_stdlib_enum_info(name) {
+        Ok(info) => {
+            debug!(
+                "Successfully found '{}' with {} variants",
+                name,
+                info.variants.len()
+            );
+            Ok(info)
+        }
+        Err(e) => {
+            debug!("Failed to find enum '{}': {:?}", name, e);
+            Err(e)
+        }
+    }
+}
+
+/// Mine union information from the Rust standard library
+///
+/// This is the union counterpart of [`mine_struct_info`]. Unions such as
+/// `MaybeUninit` always have named fields, which are reported exactly like
+/// struct fields.
+///
+/// # Arguments
+///
+/// * `name` - The full module path of the union (e.g., "core::mem::maybe_uninit::MaybeUninit")
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use quarry::mine_union_info;
+///
+/// let union_info = mine_union_info("core::mem::maybe_uninit::MaybeUninit")?;
+/// for field in &union_info.fields {
+///     println!("  {}: {}", field.name, field.type_name);
+/// }
+/// # Ok::<(), quarry::QuarryError>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns `QuarryError::TypeNotFound` if no union with the given path exists in
+/// the standard library cache, including when the path names a struct or enum instead.
+pub fn mine_union_info(name: &str) -> Result<UnionInfo> {
+    debug!("Mining union information for: '{}'", name);
+
+    match stdlib::mine_stdlib_union_info(name) {
+        Ok(info) => {
+            debug!(
+                "Successfully found '{}' with {} fields",
+                name,
+                info.fields.len()
+            );
+            Ok(info)
+        }
+        Err(e) => {
+            debug!("Failed to find union '{}': {:?}", name, e);
+            Err(e)
+        }
+    }
+}
+
+/// Resolve a re-exported path to the path its type is defined under
+///
+/// Standard library types are usually reached through re-exports: the familiar
+/// `std::string::String` is defined as `alloc::string::String`. This follows
+/// the `pub use` declarations recorded by rustdoc to find the defining path,
+/// regardless of whether the type is a struct, enum, union or type alias.
+///
+/// The re-export table is part of the standard library cache, so the first call
+/// initializes the cache like [`init_stdlib_cache`].
+///
+/// # Arguments
+///
+/// * `name` - The path to resolve (e.g., "std::string::String")
+///
+/// # Returns
+///
+/// * `Some(String)` - The defining path (e.g., "alloc::string::String")
+/// * `None` - If the path is not a re-export of a cached type (including when
+///   it already is the defining path), or the cache could not be initialized
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use quarry::resolve_alias;
+///
+/// let name = "std::string::String";
+/// let canonical = resolve_alias(name).unwrap_or_else(|| name.to_string());
+/// assert_eq!(canonical, "alloc::string::String");
+/// ```
+pub fn resolve_alias(name: &str) -> Option<String> {
+    debug!("Resolving alias: '{}'", name);
+    stdlib::resolve_alias(name)
+}
+
+/// Find the familiar std:: path for a type's defining path
+///
+/// This is the inverse of [`resolve_alias`]: given the path a type is defined
+/// under, such as `alloc::string::String`, it returns the public path users
+/// know it by, `std::string::String`. When a type is re-exported under several
+/// paths, the one in the `std` crate is preferred, then the shortest.
+///
+/// Like [`resolve_alias`], the first call initializes the standard library cache.
+///
+/// # Arguments
+///
+/// * `name` - The defining path of a type (e.g., "alloc::string::String")
+///
+/// # Returns
+///
+/// * `Some(String)` - The preferred re-export path (e.g., "std::string::String")
+/// * `None` - If the type is not re-exported anywhere (e.g., it is already
+///   defined in `std`), is not in the cache, or the cache could not be initialized
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use quarry::canonical_to_std_alias;
+///
+/// let display = canonical_to_std_alias("alloc::string::String");
+/// assert_eq!(display.as_deref(), Some("std::string::String"));
+/// ```
+pub fn canonical_to_std_alias(name: &str) -> Option<String> {
+    debug!("Finding std alias for: '{}'", name);
+    stdlib::canonical_to_std_alias(name)
+}
+
+/// Find every public re-export path of a type's defining path
+///
+/// Where [`canonical_to_std_alias`] picks the single preferred path, this
+/// returns all of them, e.g. for cross-reference documentation. Given
+/// `alloc::sync::Arc` it returns `std::sync::Arc` along with any other path
+/// the type is re-exported under. Every returned path resolves back to
+/// `canonical` through [`resolve_alias`], so each is accepted by
+/// [`mine_struct_info`] and the other lookups.
+///
+/// The reverse alias table is built once per cache and reused, so repeated
+/// calls are cheap. Like [`resolve_alias`], the first call initializes the
+/// standard library cache.
+///
+/// # Arguments
+///
+/// * `canonical` - The defining path of a type (e.g., "alloc::sync::Arc")
+///
+/// # Returns
+///
+/// The re-export paths, `std::` paths first, then shorter before longer, then
+/// alphabetically. Empty if the type is not re-exported anywhere, is not in the
+/// cache, or the cache could not be initialized.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use quarry::aliases_for;
+///
+/// for path in aliases_for("alloc::sync::Arc") {
+///     println!("also known as {}", path);
+/// }
+/// ```
+pub fn aliases_for(canonical: &str) -> Vec<String> {
+    debug!("Finding all aliases for: '{}'", canonical);
+    stdlib::aliases_for(canonical)
+}
+
+/// Resolve a standard library type alias to the type it stands for
+///
+/// Follows a single alias: for `std::io::error::Result` this returns
+/// `"core::result::Result"`, the definition path of the aliased type. Generic
+/// arguments are not part of the result; see [`AliasInfo::type_name`] for the
+/// alias's full right-hand side. Use [`resolve_type_alias_recursive`] to keep
+/// following when the target is itself an alias.
+///
+/// # Arguments
+///
+/// * `name` - The full module path of the alias (e.g., "std::io::error::Result")
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use quarry::resolve_type_alias;
+///
+/// let target = resolve_type_alias("core::fmt::Result")?;
+/// println!("Alias points to {}", target);
+/// # Ok::<(), quarry::QuarryError>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns `QuarryError::TypeNotFound` if no type alias with the given path
+/// exists in the standard library cache.
+pub fn resolve_type_alias(name: &str) -> Result<String> {
+    debug!("Resolving type alias: '{}'", name);
+    stdlib::resolve_stdlib_type_alias(name, false)
+}
+
+/// Resolve a standard library type alias, following chains of aliases
+///
+/// Behaves like [`resolve_type_alias`], but when the target is itself a type
+/// alias it is resolved in turn, so the result names a struct, enum, union or
+/// a type outside the cache.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use quarry::{mine_type_info, resolve_type_alias_recursive};
+///
+/// let target = resolve_type_alias_recursive("std::io::error::Result")?;
+/// let info = mine_type_info(&target)?;
+/// println!("{} is a {}", target, info.kind());
+/// # Ok::<(), quarry::QuarryError>(())
+/// ```
+///
+/// # Errors
+///
+/// Same as [`resolve_type_alias`].
+pub fn resolve_type_alias_recursive(name: &str) -> Result<String> {
+    debug!("Recursively resolving type alias: '{}'", name);
+    stdlib::resolve_stdlib_type_alias(name, true)
+}
+
+/// Mine information about a standard library type of any kind
+///
+/// This is the single entry point for looking up a path without knowing its
+/// kind in advance. It accepts the same full module paths and std:: aliases as
+/// [`mine_struct_info`] and returns whichever kind of definition the path names.
+///
+/// # Arguments
+///
+/// * `name` - The full module path of the type (e.g., "core::option::Option")
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use quarry::{mine_type_info, TypeInfo};
+///
+/// match mine_type_info("core::option::Option")? {
+///     TypeInfo::Struct(info) => println!("struct with {} fields", info.fields.len()),
+///     TypeInfo::Enum(info) => println!("enum with {} variants", info.variants.len()),
+///     TypeInfo::Union(info) => println!("union with {} fields", info.fields.len()),
+///     TypeInfo::TypeAlias(info) => println!("alias for {}", info.type_name),
+/// }
+/// # Ok::<(), quarry::QuarryError>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns `QuarryError::TypeNotFound` if no type with the given path exists in
+/// the standard library cache, and `QuarryError::Ambiguous` if `name` is a bare
+/// type name rather than a path.
+pub fn mine_type_info(name: &str) -> Result<TypeInfo> {
+    debug!("Mining type information for: '{}'", name);
+
+    match stdlib::mine_stdlib_type_info(name, &QuarryConfig::default()) {
+        Ok(info) => {
+            debug!("Successfully found '{}' ({})", name, info.kind());
+            Ok(info)
+        }
+        Err(e) => {
+            debug!("Failed to find type '{}': {:?}", name, e);
+            Err(e)
+        }
+    }
+}
+
+/// Initialize the standard library cache
+///
+/// This function forces initialization of the standard library type cache.
+/// Normally, the cache is initialized lazily on first use, but this can be
+/// called explicitly if you want to handle any initialization errors upfront
+/// or warm up the cache for better performance.
+///
+/// The initialization process analyzes the actual standard library installed
+/// on your system using rustdoc JSON generation, which requires the nightly
+/// toolchain and rust-src component. Every configured crate is parsed, so a
+/// broken rustdoc JSON file is reported here rather than by a later query, and
+/// the complete cache is written to disk for later processes.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use quarry::init_stdlib_cache;
+///
+/// // Initialize the cache upfront to handle any errors early
+/// init_stdlib_cache()?;
+///
+/// // Now subsequent calls will be faster
+/// let result = quarry::mine_struct_info("alloc::string::String")?;
+/// # Ok::<(), quarry::QuarryError>(())
+/// ```
+///
+/// # Errors
+///
+/// May return errors related to rustdoc JSON generation or standard library
+/// analysis. A missing toolchain is reported as [`QuarryError::NightlyMissing`]
+/// and a missing rust-src component as [`QuarryError::RustSrcMissing`].
+pub fn init_stdlib_cache() -> Result<()> {
+    debug!("Initializing standard library cache");
+
+    // Parse every documented crate rather than looking up one type, which
+    // would leave the crates it does not need pending
+    match stdlib::init_stdlib_cache_with_progress(&QuarryConfig::default(), &mut |_| {}) {
+        Ok(()) => {
+            debug!("Standard library cache initialization completed successfully");
+            Ok(())
+        }
+        Err(e) => {
+            debug!("Error during cache initialization: {:?}", e);
+            Err(e)
+        }
+    }
+}
+
+/// Check that the environment can support Quarry
+///
+/// A fast preflight for the requirements of standard library analysis: it
+/// queries the nightly toolchain's sysroot and looks for the rust-src sources,
+/// exactly like the first cache build would, but without running the expensive
+/// `cargo doc` step or touching the cache.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use quarry::check_requirements;
+///
+/// let requirements = check_requirements()?;
+/// if !requirements.nightly_installed {
+///     eprintln!("Run: rustup toolchain install nightly");
+/// } else if !requirements.rust_src_installed {
+///     eprintln!("Run: rustup component add rust-src --toolchain nightly");
+/// } else if let Some(version) = &requirements.nightly_version {
+///     println!("Ready: {}", version);
+/// }
+/// # Ok::<(), quarry::QuarryError>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns `QuarryError::Io` if `rustc` cannot be run at all (for example when
+/// rustup is not installed). Missing components are reported through the
+/// returned [`Requirements`] instead.
+pub fn check_requirements() -> Result<Requirements> {
+    stdlib::check_requirements(&QuarryConfig::default())
+}
+
+/// Initialize the standard library cache, reporting progress along the way
+///
+/// Behaves like [`init_stdlib_cache`], but calls `cb` as initialization moves
+/// through its stages, so a CLI can show a spinner or status line during a
+/// cold start instead of appearing frozen.
+///
+/// Stages that are not needed are skipped: a cache that is already in memory
+/// only reports [`InitStage::Done`], one restored from disk skips straight to
+/// it, and reused rustdoc JSON skips [`InitStage::RunningCargoDoc`]. The crates
+/// are parsed concurrently, so their [`InitStage::ParsingCrate`] stages arrive
+/// together.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use quarry::{init_stdlib_cache_with_progress, InitStage};
+///
+/// init_stdlib_cache_with_progress(|stage| match stage {
+///     InitStage::LocatingSource => eprintln!("Locating standard library sources..."),
+///     InitStage::RunningCargoDoc => eprintln!("Running cargo doc (this takes a while)..."),
+///     InitStage::ParsingCrate(name) => eprintln!("Parsing {}...", name),
+///     InitStage::Done => eprintln!("Ready"),
+/// })?;
+/// # Ok::<(), quarry::QuarryError>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns the same errors as [`init_stdlib_cache`]. No [`InitStage::Done`]
+/// is reported when initialization fails.
+pub fn init_stdlib_cache_with_progress(mut cb: impl FnMut(InitStage)) -> Result<()> {
+    stdlib::init_stdlib_cache_with_progress(&QuarryConfig::default(), &mut cb)
+}
+
+/// Clear the standard library cache
+///
+/// This function clears the cached standard library type information.
+/// The cache will be rebuilt on the next call to any function that requires it.
+/// This can be useful for testing or if you want to refresh the cache
+/// after updating your Rust installation.
+///
+/// # Examples
+///
+/// ```rust
+/// use quarry::clear_stdlib_cache;
+///
+/// // Clear the cache to force rebuilding
+/// clear_stdlib_cache();
+///
+/// // The next call will rebuild the cache from scratch
+/// let result = quarry::mine_struct_info("alloc::string::String");
+/// ```
+pub fn clear_stdlib_cache() {
+    debug!("Clearing standard library cache");
+    stdlib::clear_cache();
+    debug!("Standard library cache cleared");
+}
+
+/// Set how many parsed structs the standard library cache keeps in memory
+///
+/// With `Some(capacity)`, the cached structs are written to a temporary spill
+/// file and only the `capacity` most recently looked up ones stay in memory;
+/// others are read back from the file on demand, evicting the least recently
+/// used. Paths, enums, unions and aliases always stay in memory, and analysis
+/// results do not change. `None`, the default, reads every struct back and
+/// keeps them all in memory, which is fastest when memory is not a concern.
+///
+/// The setting is process-wide: it applies to the current cache right away
+/// and to every cache built later, whichever configuration builds it.
+/// Clearing the cache does not reset it.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use quarry::{mine_struct_info, set_struct_cache_capacity};
+///
+/// set_struct_cache_capacity(Some(64))?;
+/// let info = mine_struct_info("alloc::string::String")?;
+/// # Ok::<(), quarry::QuarryError>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns `QuarryError::Io` if the spill file cannot be created, written or
+/// read back; the cache and the setting are then left as they were. Lookups
+/// that need an evicted struct fail the same way if the spill file has been
+/// removed or damaged in the meantime.
+pub fn set_struct_cache_capacity(capacity: Option<usize>) -> Result<()> {
+    stdlib::set_struct_cache_capacity(capacity)
+}
+
+/// Clear the persistent on-disk cache
+///
+/// Quarry stores parsed standard library information on disk so that later
+/// processes can skip the expensive rustdoc generation. This removes those
+/// files; the in-memory cache is left untouched (see [`clear_stdlib_cache`]).
+///
+/// The cache lives in `$QUARRY_CACHE_DIR` if set, otherwise in the platform
+/// cache directory (e.g. `~/.cache/quarry`).
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use quarry::{clear_disk_cache, clear_stdlib_cache};
+///
+/// // Force a full re-analysis on the next query
+/// clear_stdlib_cache();
+/// clear_disk_cache()?;
+/// # Ok::<(), quarry::QuarryError>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns `QuarryError::Io` if a cache file cannot be removed.
+pub fn clear_disk_cache() -> Result<()> {
+    debug!("Clearing persistent disk cache");
+    disk_cache::clear()
+}
+
+/// Get the rustdoc JSON format version the cache was built from
+///
+/// Initializes the cache if needed. Useful for checking which schema a nightly
+/// emitted, or for reporting alongside a bug. See
+/// [`SUPPORTED_RUSTDOC_FORMAT_VERSIONS`] for the versions Quarry accepts.
+///
+/// # Returns
+///
+/// The `format_version` of the parsed JSON, or `None` if the cache came from a
+/// source that doesn't record it (e.g., a cache file written by an older Quarry)
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use quarry::rustdoc_format_version;
+///
+/// if let Some(version) = rustdoc_format_version()? {
+///     println!("Built from rustdoc JSON format {}", version);
+/// }
+/// # Ok::<(), quarry::QuarryError>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the standard library cache cannot be initialized,
+/// including [`QuarryError::UnsupportedRustdocFormat`] when the toolchain's
+/// rustdoc emits a format outside the supported range.
+pub fn rustdoc_format_version() -> Result<Option<u32>> {
+    stdlib::rustdoc_format_version()
+}
+
+/// Get statistics about the standard library cache
+///
+/// Returns a tuple of (number_of_cached_types, is_initialized).
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use quarry::cache_stats;
+///
+/// let (count, initialized) = cache_stats()?;
+/// println!("Cache contains {} types, initialized: {}", count, initialized);
+/// # Ok::<(), quarry::QuarryError>(())
+/// ```
+pub fn cache_stats() -> Result<(usize, bool)> {
+    stdlib::cache_stats()
+}
+
+/// List all available standard library struct types
+///
+/// Returns a sorted list of all struct types found in the standard library.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use quarry::list_stdlib_structs;
+///
+/// let structs = list_stdlib_structs()?;
+/// for struct_name in structs.iter().take(10) {
+///     println!("  {}", struct_name);
+/// }
+/// # Ok::<(), quarry::QuarryError>(())
+/// ```
+pub fn list_stdlib_structs() -> Result<Vec<String>> {
+    stdlib::list_stdlib_structs()
+}
+
+/// List all standard library structs in a chosen order
+///
+/// Like [`list_stdlib_structs`], but returns the full [`StructInfo`] of each
+/// struct so that orders such as [`SortOrder::ByFieldCountDesc`] can look at
+/// more than the name. `SortOrder::ByPath` yields the structs in the same
+/// order as `list_stdlib_structs` yields their names.
+///
+/// # Arguments
+///
+/// * `order` - How to sort the structs
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use quarry::{SortOrder, list_stdlib_structs_sorted};
+///
+/// // The ten structs with the most fields
+/// for info in list_stdlib_structs_sorted(SortOrder::ByFieldCountDesc)?.iter().take(10) {
+///     println!("{:>3} {}", info.fields.len(), info.name);
+/// }
+/// # Ok::<(), quarry::QuarryError>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the standard library cache cannot be initialized.
+pub fn list_stdlib_structs_sorted(order: SortOrder) -> Result<Vec<StructInfo>> {
+    stdlib::list_stdlib_structs_sorted(order)
+}
+
+/// Count the standard library struct types
+///
+/// Equivalent to `list_stdlib_structs()?.len()`, but reads the length of the
+/// cache directly instead of building and sorting the name list. Unlike
+/// [`cache_stats`], it initializes the cache if needed, and it counts structs
+/// only rather than types of every kind.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use quarry::type_count;
+///
+/// println!("The standard library defines {} structs", type_count()?);
+/// # Ok::<(), quarry::QuarryError>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the standard library cache cannot be initialized.
+pub fn type_count() -> Result<usize> {
+    stdlib::stdlib_type_count()
+}
+
+/// List the standard library structs defined under a module
+///
+/// Returns the sorted full paths of all structs whose path starts with
+/// `module_prefix`, including those in nested modules. A trailing `::` on the
+/// prefix is optional.
+///
+/// # Arguments
+///
+/// * `module_prefix` - A crate or module path (e.g., "std::collections" or "alloc")
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use quarry::list_structs_under;
+///
+/// for name in list_structs_under("std::collections")? {
+///     println!("  {}", name);
+/// }
+/// # Ok::<(), quarry::QuarryError>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the standard library cache cannot be initialized. A
+/// prefix that matches nothing yields an empty vector.
+pub fn list_structs_under(module_prefix: &str) -> Result<Vec<String>> {
+    stdlib::list_stdlib_structs_under(module_prefix)
+}
+
+/// Mine every standard library struct defined in a module
+///
+/// The whole-module companion to [`mine_struct_info`]: returns the structs
+/// whose `module_path` equals `module_path`, ordered by simple name. Structs
+/// in submodules are not included; use [`list_structs_under`] to cover a whole
+/// subtree.
+///
+/// Module paths are the defining paths recorded by rustdoc, so `HashMap` is
+/// found under `std::collections::hash::map` rather than `std::collections`.
+///
+/// # Arguments
+///
+/// * `module_path` - Full module path (e.g., "alloc::string")
+///
+/// # Returns
+///
+/// The module's structs, or an empty vector if it defines none.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use quarry::mine_module;
+///
+/// for info in mine_module("alloc::string")? {
+///     println!("{} has {} fields", info.simple_name, info.fields.len());
+/// }
+/// # Ok::<(), quarry::QuarryError>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the standard library cache cannot be initialized.
+pub fn mine_module(module_path: &str) -> Result<Vec<StructInfo>> {
+    stdlib::mine_stdlib_module(module_path)
 }
 
-pub type Result<T> = std::result::Result<T, QuarryError>;
-
-/// Complete information about a struct
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-pub struct StructInfo {
-    /// The full name of the struct (e.g., "std::string::String")
-    pub name: String,
-    /// The simple name without module path (e.g., "String")
-    pub simple_name: String,
-    /// The module path (e.g., "std::string")
-    pub module_path: String,
-    /// List of fields in the struct
-    pub fields: Vec<FieldInfo>,
-    /// Whether the struct is a tuple struct
-    pub is_tuple_struct: bool,
-    /// Whether the struct is a unit struct
-    pub is_unit_struct: bool,
+/// Group the standard library struct paths by crate
+///
+/// Keys are crate names such as `std`, `alloc` and `core`; each value holds the
+/// full paths of that crate's structs in sorted order.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use quarry::structs_by_crate;
+///
+/// for (crate_name, structs) in structs_by_crate()? {
+///     println!("{}: {} structs", crate_name, structs.len());
+/// }
+/// # Ok::<(), quarry::QuarryError>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the standard library cache cannot be initialized.
+pub fn structs_by_crate() -> Result<HashMap<String, Vec<String>>> {
+    stdlib::stdlib_structs_by_crate()
 }
 
-/// Information about a struct field
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-pub struct FieldInfo {
-    /// The name of the field
-    pub name: String,
-    /// The type of the field as a string
-    pub type_name: String,
-    /// Whether the field is public
-    pub is_public: bool,
-    /// The name of the struct this field belongs to
-    pub struct_name: String,
+/// Group the standard library struct paths by module
+///
+/// Keys are full module paths such as `alloc::string`, in sorted order; each
+/// value holds the full paths of the structs defined directly in that module.
+/// Submodules get their own entries.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use quarry::structs_by_module;
+///
+/// let modules = structs_by_module()?;
+/// if let Some(structs) = modules.get("alloc::string") {
+///     println!("alloc::string defines {:?}", structs);
+/// }
+/// # Ok::<(), quarry::QuarryError>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the standard library cache cannot be initialized.
+pub fn structs_by_module() -> Result<BTreeMap<String, Vec<String>>> {
+    stdlib::stdlib_structs_by_module()
 }
 
-impl StructInfo {
-    /// Create a new StructInfo with the given name and extract module path components
-    pub fn new(name: &str) -> Self {
-        let (module_path, simple_name) = if let Some(pos) = name.rfind("::") {
-            (name[..pos].to_string(), name[pos + 2..].to_string())
-        } else {
-            (String::new(), name.to_string())
-        };
-
-        Self {
-            name: name.to_string(),
-            simple_name,
-            module_path,
-            fields: Vec::new(),
-            is_tuple_struct: false,
-            is_unit_struct: false,
-        }
-    }
+/// Compute aggregate statistics over every cached standard library struct
+///
+/// This is a read-only pass over the cache: struct kinds, the average and
+/// maximum number of fields, and the ten most common field types.
+///
+/// # Returns
+///
+/// A [`CacheStatistics`] snapshot of the current cache
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use quarry::cache_statistics;
+///
+/// let stats = cache_statistics()?;
+/// println!(
+///     "{} structs ({} tuple, {} unit), {:.1} fields on average",
+///     stats.total_structs, stats.tuple_structs, stats.unit_structs, stats.average_fields
+/// );
+/// for (type_name, count) in &stats.most_common_field_types {
+///     println!("  {:>5}  {}", count, type_name);
+/// }
+/// # Ok::<(), quarry::QuarryError>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the standard library cache cannot be initialized.
+pub fn cache_statistics() -> Result<CacheStatistics> {
+    stdlib::stdlib_cache_statistics()
 }
 
-/// Mine struct information from the Rust standard library
+/// Call a function for every cached standard library struct
 ///
-/// This function queries the standard library cache for information about a specific struct.
-/// It requires the full module path to ensure unambiguous type resolution (e.g.,
-/// "alloc::string::String" rather than just "String").
+/// Unlike [`list_stdlib_structs`] followed by one [`mine_struct_info`] call per
+/// name, this walks the cache once without allocating a list of names or
+/// re-acquiring the cache lock for each type. Structs are visited in no
+/// particular order.
+///
+/// The callback runs while the cache lock is held. It must not call back into
+/// Quarry (for example [`mine_struct_info`]), as that can deadlock.
 ///
 /// # Arguments
 ///
-/// * `name` - The full module path of the struct (e.g., "alloc::string::String")
+/// * `f` - Called once with each cached struct
 ///
 /// # Examples
 ///
 /// ```rust,no_run
-/// use quarry::mine_struct_info;
+/// use quarry::for_each_struct;
 ///
-/// // Standard library struct with full path
-/// let string_info = mine_struct_info("alloc::string::String")?;
-/// println!("Struct: {}", string_info.name);
-/// println!("Fields: {}", string_info.fields.len());
+/// let mut private_fields = 0;
+/// for_each_struct(|info| {
+///     private_fields += info.fields.iter().filter(|field| !field.is_public()).count();
+/// })?;
+/// println!("{} private fields in total", private_fields);
+/// # Ok::<(), quarry::QuarryError>(())
+/// ```
 ///
-/// // Vec from alloc crate
-/// let vec_info = mine_struct_info("alloc::vec::Vec")?;
-/// println!("Is tuple struct: {}", vec_info.is_tuple_struct);
+/// # Errors
 ///
-/// // HashMap from std collections
-/// let map_info = mine_struct_info("std::collections::HashMap")?;
-/// for field in &map_info.fields {
-///     println!("  Field: {} -> {}", field.name, field.type_name);
+/// Returns an error if the standard library cache cannot be initialized.
+pub fn for_each_struct(f: impl FnMut(&StructInfo)) -> Result<()> {
+    stdlib::for_each_stdlib_struct(f)
+}
+
+/// List the standard library structs that satisfy a predicate
+///
+/// Generalizes [`list_stdlib_structs`] to arbitrary filters. The predicate
+/// receives each cached struct by reference, so only the matches are cloned.
+///
+/// The predicate runs while the cache lock is held; calling back into Quarry
+/// from inside it can deadlock.
+///
+/// # Arguments
+///
+/// * `pred` - Returns `true` for the structs to keep
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use quarry::list_structs_where;
+///
+/// // Tuple structs in alloc with at least one private field
+/// let structs = list_structs_where(|info| {
+///     info.is_tuple_struct
+///         && info.name.starts_with("alloc::")
+///         && info.fields.iter().any(|field| !field.is_public())
+/// })?;
+/// for info in &structs {
+///     println!("  {}", info.name);
 /// }
 /// # Ok::<(), quarry::QuarryError>(())
 /// ```
 ///
 /// # Errors
 ///
-/// Returns `QuarryError::TypeNotFound` if the specified struct is not found in the
-/// standard library cache. Make sure you're using the complete module path.
-pub fn mine_struct_info(name: &str) -> Result<StructInfo> {
-    debug!("Mining struct information for: '{}'", name);
-
-    match stdlib::mine_stdlib_struct_info(name) {
-        Ok(info) => {
-            debug!(
-                "Successfully found '{}' with {} fields",
-                name,
-                info.fields.len()
-            );
-            Ok(info)
-        }
-        Err(e) => {
-            debug!("Failed to find struct '{}': {:?}", name, e);
-            Err(e)
-        }
-    }
+/// Returns an error if the standard library cache cannot be initialized.
+pub fn list_structs_where(pred: impl Fn(&StructInfo) -> bool) -> Result<Vec<StructInfo>> {
+    stdlib::list_stdlib_structs_where(pred)
 }
 
-/// Initialize the standard library cache
-///
-/// This function forces initialization of the standard library type cache.
-/// Normally, the cache is initialized lazily on first use, but this can be
-/// called explicitly if you want to handle any initialization errors upfront
-/// or warm up the cache for better performance.
+/// Build the graph of which standard library structs reference which
 ///
-/// The initialization process analyzes the actual standard library installed
-/// on your system using rustdoc JSON generation, which requires the nightly
-/// toolchain and rust-src component.
+/// Walks every field type of every cached struct and records an edge for each
+/// struct it mentions. Field types name structs the way the source wrote them
+/// (e.g., `Vec` or `ptr::NonNull`), so each name is matched against the cached
+/// paths ending in it, preferring a match in the same module, then in the same
+/// crate. Names that stay ambiguous, or name no cached struct (enums, traits,
+/// generic parameters), produce no edge.
 ///
 /// # Examples
 ///
 /// ```rust,no_run
-/// use quarry::init_stdlib_cache;
-///
-/// // Initialize the cache upfront to handle any errors early
-/// init_stdlib_cache()?;
+/// use quarry::build_dependency_graph;
 ///
-/// // Now subsequent calls will be faster
-/// let result = quarry::mine_struct_info("alloc::string::String")?;
+/// let graph = build_dependency_graph()?;
+/// println!("String uses {:?}", graph.dependencies_of("alloc::string::String"));
+/// println!("Vec is used by {} structs", graph.dependents_of("alloc::vec::Vec").len());
 /// # Ok::<(), quarry::QuarryError>(())
 /// ```
 ///
 /// # Errors
 ///
-/// May return errors related to rustdoc JSON generation or standard library
-/// analysis. Common issues include missing nightly toolchain or rust-src component.
-pub fn init_stdlib_cache() -> Result<()> {
-    debug!("Initializing standard library cache");
+/// Returns an error if the standard library cache cannot be initialized.
+pub fn build_dependency_graph() -> Result<DependencyGraph> {
+    stdlib::build_dependency_graph()
+}
 
-    // Force cache initialization by attempting to query a known type
-    // We use alloc::string::String as it should always exist
-    match stdlib::mine_stdlib_struct_info("alloc::string::String") {
-        Ok(_) => {
-            debug!("Standard library cache initialization completed successfully");
-            Ok(())
-        }
-        Err(QuarryError::TypeNotFound(_)) => {
-            // If String is not found, the cache was still initialized, just empty
-            debug!("Cache initialized but String type not found (may be expected)");
-            Ok(())
-        }
-        Err(e) => {
-            debug!("Error during cache initialization: {:?}", e);
-            Err(e)
-        }
-    }
+/// Render structs as a Mermaid class diagram
+///
+/// Produces a `classDiagram` that GitHub and most Markdown renderers draw
+/// natively when placed in a ```` ```mermaid ```` block. Each struct becomes a
+/// class labelled with its simple name and listing its fields, prefixed with
+/// `+` when public and `-` otherwise. When a field type mentions another
+/// struct of `structs`, a composition arrow (`*--`) links the two; names are
+/// matched the same way as in [`build_dependency_graph`].
+///
+/// Class identifiers are derived from the full paths (`alloc::vec::Vec`
+/// becomes `alloc__vec__Vec`), and generic arguments in field types use
+/// Mermaid's `~` syntax (`Vec<T>` becomes `Vec~T~`), since angle brackets
+/// are not valid there.
+///
+/// # Arguments
+///
+/// * `structs` - The structs to draw; arrows only point between these
+///
+/// # Returns
+///
+/// The Mermaid source, starting with `classDiagram`
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use quarry::{mine_struct_info, to_mermaid};
+///
+/// let structs = vec![
+///     mine_struct_info("alloc::string::String")?,
+///     mine_struct_info("alloc::vec::Vec")?,
+/// ];
+/// println!("```mermaid\n{}```", to_mermaid(&structs));
+/// # Ok::<(), quarry::QuarryError>(())
+/// ```
+pub fn to_mermaid(structs: &[StructInfo]) -> String {
+    stdlib::render_mermaid(structs)
 }
 
-/// Clear the standard library cache
+/// Find every standard library struct with the given simple name
 ///
-/// This function clears the cached standard library type information.
-/// The cache will be rebuilt on the next call to any function that requires it.
-/// This can be useful for testing or if you want to refresh the cache
-/// after updating your Rust installation.
+/// Unlike [`mine_struct_info`], this accepts a bare name such as `"Iter"` and
+/// returns all structs called that, from every module. It is meant for
+/// exploration; use the full path of the match you want with the other APIs.
+///
+/// # Arguments
+///
+/// * `simple` - The struct name without module path (e.g., "Iter")
+///
+/// # Returns
+///
+/// All matching structs sorted by full path, or an empty vector if there are
+/// none or the standard library cache cannot be initialized
 ///
 /// # Examples
 ///
-/// ```rust
-/// use quarry::clear_stdlib_cache;
+/// ```rust,no_run
+/// use quarry::mine_struct_info_by_simple_name;
 ///
-/// // Clear the cache to force rebuilding
-/// clear_stdlib_cache();
+/// for info in mine_struct_info_by_simple_name("Iter") {
+///     println!("{} ({} fields)", info.name, info.fields.len());
+/// }
+/// ```
+pub fn mine_struct_info_by_simple_name(simple: &str) -> Vec<StructInfo> {
+    stdlib::mine_stdlib_structs_by_simple_name(simple)
+}
+
+/// Find the full struct paths a bare or partial name could refer to
 ///
-/// // The next call will rebuild the cache from scratch
-/// let result = quarry::mine_struct_info("alloc::string::String");
+/// Quarry's lookups require full paths, so a name like `"HashMap"` is simply
+/// not found. This returns every struct whose simple name equals `partial`, or
+/// whose path contains it as a whole run of segments (e.g., `"map::HashMap"`),
+/// so tools can answer "not found, but these paths match". Both the defining
+/// path and the preferred `std::` re-export path are searched.
+///
+/// # Arguments
+///
+/// * `partial` - A simple name or trailing part of a path (e.g., "HashMap")
+///
+/// # Returns
+///
+/// The matching defining paths in sorted order; empty if nothing matches.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use quarry::{candidate_paths, is_stdlib_struct};
+///
+/// let name = "HashMap";
+/// if !is_stdlib_struct(name) {
+///     println!("'{}' not found, did you mean one of:", name);
+///     for path in candidate_paths(name)? {
+///         println!("  {}", path);
+///     }
+/// }
+/// # Ok::<(), quarry::QuarryError>(())
 /// ```
-pub fn clear_stdlib_cache() {
-    debug!("Clearing standard library cache");
-    stdlib::clear_cache();
-    debug!("Standard library cache cleared");
+///
+/// # Errors
+///
+/// Returns an error if the standard library cache cannot be initialized.
+pub fn candidate_paths(partial: &str) -> Result<Vec<String>> {
+    stdlib::candidate_paths(partial)
 }
 
-/// Get statistics about the standard library cache
+/// Search cached struct names for a query, for interactive discovery
 ///
-/// Returns a tuple of (number_of_cached_types, is_initialized).
+/// Unlike the exact lookups such as [`mine_struct_info`], this forgives
+/// imprecise input: by default the query is compared case-insensitively with
+/// each struct's simple name, and [`SearchOptions`] can make it case-sensitive
+/// or compare with full paths instead. Results are ranked, each group sorted
+/// by path:
+///
+/// 1. Exact matches of the simple name (or, when matching full paths, of the
+///    full path)
+/// 2. Names or paths starting with the query
+/// 3. Names or paths containing the query anywhere else
+///
+/// # Arguments
+///
+/// * `query` - Text to look for (e.g., "hashmap" or "vec::into")
+/// * `opts` - Case sensitivity and what to match against
+///
+/// # Returns
+///
+/// The full paths of the matching structs, best matches first; empty if
+/// nothing matches.
 ///
 /// # Examples
 ///
 /// ```rust,no_run
-/// use quarry::cache_stats;
+/// use quarry::{SearchOptions, search_structs};
 ///
-/// let (count, initialized) = cache_stats()?;
-/// println!("Cache contains {} types, initialized: {}", count, initialized);
+/// // HashMap itself first, then names that start with or contain "hashmap"
+/// for path in search_structs("hashmap", SearchOptions::default())? {
+///     println!("{}", path);
+/// }
+///
+/// let full_path = SearchOptions {
+///     match_full_path: true,
+///     case_sensitive: true,
+///     ..Default::default()
+/// };
+/// let in_vec = search_structs("alloc::vec::", full_path)?;
 /// # Ok::<(), quarry::QuarryError>(())
 /// ```
-pub fn cache_stats() -> Result<(usize, bool)> {
-    stdlib::cache_stats()
+///
+/// # Errors
+///
+/// Returns an error if the standard library cache cannot be initialized.
+pub fn search_structs(query: &str, opts: SearchOptions) -> Result<Vec<String>> {
+    stdlib::search_stdlib_structs(query, opts)
 }
 
-/// List all available standard library struct types
+/// Find every standard library struct with a field whose type mentions a name
 ///
-/// Returns a sorted list of all struct types found in the standard library.
+/// Scans the `type_name` of every field of every cached struct, answering
+/// questions such as "which structs hold a `NonNull`?". Matching is a plain,
+/// case-sensitive substring test on the rendered type, so `"NonNull"` matches
+/// `NonNull<T>`, `Option<NonNull<Node<T>>>` and `ptr::NonNull<u8>`, but also
+/// any type whose name merely contains it. For exact matches, walk the
+/// structured [`FieldInfo::ty`] (e.g., with [`list_structs_where`]) or use
+/// [`build_dependency_graph`], which resolves field types to struct paths.
+///
+/// # Arguments
+///
+/// * `type_substring` - Text to look for in field types (e.g., "NonNull")
+///
+/// # Returns
+///
+/// The full paths of the matching structs in sorted order; empty if no field
+/// type contains `type_substring`.
 ///
 /// # Examples
 ///
 /// ```rust,no_run
-/// use quarry::list_stdlib_structs;
+/// use quarry::structs_with_field_type;
 ///
-/// let structs = list_stdlib_structs()?;
-/// for struct_name in structs.iter().take(10) {
-///     println!("  {}", struct_name);
+/// for path in structs_with_field_type("NonNull")? {
+///     println!("{} holds a NonNull", path);
 /// }
 /// # Ok::<(), quarry::QuarryError>(())
 /// ```
-pub fn list_stdlib_structs() -> Result<Vec<String>> {
-    stdlib::list_stdlib_structs()
+///
+/// # Errors
+///
+/// Returns an error if the standard library cache cannot be initialized.
+pub fn structs_with_field_type(type_substring: &str) -> Result<Vec<String>> {
+    stdlib::structs_with_field_type(type_substring)
 }
 
 /// Check if a type name refers to a standard library struct
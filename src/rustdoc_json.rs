@@ -0,0 +1,545 @@
+//! Typed model of the rustdoc JSON output format
+//!
+//! Only the parts of the format that Quarry reads are modeled; serde skips every
+//! other key without allocating for it, which keeps peak memory low when parsing
+//! the large `std.json`. Values whose shape varies between format versions, or
+//! that may grow new variants on future nightlies, are wrapped in [`Lenient`] so
+//! an unrecognized shape degrades to `None` instead of failing the whole file.
+
+use serde::Deserialize;
+use serde::de::{self, Deserializer, IgnoredAny, Visitor};
+use std::collections::HashMap;
+use std::fmt;
+
+/// A value that is `None` when its JSON shape was not recognized
+#[derive(Debug)]
+pub(crate) struct Lenient<T>(pub(crate) Option<T>);
+
+impl<T> Lenient<T> {
+    /// The parsed value, if its shape was recognized
+    pub(crate) fn get(&self) -> Option<&T> {
+        self.0.as_ref()
+    }
+}
+
+impl<T> Default for Lenient<T> {
+    fn default() -> Self {
+        Lenient(None)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Lenient<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr<T> {
+            Known(T),
+            Unknown(IgnoredAny),
+        }
+
+        Ok(match Repr::deserialize(deserializer)? {
+            Repr::Known(value) => Lenient(Some(value)),
+            Repr::Unknown(IgnoredAny) => Lenient(None),
+        })
+    }
+}
+
+/// An item ID
+///
+/// Current formats use integers (`42`), older ones strings (`"0:42:1234"`).
+/// Both are normalized to the string form used for `index` keys.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct Id(pub(crate) String);
+
+impl<'de> Deserialize<'de> for Id {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct IdVisitor;
+
+        impl Visitor<'_> for IdVisitor {
+            type Value = Id;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an integer or string item ID")
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Id, E> {
+                Ok(Id(v.to_string()))
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Id, E> {
+                Ok(Id(v.to_string()))
+            }
+        }
+
+        deserializer.deserialize_any(IdVisitor)
+    }
+}
+
+/// Just the version of a rustdoc JSON document
+///
+/// Used to explain a document that failed to parse as a [`Crate`]; every other
+/// key is skipped.
+#[derive(Debug, Deserialize)]
+pub(crate) struct FormatVersion {
+    pub(crate) format_version: u32,
+}
+
+/// The top-level document produced by `rustdoc --output-format json`
+#[derive(Debug, Deserialize)]
+pub(crate) struct Crate {
+    /// The schema version, bumped by rustdoc on every format change
+    pub(crate) format_version: u32,
+    /// ID of the crate's root module
+    pub(crate) root: Id,
+    /// Every item defined in the crate, keyed by ID
+    pub(crate) index: HashMap<Id, Item>,
+    /// Canonical paths of local and external items referenced by the crate
+    #[serde(default)]
+    pub(crate) paths: HashMap<Id, ItemSummary>,
+}
+
+/// An entry of the `paths` map
+#[derive(Debug, Deserialize)]
+pub(crate) struct ItemSummary {
+    /// Path segments starting with the crate name (e.g. `["core", "result", "Result"]`)
+    pub(crate) path: Vec<String>,
+}
+
+/// A single entry of the `index`
+#[derive(Debug, Deserialize)]
+pub(crate) struct Item {
+    pub(crate) id: Id,
+    /// `None` for items without a name, such as impls
+    #[serde(default)]
+    pub(crate) name: Option<String>,
+    /// `None` for items synthesized by the compiler
+    #[serde(default)]
+    pub(crate) span: Option<Span>,
+    #[serde(default)]
+    pub(crate) visibility: Lenient<RawVisibility>,
+    #[serde(default)]
+    pub(crate) docs: Option<String>,
+    #[serde(default)]
+    pub(crate) attrs: Vec<Lenient<Attribute>>,
+    /// `None` unless the item is `#[deprecated]`
+    #[serde(default)]
+    pub(crate) deprecation: Option<Deprecation>,
+    pub(crate) inner: ItemInner,
+}
+
+/// The contents of a `#[deprecated]` attribute
+#[derive(Debug, Deserialize)]
+pub(crate) struct Deprecation {
+    #[serde(default)]
+    pub(crate) since: Option<String>,
+    #[serde(default)]
+    pub(crate) note: Option<String>,
+}
+
+/// The kind-specific payload of an item
+///
+/// rustdoc encodes this as an object with a single key naming the kind; the
+/// kinds Quarry does not read (traits, macros, ...) are skipped.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub(crate) struct ItemInner {
+    #[serde(rename = "struct")]
+    pub(crate) struct_: Option<Struct>,
+    #[serde(rename = "enum")]
+    pub(crate) enum_: Option<Enum>,
+    #[serde(rename = "union")]
+    pub(crate) union_: Option<Union>,
+    /// Called `typedef` in older formats
+    #[serde(alias = "typedef")]
+    pub(crate) type_alias: Option<TypeAlias>,
+    pub(crate) variant: Option<Variant>,
+    pub(crate) module: Option<Module>,
+    /// Called `import` in older formats
+    #[serde(rename = "use", alias = "import")]
+    pub(crate) use_: Option<Use>,
+    #[serde(rename = "impl")]
+    pub(crate) impl_: Option<Impl>,
+    pub(crate) function: Option<Function>,
+    pub(crate) assoc_const: Option<AssocConst>,
+    /// The field's type
+    pub(crate) struct_field: Option<Lenient<Type>>,
+}
+
+/// A source location
+#[derive(Debug, Deserialize)]
+pub(crate) struct Span {
+    pub(crate) filename: String,
+    /// 1-based (line, column)
+    pub(crate) begin: (u32, u32),
+    /// 1-based (line, column)
+    pub(crate) end: (u32, u32),
+}
+
+/// An attribute applied to an item
+///
+/// Current formats encode well-known attributes structurally (`{"repr": {...}}`)
+/// and older ones as their source text (`"#[repr(C)]"`).
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum Attribute {
+    Structured(StructuredAttribute),
+    Legacy(String),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum StructuredAttribute {
+    Repr(AttributeRepr),
+    /// Marks impls generated by `#[derive(...)]`
+    AutomaticallyDerived,
+    NonExhaustive,
+    /// Any other attribute, as its (possibly compiler-normalized) source text
+    Other(String),
+}
+
+/// The combined contents of an item's `#[repr(...)]` attributes
+#[derive(Debug, Deserialize)]
+pub(crate) struct AttributeRepr {
+    /// `"rust"`, `"c"`, `"transparent"` or `"simd"`
+    pub(crate) kind: String,
+    #[serde(default)]
+    pub(crate) align: Option<u64>,
+    #[serde(default)]
+    pub(crate) packed: Option<u64>,
+    /// The primitive integer type, as in `#[repr(u8)]`
+    #[serde(default)]
+    pub(crate) int: Option<String>,
+}
+
+/// Visibility as encoded by rustdoc
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum RawVisibility {
+    Public,
+    Default,
+    Crate,
+    Restricted { path: String },
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct Struct {
+    #[serde(default)]
+    pub(crate) generics: Generics,
+    pub(crate) kind: StructKind,
+    /// IDs of the impl blocks for this struct, including derived ones
+    #[serde(default)]
+    pub(crate) impls: Vec<Id>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum StructKind {
+    Unit,
+    Tuple(FieldIds),
+    Plain { fields: Vec<Id> },
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct Module {
+    /// IDs of the items declared in the module, including `use` items
+    pub(crate) items: Vec<Id>,
+}
+
+/// A `use` declaration
+#[derive(Debug, Deserialize)]
+pub(crate) struct Use {
+    /// The name the item is imported under; the source path's last segment unless renamed
+    pub(crate) name: String,
+    /// ID of the imported item, `None` if it could not be resolved (e.g. primitives)
+    #[serde(default)]
+    pub(crate) id: Option<Id>,
+    /// Called `glob` in older formats
+    #[serde(alias = "glob")]
+    pub(crate) is_glob: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct Union {
+    #[serde(default)]
+    pub(crate) generics: Generics,
+    pub(crate) fields: Vec<Id>,
+    #[serde(default)]
+    pub(crate) impls: Vec<Id>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct TypeAlias {
+    /// The aliased type
+    #[serde(rename = "type")]
+    pub(crate) type_: Lenient<Type>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct Impl {
+    /// `None` for inherent impls
+    #[serde(default, rename = "trait")]
+    pub(crate) trait_: Option<Path>,
+    /// IDs of the associated items (methods, constants, types) defined in the block
+    #[serde(default)]
+    pub(crate) items: Vec<Id>,
+    /// Set for the auto trait impls (`Send`, `Unpin`, ...) rustdoc synthesizes;
+    /// called `synthetic` in older formats
+    #[serde(default, alias = "synthetic")]
+    pub(crate) is_synthetic: bool,
+    /// The implementing type of a blanket impl such as `impl<T> From<T> for T`
+    #[serde(default)]
+    pub(crate) blanket_impl: Option<Lenient<Type>>,
+    /// Set for `impl !Trait for Type`; called `negative` in older formats
+    #[serde(default, alias = "negative")]
+    pub(crate) is_negative: bool,
+}
+
+/// An associated constant, as declared in an impl or trait
+#[derive(Debug, Deserialize)]
+pub(crate) struct AssocConst {
+    #[serde(rename = "type")]
+    pub(crate) type_: Lenient<Type>,
+    /// The value's expression, as written; called `default` in older formats
+    #[serde(default, alias = "default")]
+    pub(crate) value: Option<String>,
+}
+
+/// A function or method
+#[derive(Debug, Deserialize)]
+pub(crate) struct Function {
+    /// Called `decl` in older formats
+    #[serde(alias = "decl")]
+    pub(crate) sig: Lenient<FunctionSignature>,
+    #[serde(default)]
+    pub(crate) header: Lenient<FunctionHeader>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct FunctionSignature {
+    /// (name, type) pairs; a receiver is named `self`
+    pub(crate) inputs: Vec<(String, IgnoredAny)>,
+}
+
+/// Qualifiers of a function; the fields carried a trailing `_` in older formats
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub(crate) struct FunctionHeader {
+    #[serde(alias = "const_")]
+    pub(crate) is_const: bool,
+    #[serde(alias = "unsafe_")]
+    pub(crate) is_unsafe: bool,
+    #[serde(alias = "async_")]
+    pub(crate) is_async: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct Enum {
+    pub(crate) variants: Vec<Id>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct Variant {
+    pub(crate) kind: VariantKind,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum VariantKind {
+    Plain,
+    Tuple(FieldIds),
+    Struct { fields: Vec<Id> },
+}
+
+/// Field IDs of a tuple struct or tuple variant
+///
+/// Stored either directly as an array (`{"tuple": [1, 2]}`) or, in older
+/// formats, wrapped in an object (`{"tuple": {"fields": [1, 2]}}`). Stripped
+/// (private, undocumented) fields appear as `null`.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum FieldIds {
+    Bare(Vec<Option<Id>>),
+    Wrapped { fields: Vec<Option<Id>> },
+}
+
+impl FieldIds {
+    /// The IDs of the fields that were not stripped
+    pub(crate) fn ids(&self) -> Vec<Id> {
+        let (FieldIds::Bare(ids) | FieldIds::Wrapped { fields: ids }) = self;
+        ids.iter().flatten().cloned().collect()
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub(crate) struct Generics {
+    /// The declared lifetime, type and const parameters, in order
+    #[serde(default)]
+    pub(crate) params: Vec<GenericParamDef>,
+    #[serde(default)]
+    pub(crate) where_predicates: Vec<Lenient<WherePredicate>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct GenericParamDef {
+    /// Lifetimes include their leading `'`
+    pub(crate) name: String,
+    #[serde(default)]
+    pub(crate) kind: Lenient<GenericParamDefKind>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum GenericParamDefKind {
+    Lifetime {},
+    Type {
+        /// Set for the parameters rustdoc invents for `impl Trait` arguments;
+        /// called `synthetic` in older formats
+        #[serde(default, alias = "synthetic")]
+        is_synthetic: bool,
+        #[serde(default)]
+        default: Option<Lenient<Type>>,
+    },
+    Const {
+        #[serde(rename = "type")]
+        type_: Lenient<Type>,
+        /// The default value's expression, as written
+        #[serde(default)]
+        default: Option<String>,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) enum WherePredicate {
+    #[serde(rename = "bound_predicate")]
+    Bound {
+        #[serde(rename = "type")]
+        type_: Lenient<Type>,
+        bounds: Vec<Lenient<GenericBound>>,
+        #[serde(default)]
+        generic_params: Vec<GenericParamDef>,
+    },
+    #[serde(rename = "lifetime_predicate")]
+    Lifetime {
+        lifetime: String,
+        outlives: Vec<String>,
+    },
+    #[serde(rename = "eq_predicate")]
+    Eq {
+        lhs: Lenient<Type>,
+        rhs: Lenient<Term>,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Term {
+    Type(Type),
+    Constant(Constant),
+}
+
+/// A const expression, such as the `N` of `Foo<T, N>`
+#[derive(Debug, Deserialize)]
+pub(crate) struct Constant {
+    /// The expression as written (e.g. `"N"` or `"3"`); `"_"` when rustdoc
+    /// could not render it
+    pub(crate) expr: String,
+    /// The evaluated value, if rustdoc computed one
+    #[serde(default)]
+    pub(crate) value: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum GenericBound {
+    TraitBound {
+        #[serde(rename = "trait")]
+        trait_: Path,
+        #[serde(default)]
+        generic_params: Vec<GenericParamDef>,
+        /// `"none"`, `"maybe"` (`?Trait`) or `"maybe_const"` (`~const Trait`)
+        #[serde(default)]
+        modifier: String,
+    },
+    Outlives(String),
+}
+
+/// A type, as found in field types, bounds, and generic arguments
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Type {
+    ResolvedPath(Path),
+    Generic(String),
+    Primitive(String),
+    Tuple(Vec<Lenient<Type>>),
+    BorrowedRef {
+        #[serde(default)]
+        lifetime: Option<String>,
+        is_mutable: bool,
+        #[serde(rename = "type")]
+        type_: Box<Lenient<Type>>,
+    },
+    RawPointer {
+        is_mutable: bool,
+        #[serde(rename = "type")]
+        type_: Box<Lenient<Type>>,
+    },
+    Slice(Box<Lenient<Type>>),
+    Array {
+        #[serde(rename = "type")]
+        type_: Box<Lenient<Type>>,
+        /// The length expression as written (e.g. `"16"` or `"N"`)
+        len: String,
+    },
+    DynTrait(DynTrait),
+    ImplTrait(Vec<Lenient<GenericBound>>),
+    /// An associated type projection such as `<T as Iterator>::Item`
+    QualifiedPath {
+        /// The associated item's name
+        name: String,
+        self_type: Box<Lenient<Type>>,
+        /// `None` for inherent associated types
+        #[serde(default, rename = "trait")]
+        trait_: Option<Path>,
+    },
+}
+
+/// A trait object such as `dyn Error + Send + 'a`
+#[derive(Debug, Deserialize)]
+pub(crate) struct DynTrait {
+    pub(crate) traits: Vec<PolyTrait>,
+    #[serde(default)]
+    pub(crate) lifetime: Option<String>,
+}
+
+/// One trait of a trait object
+#[derive(Debug, Deserialize)]
+pub(crate) struct PolyTrait {
+    #[serde(rename = "trait")]
+    pub(crate) trait_: Path,
+}
+
+/// A path to a type or trait, with its generic arguments
+#[derive(Debug, Deserialize)]
+pub(crate) struct Path {
+    /// Older formats call this `name`
+    #[serde(alias = "name")]
+    pub(crate) path: String,
+    /// ID of the item the path refers to
+    #[serde(default)]
+    pub(crate) id: Option<Id>,
+    #[serde(default)]
+    pub(crate) args: Option<Box<Lenient<GenericArgs>>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum GenericArgs {
+    AngleBracketed { args: Vec<Lenient<GenericArg>> },
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum GenericArg {
+    Type(Type),
+    Const(Constant),
+}
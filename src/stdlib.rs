@@ -3,105 +3,657 @@
 //! This module uses rustdoc JSON output to analyze the actual standard library
 //! installed on the user's system and creates a lookup table for fast access.
 
-use crate::{FieldInfo, QuarryError, Result, StructInfo};
+use crate::disk_cache;
+use crate::rustdoc_json::{self, Crate, GenericBound, Id, Item, Lenient};
+use crate::struct_store::StructStore;
+use crate::{
+    AliasInfo, AssocConst, CacheStatistics, DependencyGraph, Deprecation, EnumInfo, FieldInfo,
+    GenericParam, GenericParamKind, InitStage, MethodInfo, QuarryConfig, QuarryError, Requirements,
+    Result, SUPPORTED_RUSTDOC_FORMAT_VERSIONS, SearchOptions, SortOrder, SourceSpan, Stability,
+    StabilityLevel, StructInfo, Type, TypeInfo, TypeKind, UnionInfo, VariantInfo, VariantKind,
+    Visibility,
+};
 use log::debug;
-use serde_json::Value;
-use std::collections::HashMap;
-use std::sync::{Mutex, OnceLock};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
 
 // Constants for string parsing
 const STD_SRC_PREFIX: &str = "std/src/";
 const ALLOC_SRC_PREFIX: &str = "alloc/src/";
 const CORE_SRC_PREFIX: &str = "core/src/";
+const PROC_MACRO_SRC_PREFIX: &str = "proc_macro/src/";
+const LIBRARY_DIR: &str = "library/";
+const SRC_DIR: &str = "src/";
+/// Every stdlib crate Quarry can document, in the order their types are merged
+pub(crate) const STDLIB_CRATES: [&str; 4] = ["std", "alloc", "core", "proc_macro"];
+/// The crates documented unless `QuarryConfig::crates` says otherwise
+pub(crate) const DEFAULT_STDLIB_CRATES: [&str; 3] = ["std", "alloc", "core"];
 const CRATE_PREFIX: &str = "crate::";
 
+/// Maximum number of re-export steps followed when resolving a std:: path
+const MAX_REEXPORT_HOPS: usize = 8;
+
+/// Maximum number of "did you mean" suggestions in a `TypeNotFound` error
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Maximum edit distance for a cached path to be suggested
+const MAX_SUGGESTION_DISTANCE: usize = 3;
+
+/// Directory (under the system temp dir) where stdlib JSON is kept for reuse
+const RUSTDOC_JSON_DIR: &str = "quarry_stdlib_json";
+
+/// Directory (under the system temp dir) holding a target directory per analyzed crate
+const CRATE_DOCS_DIR: &str = "quarry_crate_docs";
+
+/// File in `RUSTDOC_JSON_DIR` holding the commit hash of the toolchain that
+/// produced the JSON next to it
+const RUSTDOC_STAMP_FILE: &str = "toolchain.stamp";
+
+/// How often a `cargo doc` run with a timeout is checked for completion
+const RUSTDOC_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(100);
+
+/// Re-exports that are pinned instead of derived from `use` items
+///
+/// `std::collections::{HashMap, HashSet}` reach their definitions through a
+/// glob import of the private `hash` module, which makes them the only common
+/// lookups that depend on glob resolution.
+const STD_ALIAS_OVERRIDES: &[(&str, &str)] = &[
+    (
+        "std::collections::HashMap",
+        "std::collections::hash::map::HashMap",
+    ),
+    (
+        "std::collections::HashSet",
+        "std::collections::hash::set::HashSet",
+    ),
+    (
+        "std::collections::hash_map::HashMap",
+        "std::collections::hash::map::HashMap",
+    ),
+    (
+        "std::collections::hash_set::HashSet",
+        "std::collections::hash::set::HashSet",
+    ),
+];
+
 /// Global cache for standard library types
-static STDLIB_CACHE: OnceLock<Mutex<Option<HashMap<String, StructInfo>>>> = OnceLock::new();
+///
+/// The cache is immutable once built, so lookups share a read lock; the write
+/// lock is only taken to build, extend, bound or clear it.
+static STDLIB_CACHE: OnceLock<RwLock<Option<LoadedTypes>>> = OnceLock::new();
+
+/// How many cached structs to keep in memory, or `None` to keep them all
+///
+/// Set through `set_struct_cache_capacity` and applied to every cache built
+/// afterwards. Only changed while holding the write lock of `STDLIB_CACHE`.
+static STRUCT_CACHE_CAPACITY: Mutex<Option<usize>> = Mutex::new(None);
+
+/// The cached types together with the configuration key they were built for
+#[derive(Debug)]
+struct LoadedTypes {
+    /// `QuarryConfig::cache_key` of the configuration used to build `types`
+    cache_key: String,
+    types: StdlibTypes,
+    /// Crates whose saved JSON is parsed on first use, or `None` once `types`
+    /// covers every documented crate
+    pending: Option<PendingCrates>,
+    /// Whether `types` holds types seeded or merged in by the caller, which
+    /// must never be written to the disk cache of the toolchain
+    seeded: bool,
+}
+
+impl LoadedTypes {
+    /// Whether every crate in `needed` has been parsed into `types`
+    fn has_parsed(&self, needed: &[&str]) -> bool {
+        self.pending
+            .as_ref()
+            .is_none_or(|pending| !pending.crates.iter().any(|name| needed.contains(name)))
+    }
+}
+
+/// Stdlib crates whose rustdoc JSON is saved in `RUSTDOC_JSON_DIR` but not parsed yet
+#[derive(Debug)]
+struct PendingCrates {
+    /// The directory holding the saved JSON
+    json_dir: std::path::PathBuf,
+    /// The stamp the JSON carried when the cache was built, re-checked before
+    /// parsing so a concurrent `cargo doc` run for another toolchain is noticed
+    stamp: String,
+    crates: Vec<&'static str>,
+}
+
+/// All type information parsed from one or more rustdoc JSON files, keyed by full path
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(crate) struct StdlibTypes {
+    pub(crate) structs: StructStore,
+    pub(crate) enums: HashMap<String, EnumInfo>,
+    pub(crate) unions: HashMap<String, UnionInfo>,
+    pub(crate) aliases: HashMap<String, AliasInfo>,
+    /// Public re-export paths mapped to the path they re-export
+    /// (e.g., "std::option" → "core::option")
+    pub(crate) reexports: HashMap<String, String>,
+    /// Glob re-exports as (module path, source path) pairs
+    pub(crate) glob_reexports: Vec<(String, String)>,
+    /// The rustdoc JSON `format_version` the types were parsed from, if known
+    #[serde(default)]
+    pub(crate) format_version: Option<u32>,
+    /// Defining paths mapped to all of their re-export paths, preferred first,
+    /// built on first use
+    #[serde(skip)]
+    std_aliases: OnceLock<HashMap<String, Vec<String>>>,
+    /// Struct paths whose entry was parsed from a crate other than the one
+    /// defining them (see `insert_struct_with_full_name`)
+    #[serde(skip)]
+    reexported_structs: HashSet<String>,
+}
+
+impl StdlibTypes {
+    /// Total number of types of every kind
+    pub(crate) fn len(&self) -> usize {
+        self.structs.len() + self.enums.len() + self.unions.len() + self.aliases.len()
+    }
+
+    /// Whether no types of any kind were found
+    pub(crate) fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Whether a type of any kind is cached under exactly this path
+    fn contains_path(&self, path: &str) -> bool {
+        self.structs.contains_key(path)
+            || self.enums.contains_key(path)
+            || self.unions.contains_key(path)
+            || self.aliases.contains_key(path)
+    }
+
+    /// Merge another set of types into this one, later entries replacing earlier ones
+    ///
+    /// Structs follow the precedence of `insert_struct_with_full_name`: an entry
+    /// parsed from the defining crate always wins over a re-exported copy,
+    /// whichever side it comes from.
+    fn merge(&mut self, other: StdlibTypes) -> Result<()> {
+        for (name, struct_info) in other.structs.into_map()? {
+            let is_definition = !other.reexported_structs.contains(&name);
+            insert_struct_with_full_name(self, struct_info, is_definition)?;
+        }
+        self.enums.extend(other.enums);
+        self.unions.extend(other.unions);
+        self.aliases.extend(other.aliases);
+        self.reexports.extend(other.reexports);
+        self.glob_reexports.extend(other.glob_reexports);
+        self.format_version = other.format_version.or(self.format_version);
+        // The reverse map must be rebuilt from the merged re-exports
+        self.std_aliases = OnceLock::new();
+        Ok(())
+    }
+
+    /// The reverse of the re-export table, built on first use
+    fn std_aliases(&self) -> &HashMap<String, Vec<String>> {
+        self.std_aliases.get_or_init(|| build_std_aliases(self))
+    }
+
+    /// Make every field with the same `type_name` share a single allocation
+    ///
+    /// Names like `usize` or `PhantomData<T>` repeat thousands of times across
+    /// the stdlib. Types parsed from separate JSON files or deserialized from
+    /// a cache file each carry their own copies, so this runs whenever types
+    /// enter the global cache. Structs already spilled to disk are skipped.
+    fn intern_type_names(&mut self) {
+        let mut pool: HashSet<Arc<str>> = HashSet::new();
+        let fields = self
+            .structs
+            .resident_values_mut()
+            .flat_map(|info| Arc::make_mut(info).fields.iter_mut())
+            .chain(
+                self.unions
+                    .values_mut()
+                    .flat_map(|info| info.fields.iter_mut()),
+            )
+            .chain(
+                self.enums
+                    .values_mut()
+                    .flat_map(|info| info.variants.iter_mut())
+                    .flat_map(|variant| variant.fields.iter_mut()),
+            );
+
+        for field in fields {
+            match pool.get(&*field.type_name) {
+                Some(shared) => field.type_name = Arc::clone(shared),
+                None => {
+                    pool.insert(Arc::clone(&field.type_name));
+                }
+            }
+        }
+        debug!("Interned field types into {} distinct names", pool.len());
+    }
+}
+
+/// Run `f` against the cached standard library types, initializing the cache first if needed
+///
+/// The cache holds types for one configuration at a time. If it was built for a
+/// different configuration (e.g., another toolchain), it is rebuilt for `config`.
+/// Every documented crate is parsed before `f` runs.
+fn with_stdlib_types<R>(config: &QuarryConfig, f: impl FnOnce(&StdlibTypes) -> R) -> Result<R> {
+    with_stdlib_crates(config, &config.documented_crates(), f)
+}
+
+/// Run `f` against the types needed to look up `path`
+///
+/// Paths under `alloc::` or `core::` only need that crate's JSON parsed, so
+/// narrow workloads never pay for parsing `std.json`. Any other path may reach
+/// its definition through std's re-exports and needs every crate.
+fn with_stdlib_types_for_path<R>(
+    config: &QuarryConfig,
+    path: &str,
+    f: impl FnOnce(&StdlibTypes) -> R,
+) -> Result<R> {
+    let crates = config.documented_crates();
+    let first_segment = path.split("::").next().unwrap_or(path);
+    match crates
+        .iter()
+        .find(|name| **name == first_segment && **name != "std")
+    {
+        Some(name) => with_stdlib_crates(config, &[name], f),
+        None => with_stdlib_crates(config, &crates, f),
+    }
+}
+
+/// Run `f` once the crates in `needed` have been parsed into the cache
+fn with_stdlib_crates<R>(
+    config: &QuarryConfig,
+    needed: &[&str],
+    f: impl FnOnce(&StdlibTypes) -> R,
+) -> Result<R> {
+    let cache = STDLIB_CACHE.get_or_init(|| RwLock::new(None));
+    let cache_key = config.cache_key();
+
+    // Fast path: a warm cache only needs a shared read lock
+    {
+        let cache_guard = cache.read().unwrap();
+        if let Some(loaded) = cache_guard.as_ref()
+            && loaded.cache_key == cache_key
+            && loaded.has_parsed(needed)
+        {
+            return Ok(f(&loaded.types));
+        }
+    }
+
+    // Another thread may have initialized the cache before we got the write
+    // lock; `ensure_loaded` re-checks under it
+    let mut cache_guard = cache.write().unwrap();
+    ensure_loaded(&mut cache_guard, config, needed, &mut |_| {})?;
+
+    Ok(f(&cache_guard.as_ref().unwrap().types))
+}
+
+/// Initialize the cache for `config` if needed, reporting progress to `progress`
+///
+/// Emits `InitStage::Done` once the cache is ready, whether or not any work
+/// had to be done.
+pub(crate) fn init_stdlib_cache_with_progress(
+    config: &QuarryConfig,
+    progress: &mut dyn FnMut(InitStage),
+) -> Result<()> {
+    let cache = STDLIB_CACHE.get_or_init(|| RwLock::new(None));
+    let mut cache_guard = cache.write().unwrap();
+    ensure_loaded(
+        &mut cache_guard,
+        config,
+        &config.documented_crates(),
+        progress,
+    )?;
+
+    progress(InitStage::Done);
+    Ok(())
+}
+
+/// Fill the locked cache with the types for `config`, unless it already holds them
+///
+/// At least the crates in `needed` are parsed; others may be left pending. A
+/// newly built cache is bounded as `apply_struct_capacity` describes.
+fn ensure_loaded(
+    cache_guard: &mut Option<LoadedTypes>,
+    config: &QuarryConfig,
+    needed: &[&str],
+    progress: &mut dyn FnMut(InitStage),
+) -> Result<()> {
+    let cache_key = config.cache_key();
+
+    // Parse pending crates into a cache built for this configuration
+    match cache_guard.as_mut() {
+        Some(loaded) if loaded.cache_key == cache_key => {
+            if loaded.has_parsed(needed) {
+                debug!("Using existing initialized cache");
+                return Ok(());
+            }
+            if parse_pending_crates(loaded, config, needed, progress)? {
+                return Ok(());
+            }
+            debug!("Saved rustdoc JSON changed since the cache was built, reinitializing");
+        }
+        Some(_) => debug!("Cache was built for another configuration, reinitializing"),
+        None => debug!("Cache not initialized, initializing stdlib types cache"),
+    }
+
+    // Initialize the cache if it's empty, stale or was built for another configuration
+    match init_stdlib_types(config, needed, progress) {
+        Ok(mut loaded) => {
+            debug!(
+                "Successfully initialized cache with {} types",
+                loaded.types.len()
+            );
+            loaded.types.intern_type_names();
+            apply_struct_capacity(&mut loaded)?;
+            *cache_guard = Some(loaded);
+        }
+        Err(e) => {
+            debug!("Failed to initialize stdlib types cache: {:?}", e);
+            return Err(e);
+        }
+    }
+
+    Ok(())
+}
+
+/// Keep at most the capacity set by `set_struct_cache_capacity` in memory
+///
+/// Called on every newly built or seeded cache. Structs parsed into a cache
+/// later go through its store, which keeps the bound.
+fn apply_struct_capacity(loaded: &mut LoadedTypes) -> Result<()> {
+    let capacity = *STRUCT_CACHE_CAPACITY.lock().unwrap();
+    loaded
+        .types
+        .structs
+        .set_capacity(capacity)
+        .map_err(QuarryError::Io)
+}
+
+/// Parse the pending crates in `needed` from their saved JSON into the cache
+///
+/// Once no crate is pending any more the complete types are written to the
+/// disk cache, just like after an eager initialization, unless the cache was
+/// seeded by the caller.
+///
+/// # Returns
+///
+/// `Ok(false)` if the saved JSON is no longer the one the cache was built
+/// from, in which case the cache must be rebuilt
+fn parse_pending_crates(
+    loaded: &mut LoadedTypes,
+    config: &QuarryConfig,
+    needed: &[&str],
+    progress: &mut dyn FnMut(InitStage),
+) -> Result<bool> {
+    let Some(pending) = loaded.pending.as_mut() else {
+        return Ok(true);
+    };
+    let to_parse: Vec<&'static str> = pending
+        .crates
+        .iter()
+        .copied()
+        .filter(|name| needed.contains(name))
+        .collect();
+    if !rustdoc_output_is_fresh(&pending.json_dir, &pending.stamp, &to_parse) {
+        return Ok(false);
+    }
+
+    debug!("Parsing pending crates on demand: {:?}", to_parse);
+    let types = parse_stdlib_json_dir(&pending.json_dir, &to_parse, progress)?;
+    loaded.types.merge(types)?;
+    loaded.types.intern_type_names();
+    pending.crates.retain(|name| !to_parse.contains(name));
+
+    if pending.crates.is_empty() {
+        debug!("All crates parsed, {} types in total", loaded.types.len());
+        loaded.pending = None;
+        if loaded.seeded {
+            debug!("Not writing disk cache for seeded types");
+        } else if let Err(e) = disk_cache::store(config, &loaded.types) {
+            debug!("Failed to write disk cache: {:?}", e);
+        }
+    }
+    Ok(true)
+}
 
 /// Initialize the standard library type database by analyzing the actual stdlib
-fn init_stdlib_types() -> Result<HashMap<String, StructInfo>> {
+///
+/// A previously persisted disk cache for the current toolchain is used when
+/// available; otherwise the stdlib is analyzed and the result is written back
+/// to disk for later processes. Only complete results are written, so when
+/// some crates are left pending the disk cache is written once they are parsed.
+fn init_stdlib_types(
+    config: &QuarryConfig,
+    needed: &[&str],
+    progress: &mut dyn FnMut(InitStage),
+) -> Result<LoadedTypes> {
     debug!("Initializing standard library type database");
+
+    validate_crates(config)?;
+
+    if let Some(types) = disk_cache::load(config) {
+        debug!("Initialized stdlib database from disk cache");
+        return Ok(LoadedTypes {
+            cache_key: config.cache_key(),
+            types,
+            pending: None,
+            seeded: false,
+        });
+    }
+
     // Generate rustdoc JSON directly from the standard library source
     // This will include private fields when using --document-private-items
-    let result = analyze_stdlib_with_rustdoc();
+    let result = analyze_stdlib_with_rustdoc(config, needed, progress);
     match &result {
-        Ok(types) => debug!(
-            "Successfully initialized stdlib database with {} types",
-            types.len()
-        ),
+        Ok((types, pending)) => {
+            debug!(
+                "Successfully initialized stdlib database with {} types",
+                types.len()
+            );
+            if pending.is_none()
+                && let Err(e) = disk_cache::store(config, types)
+            {
+                debug!("Failed to write disk cache: {:?}", e);
+            }
+        }
         Err(e) => debug!("Failed to initialize stdlib database: {:?}", e),
     }
-    result
+    result.map(|(types, pending)| LoadedTypes {
+        cache_key: config.cache_key(),
+        types,
+        pending,
+        seeded: false,
+    })
+}
+
+/// Check that `QuarryConfig::crates` names only supported stdlib crates
+fn validate_crates(config: &QuarryConfig) -> Result<()> {
+    if config.crates.is_empty() {
+        return Err(QuarryError::InvalidConfig(
+            "crates must name at least one of std, alloc, core and proc_macro".to_string(),
+        ));
+    }
+    if let Some(unsupported) = config
+        .crates
+        .iter()
+        .find(|name| !STDLIB_CRATES.contains(&name.as_str()))
+    {
+        return Err(QuarryError::InvalidConfig(format!(
+            "unsupported crate '{}' in crates; expected std, alloc, core or proc_macro",
+            unsupported
+        )));
+    }
+    Ok(())
 }
 
 /// Generate rustdoc JSON directly from the standard library
-fn analyze_stdlib_with_rustdoc() -> Result<HashMap<String, StructInfo>> {
+///
+/// Returns the parsed types of at least the crates in `needed`, plus the
+/// crates left for parsing on demand.
+fn analyze_stdlib_with_rustdoc(
+    config: &QuarryConfig,
+    needed: &[&str],
+    progress: &mut dyn FnMut(InitStage),
+) -> Result<(StdlibTypes, Option<PendingCrates>)> {
     debug!("Starting rustdoc analysis of standard library");
 
     // Find the standard library source
     debug!("Locating standard library source path");
-    let stdlib_path = find_stdlib_source_path()?;
+    progress(InitStage::LocatingSource);
+    let stdlib_path = match find_stdlib_source_path(&config.toolchain) {
+        Ok(path) => path,
+        // Without sources there is no reusable JSON either, and offline
+        // callers care that the cache is missing, not why the source is
+        Err(e) if config.offline => {
+            debug!("Offline and stdlib source unavailable: {:?}", e);
+            return Err(QuarryError::OfflineCacheUnavailable(
+                config.toolchain.clone(),
+            ));
+        }
+        Err(e) => return Err(e),
+    };
     debug!("Found stdlib source at: {:?}", stdlib_path);
 
     // Generate rustdoc JSON with private items included
     debug!("Generating rustdoc JSON for standard library");
-    let types = generate_stdlib_rustdoc_json(&stdlib_path)?;
+    let (types, pending) = generate_stdlib_rustdoc_json(&stdlib_path, config, needed, progress)?;
     debug!(
         "Generated and parsed {} types from rustdoc JSON",
         types.len()
     );
 
-    Ok(types)
+    Ok((types, pending))
 }
 
 /// Find the path to the standard library source
-fn find_stdlib_source_path() -> Result<std::path::PathBuf> {
-    debug!("Finding standard library source path via nightly rustc");
+///
+/// # Arguments
+///
+/// * `toolchain` - The rustup toolchain to query (e.g., "nightly" or "nightly-2024-05-01")
+fn find_stdlib_source_path(toolchain: &str) -> Result<std::path::PathBuf> {
+    debug!(
+        "Finding standard library source path via rustc +{}",
+        toolchain
+    );
+
+    let Some(sysroot) = query_sysroot(toolchain)? else {
+        return Err(QuarryError::NightlyMissing(toolchain.to_string()));
+    };
+    let stdlib_path = stdlib_source_path(&sysroot);
+
+    debug!("Checking for stdlib source at: {:?}", stdlib_path);
+    if !stdlib_path.exists() {
+        debug!("Standard library source not found at expected path");
+        return Err(QuarryError::RustSrcMissing(toolchain.to_string()));
+    }
+
+    debug!("Standard library source found successfully");
+    Ok(stdlib_path)
+}
 
-    // Try to find the stdlib through nightly rustc (since we need nightly for rustdoc JSON)
+/// Ask a toolchain's rustc for its sysroot
+///
+/// Runs `rustc +<toolchain> --print sysroot`. We need the nightly rustc here,
+/// since only nightly can produce rustdoc JSON.
+///
+/// # Returns
+///
+/// * `Ok(Some(PathBuf))` - The sysroot of the installed toolchain
+/// * `Ok(None)` - If rustc reports a failure, i.e. the toolchain is not installed
+/// * `Err(QuarryError::Io)` - If rustc cannot be run at all
+fn query_sysroot(toolchain: &str) -> Result<Option<std::path::PathBuf>> {
     let output = std::process::Command::new("rustc")
-        .args(&["+nightly", "--print", "sysroot"])
+        .args([&format!("+{}", toolchain), "--print", "sysroot"])
         .output()
         .map_err(QuarryError::Io)?;
 
     if !output.status.success() {
-        debug!("Failed to get sysroot from nightly rustc");
+        debug!("Failed to get sysroot from rustc +{}", toolchain);
         let error_msg = String::from_utf8_lossy(&output.stderr);
         debug!("Error output: {}", error_msg);
-        return Err(QuarryError::TypeNotFound(
-            "Could not find Rust nightly sysroot. Make sure nightly toolchain is installed with: rustup toolchain install nightly".to_string(),
-        ));
+        return Ok(None);
     }
 
     let sysroot_string = String::from_utf8_lossy(&output.stdout);
     let sysroot = sysroot_string.trim();
     debug!("Found sysroot: {}", sysroot);
+    Ok(Some(std::path::PathBuf::from(sysroot)))
+}
 
-    let stdlib_path = std::path::PathBuf::from(sysroot)
+/// Where the rust-src component puts the std sources within a sysroot
+fn stdlib_source_path(sysroot: &std::path::Path) -> std::path::PathBuf {
+    sysroot
         .join("lib")
         .join("rustlib")
         .join("src")
         .join("rust")
         .join("library")
         .join("std")
-        .join("src");
+        .join("src")
+}
 
-    debug!("Checking for stdlib source at: {:?}", stdlib_path);
-    if !stdlib_path.exists() {
-        debug!("Standard library source not found at expected path");
-        return Err(QuarryError::TypeNotFound(
-            "Standard library source not found. Try installing rust-src component for nightly toolchain with: rustup component add rust-src --toolchain nightly".to_string()
-        ));
-    }
+/// Check whether a toolchain can support standard library analysis
+///
+/// Performs the same sysroot query and source lookup as
+/// `find_stdlib_source_path`, plus a version query, but never runs `cargo doc`.
+///
+/// # Returns
+///
+/// * `Ok(Requirements)` - What was found; missing pieces are reported as `false`
+/// * `Err(QuarryError::Io)` - If rustc cannot be run at all
+pub(crate) fn check_requirements(config: &QuarryConfig) -> Result<Requirements> {
+    debug!("Checking requirements for toolchain: {}", config.toolchain);
+
+    let Some(sysroot) = query_sysroot(&config.toolchain)? else {
+        return Ok(Requirements {
+            nightly_installed: false,
+            rust_src_installed: false,
+            nightly_version: None,
+        });
+    };
 
-    debug!("Standard library source found successfully");
-    Ok(stdlib_path)
+    let requirements = Requirements {
+        nightly_installed: true,
+        rust_src_installed: stdlib_source_path(&sysroot).exists(),
+        nightly_version: disk_cache::toolchain_version(&config.toolchain)
+            .map(|version| version.version),
+    };
+    debug!("Requirements: {:?}", requirements);
+    Ok(requirements)
+}
+
+/// Deletes a temporary directory when dropped, unless asked to keep it
+struct TempDirGuard {
+    path: std::path::PathBuf,
+    keep: bool,
+}
+
+impl Drop for TempDirGuard {
+    fn drop(&mut self) {
+        if self.keep {
+            debug!("Keeping temporary directory: {:?}", self.path);
+            return;
+        }
+        debug!("Removing temporary directory: {:?}", self.path);
+        if let Err(e) = std::fs::remove_dir_all(&self.path) {
+            debug!(
+                "Failed to remove temporary directory {:?}: {}",
+                self.path, e
+            );
+        }
+    }
 }
 
 /// Generate rustdoc JSON for the standard library with private items
+///
+/// When the JSON is kept for reuse, only the crates in `needed` are parsed
+/// right away and the others are returned as pending.
 fn generate_stdlib_rustdoc_json(
     stdlib_src_path: &std::path::Path,
-) -> Result<HashMap<String, StructInfo>> {
+    config: &QuarryConfig,
+    needed: &[&str],
+    progress: &mut dyn FnMut(InitStage),
+) -> Result<(StdlibTypes, Option<PendingCrates>)> {
     debug!(
         "Generating rustdoc JSON for stdlib at: {:?}",
         stdlib_src_path
@@ -109,7 +661,7 @@ fn generate_stdlib_rustdoc_json(
 
     // Navigate to the library workspace root where Cargo.toml is
     let library_root = stdlib_src_path.parent().ok_or_else(|| {
-        QuarryError::TypeNotFound("Could not find library root directory".to_string())
+        QuarryError::StdlibAnalysis("Could not find library root directory".to_string())
     })?;
 
     debug!("Using library root directory: {:?}", library_root);
@@ -118,14 +670,45 @@ fn generate_stdlib_rustdoc_json(
     let cargo_toml_path = library_root.join("Cargo.toml");
     if !cargo_toml_path.exists() {
         debug!("Cargo.toml not found at: {:?}", cargo_toml_path);
-        return Err(QuarryError::TypeNotFound(
-            "Standard library Cargo.toml not found. The rust-src component may be incomplete."
-                .to_string(),
-        ));
+        // The rust-src component is incomplete; reinstalling it is the fix
+        return Err(QuarryError::RustSrcMissing(config.toolchain.clone()));
     }
 
     debug!("Found Cargo.toml at: {:?}", cargo_toml_path);
 
+    let crates = config.documented_crates();
+
+    // JSON saved by an earlier run of the same toolchain, target and private
+    // item setting can be parsed as is, so the stamp records all three
+    let json_dir = std::env::temp_dir().join(RUSTDOC_JSON_DIR);
+    let stamp = disk_cache::toolchain_version(&config.toolchain)
+        .map(|version| version.commit_hash)
+        .filter(|hash| hash != "unknown")
+        .map(|hash| {
+            let mut stamp = hash;
+            if let Some(target) = &config.target {
+                stamp.push_str(&format!(" {}", target));
+            }
+            if !config.document_private_items {
+                stamp.push_str(" public");
+            }
+            stamp
+        });
+    if config.reuse_rustdoc_output
+        && let Some(stamp) = &stamp
+        && rustdoc_output_is_fresh(&json_dir, stamp, &crates)
+    {
+        debug!("Reusing fresh rustdoc JSON from: {:?}", json_dir);
+        return parse_saved_json(json_dir, stamp, &crates, needed, progress);
+    }
+
+    if config.offline {
+        debug!("Offline mode: refusing to run cargo doc");
+        return Err(QuarryError::OfflineCacheUnavailable(
+            config.toolchain.clone(),
+        ));
+    }
+
     // Create a temporary directory for the JSON output
     let temp_dir = std::env::temp_dir().join("quarry_stdlib_docs");
     debug!("Using temporary directory: {:?}", temp_dir);
@@ -135,29 +718,40 @@ fn generate_stdlib_rustdoc_json(
         std::fs::remove_dir_all(&temp_dir).map_err(QuarryError::Io)?;
     }
     std::fs::create_dir_all(&temp_dir).map_err(QuarryError::Io)?;
+    // Removes the directory on every exit path below, including errors
+    let _temp_guard = TempDirGuard {
+        path: temp_dir.clone(),
+        keep: config.keep_temp,
+    };
 
     debug!("Executing cargo doc on the actual standard library workspace");
-
-    // Use cargo doc with JSON output, but document multiple key crates
-    let output = std::process::Command::new("cargo")
-        .args(&[
-            "+nightly",                 // Use nightly toolchain
-            "doc",                      // Generate documentation
-            "--package", "std",         // Document std package
-            "--package", "alloc",       // Document alloc package
-            "--package", "core",        // Document core package
-            "--lib",                    // Document library only
-            "--no-deps",                // Don't document dependencies
-            "--document-private-items", // Include private items
-            "--target-dir",
-            temp_dir.to_str().unwrap(), // Custom target directory
+    progress(InitStage::RunningCargoDoc);
+
+    // Use cargo doc with JSON output, documenting each requested crate
+    let toolchain_arg = format!("+{}", config.toolchain);
+    let mut command = std::process::Command::new("cargo");
+    command.args([toolchain_arg.as_str(), "doc"]); // Use the configured nightly toolchain
+    for crate_name in &crates {
+        command.args(["--package", crate_name]);
+    }
+    if let Some(target) = &config.target {
+        command.args(["--target", target]); // Cross-document for another target
+    }
+    if config.document_private_items {
+        command.arg("--document-private-items"); // Include private items
+    }
+    command
+        .args([
+            "--lib",     // Document library only
+            "--no-deps", // Don't document dependencies
         ])
+        .arg("--target-dir")
+        .arg(&temp_dir) // Custom target directory, possibly a non-UTF-8 path
         .env("RUSTDOCFLAGS", "-Z unstable-options --output-format json") // Enable JSON output
         .env("RUSTC_BOOTSTRAP", "1") // Allow unstable features
         .env("__CARGO_DEFAULT_LIB_METADATA", "stable") // Std library metadata
-        .current_dir(library_root) // Run from library root
-        .output()
-        .map_err(QuarryError::Io)?;
+        .current_dir(library_root); // Run from library root
+    let output = output_with_timeout(&mut command, config.rustdoc_timeout)?;
 
     if !output.status.success() {
         let error_msg = String::from_utf8_lossy(&output.stderr);
@@ -169,39 +763,173 @@ fn generate_stdlib_rustdoc_json(
             debug!("Cargo doc stdout: {}", stdout_msg);
         }
 
-        return Err(QuarryError::TypeNotFound(format!(
-            "Failed to generate rustdoc JSON for standard library: {}",
-            error_msg
-        )));
+        return Err(QuarryError::RustdocFailed {
+            status: output.status.code(),
+            stderr: error_msg.into_owned(),
+        });
     }
 
     debug!("Cargo doc execution completed successfully");
 
-    // Find the generated JSON files
-    let mut all_types = HashMap::new();
-
-    // Check for std.json, alloc.json, and core.json
-    let crate_names = ["std", "alloc", "core"];
-    for crate_name in &crate_names {
-        let json_path = temp_dir.join("doc").join(format!("{}.json", crate_name));
-        debug!("Looking for {} JSON output at: {:?}", crate_name, json_path);
-
-        if json_path.exists() {
-            debug!("Found {} JSON at: {:?}", crate_name, json_path);
-            // Parse this crate's JSON and merge into all_types
-            let crate_types = parse_rustdoc_json_directly(&json_path)?;
-            debug!(
-                "Parsed {} types from {} crate",
-                crate_types.len(),
-                crate_name
-            );
+    // Cross builds nest their output under the target triple
+    let doc_dir = match &config.target {
+        Some(target) => temp_dir.join(target).join("doc"),
+        None => temp_dir.join("doc"),
+    };
+
+    // Keep the JSON (but not the bulky build output) for later cold starts,
+    // and parse the crates that aren't needed yet from there on demand
+    if config.reuse_rustdoc_output
+        && let Some(stamp) = &stamp
+    {
+        if save_rustdoc_output(&doc_dir, &json_dir, stamp, &crates) {
+            return parse_saved_json(json_dir, stamp, &crates, needed, progress);
+        }
+
+        // A failed save may have moved some files already; each one is in
+        // exactly one of the two directories
+        let (generated, moved): (Vec<&str>, Vec<&str>) = crates
+            .iter()
+            .partition(|name| doc_dir.join(format!("{}.json", name)).is_file());
+        let mut all_types = parse_stdlib_json_dir(&doc_dir, &generated, progress)?;
+        all_types.merge(parse_stdlib_json_dir(&json_dir, &moved, progress)?)?;
+        return Ok((all_types, None));
+    }
+
+    let all_types = parse_stdlib_json_dir(&doc_dir, &crates, progress)?;
+    Ok((all_types, None))
+}
 
-            // Merge the types
-            for (name, struct_info) in crate_types {
-                all_types.insert(name, struct_info);
+/// Parse the `needed` crates from saved JSON and leave the rest pending
+fn parse_saved_json(
+    json_dir: std::path::PathBuf,
+    stamp: &str,
+    crates: &[&'static str],
+    needed: &[&str],
+    progress: &mut dyn FnMut(InitStage),
+) -> Result<(StdlibTypes, Option<PendingCrates>)> {
+    let (now, later): (Vec<&'static str>, Vec<&'static str>) =
+        crates.iter().partition(|name| needed.contains(name));
+    let types = parse_stdlib_json_dir(&json_dir, &now, progress)?;
+
+    if !later.is_empty() {
+        debug!("Deferring parsing of crates until first use: {:?}", later);
+    }
+    let pending = (!later.is_empty()).then(|| PendingCrates {
+        json_dir,
+        stamp: stamp.to_string(),
+        crates: later,
+    });
+    Ok((types, pending))
+}
+
+/// Whether a directory holds JSON for every crate in `crates`, stamped with `stamp`
+fn rustdoc_output_is_fresh(json_dir: &std::path::Path, stamp: &str, crates: &[&str]) -> bool {
+    let stamped = std::fs::read_to_string(json_dir.join(RUSTDOC_STAMP_FILE))
+        .is_ok_and(|contents| contents.trim() == stamp);
+    stamped
+        && crates
+            .iter()
+            .all(|crate_name| json_dir.join(format!("{}.json", crate_name)).is_file())
+}
+
+/// Move freshly generated stdlib JSON into `json_dir` and stamp it
+///
+/// The stamp is removed first and written last, so an interrupted save never
+/// leaves a directory that looks fresh. JSON of stdlib crates outside `crates`
+/// is deleted, since the new stamp would otherwise vouch for it. Failures are
+/// logged and otherwise ignored; the next cold start simply runs `cargo doc` again.
+///
+/// # Returns
+///
+/// Whether every file was saved and stamped
+fn save_rustdoc_output(
+    doc_dir: &std::path::Path,
+    json_dir: &std::path::Path,
+    stamp: &str,
+    crates: &[&str],
+) -> bool {
+    debug!("Saving rustdoc JSON to: {:?}", json_dir);
+
+    let stamp_path = json_dir.join(RUSTDOC_STAMP_FILE);
+    let saved = (|| -> std::io::Result<()> {
+        std::fs::create_dir_all(json_dir)?;
+        if stamp_path.exists() {
+            std::fs::remove_file(&stamp_path)?;
+        }
+        for crate_name in STDLIB_CRATES {
+            let file_name = format!("{}.json", crate_name);
+            if crates.contains(&crate_name) {
+                std::fs::rename(doc_dir.join(&file_name), json_dir.join(&file_name))?;
+            } else if json_dir.join(&file_name).exists() {
+                std::fs::remove_file(json_dir.join(&file_name))?;
             }
-        } else {
-            debug!("No JSON found for {} crate at: {:?}", crate_name, json_path);
+        }
+        std::fs::write(&stamp_path, stamp)
+    })();
+
+    if let Err(e) = &saved {
+        debug!("Failed to save rustdoc JSON to {:?}: {}", json_dir, e);
+    }
+    saved.is_ok()
+}
+
+/// Parse the JSON files of the given stdlib crates in a directory and merge them
+///
+/// The files are parsed concurrently, so `InitStage::ParsingCrate` is reported
+/// for each crate as its parse starts rather than one after another.
+fn parse_stdlib_json_dir(
+    doc_dir: &std::path::Path,
+    crates: &[&str],
+    progress: &mut dyn FnMut(InitStage),
+) -> Result<StdlibTypes> {
+    // Find the generated JSON files
+    let mut all_types = StdlibTypes::default();
+
+    // Parse each crate's JSON on a separate thread. The files are independent,
+    // and std.json alone takes most of the parse time.
+    let results: Vec<Result<Option<StdlibTypes>>> = std::thread::scope(|scope| {
+        let handles: Vec<_> = crates
+            .iter()
+            .map(|crate_name| {
+                let json_path = doc_dir.join(format!("{}.json", crate_name));
+                if json_path.exists() {
+                    progress(InitStage::ParsingCrate(crate_name.to_string()));
+                }
+                scope.spawn(move || {
+                    debug!("Looking for {} JSON output at: {:?}", crate_name, json_path);
+                    if !json_path.exists() {
+                        debug!("No JSON found for {} crate at: {:?}", crate_name, json_path);
+                        return Ok(None);
+                    }
+
+                    debug!("Found {} JSON at: {:?}", crate_name, json_path);
+                    let crate_types = parse_rustdoc_json_directly(&json_path)?;
+                    debug!(
+                        "Parsed {} types from {} crate",
+                        crate_types.len(),
+                        crate_name
+                    );
+                    Ok(Some(crate_types))
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+            })
+            .collect()
+    });
+
+    // Merge in the fixed `STDLIB_CRATES` order, regardless of which thread
+    // finished first, so a path defined in several crates resolves consistently
+    for crate_types in results {
+        if let Some(crate_types) = crate_types? {
+            all_types.merge(crate_types)?;
         }
     }
 
@@ -209,9 +937,9 @@ fn generate_stdlib_rustdoc_json(
         debug!(
             "No types found after parsing all expected JSON files (std.json, alloc.json, core.json)"
         );
-        return Err(QuarryError::TypeNotFound(format!(
-            "Failed to parse any types from generated rustdoc JSON files"
-        )));
+        return Err(QuarryError::StdlibAnalysis(
+            "Failed to parse any types from generated rustdoc JSON files".to_string(),
+        ));
     }
 
     debug!(
@@ -221,66 +949,514 @@ fn generate_stdlib_rustdoc_json(
     Ok(all_types)
 }
 
-/// Parse rustdoc JSON directly to extract struct information with private fields
-fn parse_rustdoc_json_directly(json_path: &std::path::Path) -> Result<HashMap<String, StructInfo>> {
-    debug!("Parsing rustdoc JSON from: {:?}", json_path);
-    let mut types = HashMap::new();
-
-    // Read and parse the JSON
-    debug!("Reading JSON file content");
-    let json_content = std::fs::read_to_string(json_path).map_err(QuarryError::Io)?;
-    debug!("JSON file size: {} bytes", json_content.len());
+/// Run a `cargo doc` command to completion, killing it if it outlives `timeout`
+///
+/// With no timeout this is `Command::output`. Otherwise the child is spawned
+/// with piped output, which is drained on background threads so a chatty
+/// build cannot block on a full pipe, and polled until it exits or the
+/// deadline passes. Output is collected until both pipes close or the deadline
+/// passes, whichever comes first, so a grandchild that inherited a pipe cannot
+/// hold up the return.
+///
+/// # Returns
+///
+/// * `Ok(Output)` - The exit status and captured output of a finished run
+/// * `Err(QuarryError::RustdocFailed)` - If the deadline passed; `stderr` holds
+///   a timeout note followed by the output captured so far
+/// * `Err(QuarryError::Io)` - If the command cannot be spawned or waited on
+fn output_with_timeout(
+    command: &mut std::process::Command,
+    timeout: Option<std::time::Duration>,
+) -> Result<std::process::Output> {
+    use std::io::Read;
+    use std::process::Stdio;
+    use std::sync::{Arc, mpsc};
+
+    let Some(timeout) = timeout else {
+        return command.output().map_err(QuarryError::Io);
+    };
 
-    debug!("Parsing JSON content");
-    let json: Value = serde_json::from_str(&json_content)
-        .map_err(|e| QuarryError::Io(std::io::Error::new(std::io::ErrorKind::InvalidData, e)))?;
+    // Read a pipe to its end on a background thread, appending to a buffer that
+    // stays readable even if the reader never finishes (e.g. a grandchild keeps
+    // the pipe open), and signal `done` once the pipe is closed
+    fn drain(mut pipe: impl Read + Send + 'static, done: mpsc::Sender<()>) -> Arc<Mutex<Vec<u8>>> {
+        let buffer = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&buffer);
+        std::thread::spawn(move || {
+            let mut chunk = [0; 8192];
+            while let Ok(read) = pipe.read(&mut chunk) {
+                if read == 0 {
+                    break;
+                }
+                sink.lock().unwrap().extend_from_slice(&chunk[..read]);
+            }
+            let _ = done.send(());
+        });
+        buffer
+    }
 
-    // Extract struct information from the JSON
-    debug!("Looking for 'index' section in JSON");
-    if let Some(index) = json.get("index") {
-        if let Some(index_obj) = index.as_object() {
-            debug!("Found index with {} items", index_obj.len());
-            let mut processed = 0;
-
-            for (_item_id, item_data) in index_obj {
-                if let Some(struct_info) = parse_item_for_struct(item_data, &json)? {
-                    debug!("Found struct: {}", struct_info.name);
-                    // Insert with full name only - requires users to be explicit about paths
-                    insert_struct_with_full_name(&mut types, struct_info);
+    let mut child = command
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(QuarryError::Io)?;
+    let (done, drained) = mpsc::channel();
+    let stdout = drain(child.stdout.take().unwrap(), done.clone());
+    let stderr = drain(child.stderr.take().unwrap(), done);
+
+    let deadline = std::time::Instant::now() + timeout;
+    loop {
+        if let Some(status) = child.try_wait().map_err(QuarryError::Io)? {
+            // Give the readers what is left of the deadline to reach the end of
+            // both pipes, then take whatever they have read without joining them
+            for _ in 0..2 {
+                let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+                if drained.recv_timeout(remaining).is_err() {
+                    debug!("cargo doc output still open at the deadline, keeping what was read");
+                    break;
                 }
-                processed += 1;
             }
-            debug!(
-                "Finished processing {} items, found {} structs",
-                processed,
-                types.len()
-            );
-        } else {
-            debug!("Index section is not an object");
+            return Ok(std::process::Output {
+                status,
+                stdout: std::mem::take(&mut *stdout.lock().unwrap()),
+                stderr: std::mem::take(&mut *stderr.lock().unwrap()),
+            });
         }
-    } else {
-        debug!("No 'index' section found in JSON");
-    }
 
-    Ok(types)
+        if std::time::Instant::now() >= deadline {
+            debug!("cargo doc exceeded its {:?} timeout, killing it", timeout);
+            let _ = child.kill();
+            let _ = child.wait();
+            let captured = String::from_utf8_lossy(&stderr.lock().unwrap()).into_owned();
+            return Err(QuarryError::RustdocFailed {
+                status: None,
+                stderr: format!(
+                    "timed out after {} seconds and was killed\n{}",
+                    timeout.as_secs(),
+                    captured
+                ),
+            });
+        }
+
+        std::thread::sleep(RUSTDOC_POLL_INTERVAL);
+    }
 }
 
-/// Parse a single item from rustdoc JSON to see if it's a struct
-///
-/// This function examines a rustdoc JSON item and determines if it represents
-/// a struct definition. It extracts the struct name, module path, fields, and
-/// other metadata from the JSON structure.
+/// Analyze an arbitrary crate and return every struct it defines
 ///
-/// # JSON Structure Example
+/// Runs `cargo doc --document-private-items` with JSON output for the crate
+/// described by `manifest_path`, then parses each generated JSON file exactly
+/// like the stdlib ones. Struct names are rooted at the crate name recorded in
+/// the JSON (e.g. `my_crate::config::Settings`).
 ///
-/// For a struct like `String`, the JSON looks like:
-/// ```json
-/// {
-///   "id": 246,
-///   "crate_id": 0,
-///   "name": "String",
-///   "span": {
-///     "filename": "alloc/src/string.rs",
+/// Results are not cached; every call regenerates the documentation, although
+/// cargo's incremental build output in the crate's target directory (see
+/// `crate_target_dir`) is reused.
+pub(crate) fn mine_crate_structs(
+    manifest_path: &std::path::Path,
+    config: &QuarryConfig,
+) -> Result<HashMap<String, StructInfo>> {
+    debug!("Generating rustdoc JSON for crate at: {:?}", manifest_path);
+
+    if !manifest_path.is_file() {
+        return Err(QuarryError::Io(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            format!("Cargo manifest not found: {}", manifest_path.display()),
+        )));
+    }
+    let crate_root = manifest_path
+        .parent()
+        .filter(|p| !p.as_os_str().is_empty())
+        .unwrap_or_else(|| std::path::Path::new("."));
+
+    let target_dir = crate_target_dir(&manifest_path.canonicalize().map_err(QuarryError::Io)?);
+    let doc_dir = target_dir.join("doc");
+    debug!("Documenting into target directory: {:?}", target_dir);
+
+    let toolchain_arg = format!("+{}", config.toolchain);
+    let mut command = std::process::Command::new("cargo");
+    command
+        .args([
+            toolchain_arg.as_str(), // Use the configured nightly toolchain
+            "doc",                  // Generate documentation
+            "--lib",                // Document library only
+            "--no-deps",            // Don't document dependencies
+        ])
+        .arg("--manifest-path")
+        .arg(manifest_path) // Crate to document, possibly a non-UTF-8 path
+        .arg("--target-dir")
+        .arg(&target_dir) // Custom target directory
+        .env("RUSTDOCFLAGS", "-Z unstable-options --output-format json") // Enable JSON output
+        .current_dir(crate_root);
+    if config.document_private_items {
+        command.arg("--document-private-items"); // Include private items
+    }
+    let output = output_with_timeout(&mut command, config.rustdoc_timeout)?;
+
+    if !output.status.success() {
+        let error_msg = String::from_utf8_lossy(&output.stderr);
+        debug!("Cargo doc command failed with error: {}", error_msg);
+        return Err(QuarryError::RustdocFailed {
+            status: output.status.code(),
+            stderr: error_msg.into_owned(),
+        });
+    }
+
+    // A workspace manifest may document several packages; merge them all
+    let mut all_types = StdlibTypes::default();
+    for entry in std::fs::read_dir(&doc_dir).map_err(QuarryError::Io)? {
+        let json_path = entry.map_err(QuarryError::Io)?.path();
+        if json_path.extension().is_some_and(|ext| ext == "json") {
+            debug!("Found crate JSON at: {:?}", json_path);
+            all_types.merge(parse_rustdoc_json_directly(&json_path)?)?;
+        }
+    }
+
+    debug!(
+        "Parsed {} structs from crate at {:?}",
+        all_types.structs.len(),
+        manifest_path
+    );
+    unshare_structs(all_types.structs)
+}
+
+/// The target directory `mine_crate_structs` documents the crate at `manifest_path` into
+///
+/// Keyed by a hash of the canonical manifest path, so each crate gets a
+/// directory of its own: calls for different crates, even concurrent ones,
+/// never read each other's JSON, and nothing has to be deleted between runs.
+fn crate_target_dir(manifest_path: &std::path::Path) -> std::path::PathBuf {
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    manifest_path.hash(&mut hasher);
+    std::env::temp_dir()
+        .join(CRATE_DOCS_DIR)
+        .join(format!("{:016x}", hasher.finish()))
+}
+
+/// Parse a pre-generated rustdoc JSON file and return the structs it defines
+pub(crate) fn parse_rustdoc_json_file(
+    json_path: &std::path::Path,
+) -> Result<HashMap<String, StructInfo>> {
+    let types = parse_rustdoc_json_directly(json_path)?;
+    debug!(
+        "Parsed {} structs from {:?}",
+        types.structs.len(),
+        json_path
+    );
+    unshare_structs(types.structs)
+}
+
+/// Parse rustdoc JSON held in a string and return the structs it defines
+pub(crate) fn parse_rustdoc_json_str(json: &str) -> Result<HashMap<String, StructInfo>> {
+    let types = parse_rustdoc_json_bytes(json.as_bytes())?;
+    debug!(
+        "Parsed {} structs from in-memory rustdoc JSON",
+        types.structs.len()
+    );
+    unshare_structs(types.structs)
+}
+
+/// Turn a freshly parsed struct map into owned values for callers outside the cache
+///
+/// Nothing else holds the `Arc`s yet, so no struct is cloned.
+fn unshare_structs(structs: StructStore) -> Result<HashMap<String, StructInfo>> {
+    Ok(structs
+        .into_map()?
+        .into_iter()
+        .map(|(name, info)| (name, Arc::unwrap_or_clone(info)))
+        .collect())
+}
+
+/// Seed the global cache with the types parsed from a pre-generated rustdoc JSON file
+///
+/// The types are stored under the default configuration, so later default
+/// lookups use them without running `cargo doc`. If the cache already holds
+/// types for the default configuration, the new types are merged into them,
+/// which allows loading `std.json`, `alloc.json`, and `core.json` one by one.
+/// A cache with merged types is never written to the disk cache.
+pub(crate) fn load_cache_from_json(json_path: &std::path::Path) -> Result<()> {
+    let types = parse_rustdoc_json_directly(json_path)?;
+    debug!("Loaded {} types from {:?}", types.len(), json_path);
+    seed_cache(types, true)
+}
+
+/// Store types in the global cache under the default configuration
+///
+/// With `merge`, types already cached for the default configuration are kept
+/// and the new ones merged into them; otherwise the cache is replaced.
+fn seed_cache(mut types: StdlibTypes, merge: bool) -> Result<()> {
+    let cache_key = QuarryConfig::default().cache_key();
+
+    let cache = STDLIB_CACHE.get_or_init(|| RwLock::new(None));
+    let mut cache_guard = cache.write().unwrap();
+    match cache_guard.as_mut() {
+        Some(loaded) if merge && loaded.cache_key == cache_key => {
+            debug!("Merging {} types into existing cache", types.len());
+            loaded.types.merge(types)?;
+            loaded.types.intern_type_names();
+            loaded.seeded = true;
+        }
+        _ => {
+            debug!("Seeding cache with {} types", types.len());
+            types.intern_type_names();
+            let mut loaded = LoadedTypes {
+                cache_key,
+                types,
+                pending: None,
+                seeded: true,
+            };
+            apply_struct_capacity(&mut loaded)?;
+            *cache_guard = Some(loaded);
+        }
+    }
+    Ok(())
+}
+
+/// Write the whole default-configuration cache to a JSON file
+///
+/// The file holds the serialized `StdlibTypes`: every struct, enum, union and
+/// type alias plus the re-export tables, so an import answers exactly the same
+/// queries. The cache is initialized first if needed.
+pub(crate) fn export_cache_to_json(path: &std::path::Path) -> Result<()> {
+    debug!("Exporting stdlib cache to: {:?}", path);
+
+    let content = with_stdlib_types(&QuarryConfig::default(), serde_json::to_vec)?
+        .map_err(|e| QuarryError::Io(std::io::Error::new(std::io::ErrorKind::InvalidData, e)))?;
+    std::fs::write(path, &content).map_err(QuarryError::Io)?;
+
+    debug!("Exported {} bytes to {:?}", content.len(), path);
+    Ok(())
+}
+
+/// Write the whole default-configuration cache to a bincode file
+///
+/// Same content as `export_cache_to_json`, in bincode's compact encoding.
+#[cfg(feature = "bincode")]
+pub(crate) fn export_cache_bin(path: &std::path::Path) -> Result<()> {
+    debug!("Exporting stdlib cache (bincode) to: {:?}", path);
+
+    let content = with_stdlib_types(&QuarryConfig::default(), bincode::serialize)?
+        .map_err(|e| QuarryError::Io(std::io::Error::new(std::io::ErrorKind::InvalidData, e)))?;
+    std::fs::write(path, &content).map_err(QuarryError::Io)?;
+
+    debug!("Exported {} bytes to {:?}", content.len(), path);
+    Ok(())
+}
+
+/// Replace the default-configuration cache with one exported by `export_cache_bin`
+#[cfg(feature = "bincode")]
+pub(crate) fn import_cache_bin(path: &std::path::Path) -> Result<()> {
+    debug!("Importing stdlib cache (bincode) from: {:?}", path);
+
+    let content = std::fs::read(path).map_err(QuarryError::Io)?;
+    let types: StdlibTypes = bincode::deserialize(&content)
+        .map_err(|e| QuarryError::Io(std::io::Error::new(std::io::ErrorKind::InvalidData, e)))?;
+
+    debug!("Imported {} types from {:?}", types.len(), path);
+    seed_cache(types, false)
+}
+
+/// Replace the default-configuration cache with one exported by `export_cache_to_json`
+pub(crate) fn import_cache_from_json(path: &std::path::Path) -> Result<()> {
+    debug!("Importing stdlib cache from: {:?}", path);
+
+    let content = std::fs::read(path).map_err(QuarryError::Io)?;
+    let types: StdlibTypes = serde_json::from_slice(&content)
+        .map_err(|e| QuarryError::Io(std::io::Error::new(std::io::ErrorKind::InvalidData, e)))?;
+
+    debug!("Imported {} types from {:?}", types.len(), path);
+    seed_cache(types, false)
+}
+
+/// Reject rustdoc JSON whose schema version Quarry has not been written against
+fn check_format_version(found: u32) -> Result<()> {
+    if SUPPORTED_RUSTDOC_FORMAT_VERSIONS.contains(&found) {
+        Ok(())
+    } else {
+        debug!("Unsupported rustdoc JSON format version: {}", found);
+        Err(QuarryError::UnsupportedRustdocFormat {
+            found,
+            supported: SUPPORTED_RUSTDOC_FORMAT_VERSIONS,
+        })
+    }
+}
+
+/// Parse rustdoc JSON directly to extract struct and enum information with private fields
+fn parse_rustdoc_json_directly(json_path: &std::path::Path) -> Result<StdlibTypes> {
+    debug!("Parsing rustdoc JSON from: {:?}", json_path);
+
+    debug!("Reading JSON file content");
+    let json_content = std::fs::read(json_path).map_err(QuarryError::Io)?;
+    debug!("JSON file size: {} bytes", json_content.len());
+
+    parse_rustdoc_json_bytes(&json_content)
+}
+
+/// Parse rustdoc JSON that is already in memory
+///
+/// Shared by the file-based parser and [`parse_rustdoc_json_str`], so both
+/// report unsupported format versions and malformed JSON the same way.
+fn parse_rustdoc_json_bytes(json_content: &[u8]) -> Result<StdlibTypes> {
+    let mut types = StdlibTypes::default();
+
+    debug!("Parsing JSON content");
+    let krate: Crate = match serde_json::from_slice(json_content) {
+        Ok(krate) => krate,
+        Err(e) => {
+            // A schema change is the likely cause when the version is unsupported
+            if let Ok(version) = serde_json::from_slice::<rustdoc_json::FormatVersion>(json_content)
+            {
+                check_format_version(version.format_version)?;
+            }
+            return Err(QuarryError::MalformedRustdocJson(e));
+        }
+    };
+
+    debug!("rustdoc JSON format version: {}", krate.format_version);
+    check_format_version(krate.format_version)?;
+    types.format_version = Some(krate.format_version);
+
+    let crate_name = root_crate_name(&krate).unwrap_or_default().to_string();
+    debug!("Documented crate name: '{}'", crate_name);
+
+    // Extract struct information from the index
+    debug!("Found index with {} items", krate.index.len());
+    let mut processed = 0;
+
+    for item in krate.index.values() {
+        if let Some(struct_info) = parse_item_for_struct(item, &krate, &crate_name)? {
+            debug!("Found struct: {}", struct_info.name);
+            // Insert with full name only - requires users to be explicit about paths
+            let is_definition = defines_path(&crate_name, &struct_info.name);
+            insert_struct_with_full_name(&mut types, Arc::new(struct_info), is_definition)?;
+        } else if let Some(enum_info) = parse_item_for_enum(item, &krate, &crate_name)? {
+            debug!("Found enum: {}", enum_info.name);
+            types.enums.insert(enum_info.name.clone(), enum_info);
+        } else if let Some(union_info) = parse_item_for_union(item, &krate, &crate_name)? {
+            debug!("Found union: {}", union_info.name);
+            types.unions.insert(union_info.name.clone(), union_info);
+        } else if let Some(alias_info) = parse_item_for_type_alias(item, &krate, &crate_name) {
+            debug!("Found type alias: {}", alias_info.name);
+            types.aliases.insert(alias_info.name.clone(), alias_info);
+        }
+        processed += 1;
+    }
+    collect_reexports(&krate, &mut types);
+    debug!(
+        "Finished processing {} items, found {} structs, {} enums, {} unions and {} type aliases",
+        processed,
+        types.structs.len(),
+        types.enums.len(),
+        types.unions.len(),
+        types.aliases.len()
+    );
+
+    Ok(types)
+}
+
+/// Record the public `use` declarations of a crate as re-exports
+///
+/// Each public `use` item inside a module maps the path it creates to the
+/// canonical path of the imported item, taken from the `paths` map. Glob imports
+/// are recorded separately since they re-export every public name of their source.
+///
+/// # JSON Structure Example
+///
+/// `pub use core::option;` in the root of `std` looks like:
+/// ```json
+/// {
+///   "visibility": "public",
+///   "inner": {
+///     "use": { "source": "core::option", "name": "option", "id": 812, "is_glob": false }
+///   }
+/// }
+/// ```
+/// with `"812": { "crate_id": 2, "path": ["core", "option"], "kind": "module" }`
+/// in `paths`, giving the re-export `std::option` → `core::option`.
+fn collect_reexports(krate: &Crate, types: &mut StdlibTypes) {
+    let mut glob_reexports = Vec::new();
+
+    for (module_id, module_item) in &krate.index {
+        let Some(module) = &module_item.inner.module else {
+            continue;
+        };
+        let Some(module_summary) = krate.paths.get(module_id) else {
+            continue;
+        };
+        let module_path = module_summary.path.join("::");
+
+        for item_id in &module.items {
+            let Some(item) = krate.index.get(item_id) else {
+                continue;
+            };
+            let Some(use_item) = &item.inner.use_ else {
+                continue;
+            };
+            if !matches!(
+                item.visibility.get(),
+                Some(rustdoc_json::RawVisibility::Public)
+            ) {
+                continue;
+            }
+            // Imports of primitives and other unresolvable paths carry no ID
+            let Some(target) = use_item.id.as_ref().and_then(|id| krate.paths.get(id)) else {
+                continue;
+            };
+            let target = target.path.join("::");
+
+            if use_item.is_glob {
+                glob_reexports.push((module_path.clone(), target));
+            } else {
+                let path = format!("{}::{}", module_path, use_item.name);
+                if path != target {
+                    types.reexports.insert(path, target);
+                }
+            }
+        }
+    }
+
+    // Keep resolution deterministic despite the index's hash order
+    glob_reexports.sort();
+    debug!(
+        "Collected {} re-exports and {} glob re-exports",
+        types.reexports.len(),
+        glob_reexports.len()
+    );
+    types.glob_reexports.extend(glob_reexports);
+}
+
+/// Get the name of the crate a rustdoc JSON file documents
+///
+/// The top-level `root` field holds the ID of the crate's root module, whose
+/// index entry carries the crate name (with `-` already replaced by `_`):
+/// ```json
+/// {
+///   "root": 0,
+///   "index": { "0": { "name": "my_crate", "inner": { "module": { ... } } } }
+/// }
+/// ```
+fn root_crate_name(krate: &Crate) -> Option<&str> {
+    krate.index.get(&krate.root)?.name.as_deref()
+}
+
+/// Parse a single item from rustdoc JSON to see if it's a struct
+///
+/// This function examines a rustdoc JSON item and determines if it represents
+/// a struct definition. It extracts the struct name, module path, fields, and
+/// other metadata from the JSON structure.
+///
+/// # JSON Structure Example
+///
+/// For a struct like `String`, the JSON looks like:
+/// ```json
+/// {
+///   "id": 246,
+///   "crate_id": 0,
+///   "name": "String",
+///   "span": {
+///     "filename": "alloc/src/string.rs",
 ///     "begin": [360, 1],
 ///     "end": [362, 2]
 ///   },
@@ -304,35 +1480,19 @@ fn parse_rustdoc_json_directly(json_path: &std::path::Path) -> Result<HashMap<St
 /// - `Ok(Some(StructInfo))` if the item is a struct
 /// - `Ok(None)` if the item is not a struct or cannot be parsed
 /// - `Err(QuarryError)` if there's an error during parsing
-fn parse_item_for_struct(item_data: &Value, full_json: &Value) -> Result<Option<StructInfo>> {
-    let item_obj = match item_data.as_object() {
-        Some(obj) => obj,
-        None => return Ok(None),
-    };
-
-    // Check if this item has struct inner data
-    let inner = match item_obj.get("inner") {
-        Some(inner) => inner,
-        None => return Ok(None),
-    };
-
-    let inner_obj = match inner.as_object() {
-        Some(obj) => obj,
-        None => return Ok(None),
-    };
-
+fn parse_item_for_struct(
+    item: &Item,
+    krate: &Crate,
+    crate_name: &str,
+) -> Result<Option<StructInfo>> {
     // Look for struct data
-    let struct_data = match inner_obj.get("struct") {
+    let struct_data = match &item.inner.struct_ {
         Some(data) => data,
         None => return Ok(None), // Not a struct
     };
 
     // Get the struct name
-    let name = item_obj
-        .get("name")
-        .and_then(|n| n.as_str())
-        .unwrap_or("")
-        .to_string();
+    let name = item.name.clone().unwrap_or_default();
 
     if name.is_empty() {
         return Ok(None);
@@ -342,7 +1502,7 @@ fn parse_item_for_struct(item_data: &Value, full_json: &Value) -> Result<Option<
 
     // Get the full path for this item
     debug!("Getting full path for struct: {}", name);
-    let full_path = get_full_path_for_item(item_obj);
+    let full_path = get_full_path_for_item(item, krate, crate_name);
     let struct_name = if full_path.is_empty() {
         name.clone()
     } else {
@@ -354,139 +1514,381 @@ fn parse_item_for_struct(item_data: &Value, full_json: &Value) -> Result<Option<
 
     // Parse struct kind and fields
     debug!("Parsing struct kind and fields for: {}", struct_name);
-    if let Some(struct_obj) = struct_data.as_object() {
-        parse_struct_kind_and_fields(&mut struct_info, struct_obj, full_json)?;
-        debug!(
-            "Found {} fields for struct {}",
-            struct_info.fields.len(),
-            struct_name
-        );
+    parse_struct_kind_and_fields(&mut struct_info, struct_data, krate)?;
+    debug!(
+        "Found {} fields for struct {}",
+        struct_info.fields.len(),
+        struct_name
+    );
+
+    // Record where the struct is defined
+    struct_info.span = parse_span(item.span.as_ref());
+
+    // Capture the doc comment; `null` means the item is undocumented
+    struct_info.docs = item.docs.clone();
+
+    // Capture layout hints such as `#[repr(C)]`
+    struct_info.reprs = parse_reprs(&item.attrs);
+    if !struct_info.reprs.is_empty() {
+        debug!("Struct {} reprs: {:?}", struct_name, struct_info.reprs);
     }
 
-    // Parse visibility for debugging
-    if let Some(visibility) = item_obj.get("visibility") {
-        debug!("Struct {} visibility: {:?}", struct_name, visibility);
+    // Record whether the struct is usable on stable
+    struct_info.stability = parse_stability(&item.attrs);
+
+    // Record whether the struct is `#[deprecated]`
+    struct_info.deprecation = parse_deprecation(item.deprecation.as_ref());
+
+    // Record whether downstream crates may construct the struct
+    struct_info.is_non_exhaustive = is_non_exhaustive(&item.attrs);
+
+    // Record the platforms or features the struct is limited to
+    struct_info.cfg = parse_cfg(&item.attrs);
+
+    // Capture the traits implemented by `#[derive(...)]`
+    struct_info.derives = parse_derives(&struct_data.impls, krate);
+    if !struct_info.derives.is_empty() {
+        debug!("Struct {} derives: {:?}", struct_name, struct_info.derives);
     }
 
+    // Capture the functions and constants of the inherent impl blocks
+    (struct_info.methods, struct_info.associated_constants) =
+        parse_inherent_impl_items(&struct_data.impls, krate);
+    debug!(
+        "Found {} inherent methods and {} associated constants for struct {}",
+        struct_info.methods.len(),
+        struct_info.associated_constants.len(),
+        struct_name
+    );
+
+    // Capture the traits of the explicit trait impl blocks
+    struct_info.implemented_traits = parse_implemented_traits(&struct_data.impls, krate);
+    debug!(
+        "Struct {} implements {} traits",
+        struct_name,
+        struct_info.implemented_traits.len()
+    );
+
+    // Parse the struct's own visibility
+    struct_info.visibility = parse_visibility(&item.visibility);
+    debug!(
+        "Struct {} visibility: {:?}",
+        struct_name, struct_info.visibility
+    );
+
     Ok(Some(struct_info))
 }
 
-/// Get the full module path for an item
+/// Parse a single item from rustdoc JSON to see if it's an enum
 ///
-/// This function constructs the full module path for a Rust item by examining
-/// its source file location and extracting the module hierarchy.
+/// This is the enum counterpart of `parse_item_for_struct`. It looks for the
+/// `"enum"` key inside `inner` and resolves each variant ID in the index.
 ///
 /// # JSON Structure Example
 ///
-/// The function looks for span information in the JSON:
+/// For an enum like `Option`, the JSON looks like:
 /// ```json
 /// {
-///   "name": "String",
-///   "span": {
-///     "filename": "alloc/src/string.rs",
-///     "begin": [360, 1],
-///     "end": [362, 2]
+///   "name": "Option",
+///   "span": { "filename": "core/src/option.rs", ... },
+///   "inner": {
+///     "enum": {
+///       "generics": { "params": [...], "where_predicates": [] },
+///       "has_stripped_variants": false,
+///       "variants": [132, 135]  // Variant IDs to look up in the index
+///     }
 ///   }
 /// }
 /// ```
 ///
-/// From this, it extracts:
-/// - `"alloc/src/string.rs"` → `"alloc::string"`
-/// - Combined with name: `"alloc::string::String"`
+/// # Returns
 ///
-/// # Supported Patterns
+/// - `Ok(Some(EnumInfo))` if the item is an enum
+/// - `Ok(None)` if the item is not an enum or cannot be parsed
+/// - `Err(QuarryError)` if there's an error during parsing
+fn parse_item_for_enum(item: &Item, krate: &Crate, crate_name: &str) -> Result<Option<EnumInfo>> {
+    // Look for enum data
+    let enum_data = match &item.inner.enum_ {
+        Some(data) => data,
+        None => return Ok(None), // Not an enum
+    };
+
+    let name = item.name.as_deref().unwrap_or("");
+    if name.is_empty() {
+        return Ok(None);
+    }
+
+    debug!("Parsing enum details for: {}", name);
+    let mut enum_info = EnumInfo::new(&get_full_path_for_item(item, krate, crate_name));
+
+    debug!(
+        "Found {} variant IDs for enum: {}",
+        enum_data.variants.len(),
+        enum_info.name
+    );
+    enum_info.variants = parse_variants_by_ids(&enum_data.variants, krate, &enum_info.simple_name)?;
+
+    Ok(Some(enum_info))
+}
+
+/// Parse enum variants by looking up their IDs in the rustdoc JSON index
 ///
-/// - `"std/src/collections/mod.rs"` → `"std::collections"`
-/// - `"alloc/src/vec/mod.rs"` → `"alloc::vec"`
-/// - `"core/src/ptr/mod.rs"` → `"core::ptr"`
+/// Each variant item carries a `kind` that is either the string `"plain"` for
+/// unit variants, `{"tuple": [ids]}` for tuple variants, or
+/// `{"struct": {"fields": [ids]}}` for struct variants. Field IDs are resolved
+/// through `parse_fields_by_ids`, so variant fields are reported exactly like
+/// struct fields. Stripped tuple fields appear as `null` IDs and are skipped.
 ///
 /// # Arguments
 ///
-/// * `item_obj` - The JSON object representing the item
-///
-/// # Returns
-///
-/// The full module path string, or just the item name if no path can be determined
-fn get_full_path_for_item(item_obj: &serde_json::Map<String, Value>) -> String {
-    let item_name = item_obj
-        .get("name")
-        .and_then(|n| n.as_str())
-        .unwrap_or("unknown");
+/// * `variant_ids` - Variant IDs from the enum definition
+/// * `krate` - Parsed rustdoc JSON containing the index
+/// * `enum_name` - Simple name of the parent enum (for field association)
+fn parse_variants_by_ids(
+    variant_ids: &[Id],
+    krate: &Crate,
+    enum_name: &str,
+) -> Result<Vec<VariantInfo>> {
+    let mut variants = Vec::new();
+
+    for variant_id in variant_ids {
+        let Some(variant_item) = krate.index.get(variant_id) else {
+            debug!("Could not find variant item for ID: {:?}", variant_id);
+            continue;
+        };
 
-    debug!("Getting full path for item: {}", item_name);
+        let variant_name = variant_item
+            .name
+            .clone()
+            .unwrap_or_else(|| "unknown".to_string());
+        let owner = format!("{}::{}", enum_name, variant_name);
+
+        let kind = variant_item.inner.variant.as_ref().map(|v| &v.kind);
+
+        let (kind, fields) = match kind {
+            Some(rustdoc_json::VariantKind::Tuple(field_ids)) => (
+                VariantKind::Tuple,
+                parse_fields_by_ids(&field_ids.ids(), krate, &owner, true)?,
+            ),
+            Some(rustdoc_json::VariantKind::Struct { fields }) => (
+                VariantKind::Struct,
+                parse_fields_by_ids(fields, krate, &owner, false)?,
+            ),
+            _ => (VariantKind::Unit, Vec::new()),
+        };
 
-    // Try to get the path from the item's span or other metadata
-    if let Some(span) = item_obj.get("span") {
-        debug!("Found span data for item: {}", item_name);
-        if let Some(span_obj) = span.as_object() {
-            if let Some(filename) = span_obj.get("filename") {
-                if let Some(filename_str) = filename.as_str() {
-                    debug!("Source filename for {}: {}", item_name, filename_str);
-                    // Extract module path from filename
-                    if let Some(module_path) = extract_module_path_from_filename(filename_str) {
-                        let full_path = format!("{}::{}", module_path, item_name);
-                        debug!("Constructed full path for {}: {}", item_name, full_path);
-                        return full_path;
-                    } else {
-                        debug!(
-                            "Could not extract module path from filename: {}",
-                            filename_str
-                        );
-                    }
-                }
-            }
-        }
+        debug!(
+            "Parsed variant: {} ({:?}, {} fields)",
+            owner,
+            kind,
+            fields.len()
+        );
+        variants.push(VariantInfo {
+            name: variant_name,
+            kind,
+            fields,
+        });
     }
 
-    // Fallback: just use the name
-    debug!("Using fallback name for item: {}", item_name);
-    item_name.to_string()
+    Ok(variants)
 }
 
-/// Extract module path from a source filename
-///
-/// This function parses Rust standard library source file paths and converts
-/// them into module paths using Rust's module naming conventions.
-///
-/// # Examples
+/// Parse a single item from rustdoc JSON to see if it's a union
 ///
-/// ```
-/// // Standard library patterns:
-/// extract_module_path_from_filename("std/src/collections/mod.rs")
-///   // → Some("std::collections")
+/// Union fields are stored exactly like the fields of a plain struct, so they
+/// are resolved through `parse_fields_by_ids`.
 ///
-/// extract_module_path_from_filename("alloc/src/string.rs")
-///   // → Some("alloc::string")
-///
-/// extract_module_path_from_filename("core/src/ptr/mod.rs")
-///   // → Some("core::ptr")
+/// # JSON Structure Example
 ///
-/// // Non-standard library files:
-/// extract_module_path_from_filename("src/main.rs")
-///   // → None
+/// ```json
+/// {
+///   "name": "MaybeUninit",
+///   "span": { "filename": "core/src/mem/maybe_uninit.rs", ... },
+///   "inner": {
+///     "union": {
+///       "generics": { "params": [...], "where_predicates": [] },
+///       "has_stripped_fields": false,
+///       "fields": [8120, 8121],
+///       "impls": [...]
+///     }
+///   }
+/// }
 /// ```
 ///
-/// # Supported Crates
-///
-/// - **std**: `std/src/` → `std::`
-/// - **alloc**: `alloc/src/` → `alloc::`  
-/// - **core**: `core/src/` → `core::`
-///
-/// # Path Processing
+/// # Returns
 ///
-/// The function filters out common Rust file patterns:
-/// - `mod.rs` - Module definition files
-/// - `lib.rs` - Library root files  
-/// - `*.rs` - Individual source files
+/// * `Ok(Some(UnionInfo))` - If the item is a union
+/// * `Ok(None)` - If the item is not a union or has no name
+fn parse_item_for_union(item: &Item, krate: &Crate, crate_name: &str) -> Result<Option<UnionInfo>> {
+    // Look for union data
+    let union_data = match &item.inner.union_ {
+        Some(data) => data,
+        None => return Ok(None), // Not a union
+    };
+
+    let name = item.name.as_deref().unwrap_or("");
+    if name.is_empty() {
+        return Ok(None);
+    }
+
+    debug!("Parsing union details for: {}", name);
+    let mut union_info = UnionInfo::new(&get_full_path_for_item(item, krate, crate_name));
+
+    union_info.fields =
+        parse_fields_by_ids(&union_data.fields, krate, &union_info.simple_name, false)?;
+    union_info.where_predicates = parse_where_predicates(&union_data.generics);
+    union_info.visibility = parse_visibility(&item.visibility);
+    union_info.docs = item.docs.clone();
+    union_info.span = parse_span(item.span.as_ref());
+    union_info.reprs = parse_reprs(&item.attrs);
+    union_info.derives = parse_derives(&union_data.impls, krate);
+    union_info.stability = parse_stability(&item.attrs);
+    union_info.deprecation = parse_deprecation(item.deprecation.as_ref());
+
+    debug!(
+        "Found {} fields for union {}",
+        union_info.fields.len(),
+        union_info.name
+    );
+    Ok(Some(union_info))
+}
+
+/// Parse a single item from rustdoc JSON to see if it's a type alias
+///
+/// # JSON Structure Example
+///
+/// For `type Result<T> = result::Result<T, Error>;` the JSON looks like:
+/// ```json
+/// {
+///   "name": "Result",
+///   "inner": {
+///     "type_alias": {
+///       "type": {
+///         "resolved_path": {
+///           "path": "result::Result",
+///           "id": 52,
+///           "args": { "angle_bracketed": { "args": [...], "constraints": [] } }
+///         }
+///       },
+///       "generics": { "params": [...], "where_predicates": [] }
+///     }
+///   }
+/// }
+/// ```
+///
+/// Older formats use the key `"typedef"` instead of `"type_alias"`.
+///
+/// # Returns
+///
+/// `Some(AliasInfo)` if the item is a named type alias, `None` otherwise
+fn parse_item_for_type_alias(item: &Item, krate: &Crate, crate_name: &str) -> Option<AliasInfo> {
+    let alias_data = item.inner.type_alias.as_ref()?;
+
+    let name = item.name.as_deref().unwrap_or("");
+    if name.is_empty() {
+        return None;
+    }
+
+    debug!("Parsing type alias details for: {}", name);
+    let mut alias_info = AliasInfo::new(&get_full_path_for_item(item, krate, crate_name));
+
+    alias_info.ty = alias_data.type_.get().and_then(extract_type_from_json);
+    if let Some(ty) = &alias_info.ty {
+        alias_info.type_name = ty.to_string();
+    }
+    if let Some(rustdoc_json::Type::ResolvedPath(path)) = alias_data.type_.get() {
+        alias_info.target = resolve_path_target(path, krate, crate_name);
+    }
+    alias_info.visibility = parse_visibility(&item.visibility);
+    alias_info.docs = item.docs.clone();
+    alias_info.span = parse_span(item.span.as_ref());
+
+    debug!("Type alias {} = {}", alias_info.name, alias_info.type_name);
+    Some(alias_info)
+}
+
+/// Get the full module path for an item
+///
+/// The canonical path is taken from the top-level `paths` map, which rustdoc
+/// fills with the module path each item is defined under:
+/// ```json
+/// {
+///   "paths": {
+///     "246": { "crate_id": 0, "path": ["alloc", "string", "String"], "kind": "struct" }
+///   }
+/// }
+/// ```
+///
+/// Items missing from `paths` (such as items declared inside function bodies)
+/// fall back to reconstructing the path from the item's source file location.
+///
+/// # Filename Fallback Example
+///
+/// The fallback looks for span information in the JSON:
+/// ```json
+/// {
+///   "name": "String",
+///   "span": {
+///     "filename": "alloc/src/string.rs",
+///     "begin": [360, 1],
+///     "end": [362, 2]
+///   }
+/// }
+/// ```
+///
+/// From this, it extracts:
+/// - `"alloc/src/string.rs"` → `"alloc::string"`
+/// - Combined with name: `"alloc::string::String"`
+///
+/// # Supported Patterns
+///
+/// - `"std/src/collections/mod.rs"` → `"std::collections"`
+/// - `"alloc/src/vec/mod.rs"` → `"alloc::vec"`
+/// - `"core/src/ptr/mod.rs"` → `"core::ptr"`
+/// - `"src/config/mod.rs"` in crate `my_crate` → `"my_crate::config"`
 ///
 /// # Arguments
 ///
-/// * `filename` - The source file path from rustdoc JSON
+/// * `item` - The rustdoc item
+/// * `krate` - Parsed rustdoc JSON containing the `paths` map
+/// * `crate_name` - Name of the documented crate, used for non-stdlib crates
 ///
 /// # Returns
 ///
-/// * `Some(String)` - The module path if a recognized pattern is found
-/// * `None` - If the file doesn't match any known standard library patterns
+/// The full module path string, or just the item name if no path can be determined
+fn get_full_path_for_item(item: &Item, krate: &Crate, crate_name: &str) -> String {
+    let item_name = item.name.as_deref().unwrap_or("unknown");
+
+    debug!("Getting full path for item: {}", item_name);
+
+    // Prefer the authoritative path recorded by rustdoc
+    if let Some(summary) = krate.paths.get(&item.id) {
+        let full_path = summary.path.join("::");
+        debug!("Found canonical path for {}: {}", item_name, full_path);
+        return full_path;
+    }
+
+    // Try to get the path from the item's span
+    if let Some(span) = &item.span {
+        let filename_str = span.filename.as_str();
+        debug!("Source filename for {}: {}", item_name, filename_str);
+        // Extract module path from filename
+        if let Some(module_path) = extract_module_path_from_filename(filename_str, crate_name) {
+            let full_path = format!("{}::{}", module_path, item_name);
+            debug!("Constructed full path for {}: {}", item_name, full_path);
+            return full_path;
+        } else {
+            debug!(
+                "Could not extract module path from filename: {}",
+                filename_str
+            );
+        }
+    }
+
+    // Fallback: just use the name
+    debug!("Using fallback name for item: {}", item_name);
+    item_name.to_string()
+}
 
 /// Helper function to process path parts by filtering out Rust file patterns
 ///
@@ -503,7 +1905,7 @@ fn get_full_path_for_item(item_obj: &serde_json::Map<String, Value>) -> String {
 ///
 /// # Examples
 ///
-/// ```
+/// ```text
 /// process_path_parts("collections/hash_map.rs") // → ["collections", "hash_map"]
 /// process_path_parts("string.rs")               // → ["string"]
 /// process_path_parts("ptr/mod.rs")              // → ["ptr"]
@@ -513,46 +1915,118 @@ fn process_path_parts(path_after_src: &str) -> Vec<&str> {
     path_after_src
         .split('/')
         .filter(|&part| part != "mod.rs" && part != "lib.rs")
-        .map(|part| {
-            if part.ends_with(".rs") {
-                &part[..part.len() - 3]
-            } else {
-                part
-            }
-        })
+        .map(|part| part.strip_suffix(".rs").unwrap_or(part))
         .collect()
 }
 
-fn extract_module_path_from_filename(filename: &str) -> Option<String> {
+/// Find the part of a stdlib source filename after a crate's `src/` directory
+///
+/// Depending on how rust-src was packaged, spans read `std/src/string.rs`,
+/// `library/std/src/string.rs` or an absolute path ending in either. A leading
+/// `library/` component is stripped first, and `src_prefix` (e.g., "std/src/")
+/// only matches at the start of a path component, so `my-std/src/lib.rs` is not
+/// taken for `std`.
+///
+/// # Examples
+///
+/// ```text
+/// stdlib_path_after_src("std/src/string.rs", "std/src/")          // → Some("string.rs")
+/// stdlib_path_after_src("library/std/src/string.rs", "std/src/")  // → Some("string.rs")
+/// stdlib_path_after_src("/rust/library/std/src/io/mod.rs", "std/src/") // → Some("io/mod.rs")
+/// stdlib_path_after_src("my-std/src/lib.rs", "std/src/")          // → None
+/// ```
+fn stdlib_path_after_src<'a>(filename: &'a str, src_prefix: &str) -> Option<&'a str> {
+    let filename = filename.strip_prefix(LIBRARY_DIR).unwrap_or(filename);
+    filename
+        .match_indices(src_prefix)
+        .find(|(pos, _)| *pos == 0 || filename[..*pos].ends_with('/'))
+        .map(|(pos, _)| &filename[pos + src_prefix.len()..])
+}
+
+/// Extract module path from a source filename
+///
+/// This function parses Rust standard library source file paths and converts
+/// them into module paths using Rust's module naming conventions.
+///
+/// # Examples
+///
+/// ```text
+/// // Standard library patterns:
+/// extract_module_path_from_filename("std/src/collections/mod.rs")
+///   // → Some("std::collections")
+///
+/// extract_module_path_from_filename("alloc/src/string.rs")
+///   // → Some("alloc::string")
+///
+/// extract_module_path_from_filename("library/alloc/src/string.rs")
+///   // → Some("alloc::string")
+///
+/// extract_module_path_from_filename("core/src/ptr/mod.rs")
+///   // → Some("core::ptr")
+///
+/// extract_module_path_from_filename("proc_macro/src/bridge/client.rs")
+///   // → Some("proc_macro::bridge::client")
+///
+/// // Other crates fall back to the crate name from the JSON:
+/// extract_module_path_from_filename("src/config/mod.rs", "my_crate")
+///   // → Some("my_crate::config")
+///
+/// // Files outside any `src/` directory:
+/// extract_module_path_from_filename("build/generated.rs", "my_crate")
+///   // → None
+/// ```
+///
+/// # Supported Crates
+///
+/// - **std**: `std/src/` → `std::`
+/// - **alloc**: `alloc/src/` → `alloc::`
+/// - **core**: `core/src/` → `core::`
+/// - **proc_macro**: `proc_macro/src/` → `proc_macro::`
+/// - **any other crate**: `src/` → `<crate_name>::`
+///
+/// The stdlib prefixes are only tried when `crate_name` is one of the stdlib
+/// crates (or unknown), and must start a path component (see
+/// `stdlib_path_after_src`), so a workspace member such as `my-core/src/` is not
+/// mistaken for `core`. A leading `library/` component, present in some rust-src
+/// layouts, is ignored.
+///
+/// # Path Processing
+///
+/// The function filters out common Rust file patterns:
+/// - `mod.rs` - Module definition files
+/// - `lib.rs` - Library root files
+/// - `*.rs` - Individual source files
+///
+/// Backslashes are normalized to forward slashes first, since rustdoc records
+/// native paths (`library\alloc\src\string.rs`) when run on Windows.
+///
+/// # Arguments
+///
+/// * `filename` - The source file path from rustdoc JSON
+/// * `crate_name` - Name of the documented crate, or `""` if unknown
+///
+/// # Returns
+///
+/// * `Some(String)` - The module path if a recognized pattern is found
+/// * `None` - If the file doesn't match any known patterns
+fn extract_module_path_from_filename(filename: &str, crate_name: &str) -> Option<String> {
     debug!("Extracting module path from filename: {}", filename);
+    let filename = &filename.replace('\\', "/");
+
+    if !crate_name.is_empty() && !STDLIB_CRATES.contains(&crate_name) {
+        return extract_crate_module_path(filename, crate_name);
+    }
 
     // Look for std patterns - handle "std/src/" pattern
-    if let Some(pos) = filename.find(STD_SRC_PREFIX) {
-        debug!("Found std library pattern in filename at position: {}", pos);
-        let after_src = &filename[pos + STD_SRC_PREFIX.len()..]; // Skip "std/src/"
+    if let Some(after_src) = stdlib_path_after_src(filename, STD_SRC_PREFIX) {
+        debug!("Found std library pattern in filename");
         debug!("Path after 'std/src/': {}", after_src);
 
         let path_parts = process_path_parts(after_src);
         debug!("Filtered path parts: {:?}", path_parts);
 
         if !path_parts.is_empty() {
-            // Handle special cases where public API differs from file structure
-            let module_path = match path_parts.as_slice() {
-                // Collections are exposed at std::collections level regardless of internal structure
-                ["collections", "hash", "map"] => "std::collections".to_string(),
-                ["collections", "hash", "set"] => "std::collections".to_string(),
-                ["collections", "btree", "map"] => "std::collections".to_string(),
-                ["collections", "btree", "set"] => "std::collections".to_string(),
-                ["collections", "linked_list"] => "std::collections".to_string(),
-                ["collections", "vec_deque"] => "std::collections".to_string(),
-                ["collections", "binary_heap"] => "std::collections".to_string(),
-                // For collections that are directly in collections/, use the first level
-                parts if parts.len() >= 2 && parts[0] == "collections" => {
-                    format!("std::collections")
-                }
-                // Default case: join all parts
-                _ => format!("std::{}", path_parts.join("::")),
-            };
+            let module_path = format!("std::{}", path_parts.join("::"));
             debug!("Constructed module path: {}", module_path);
             return Some(module_path);
         } else {
@@ -562,12 +2036,8 @@ fn extract_module_path_from_filename(filename: &str) -> Option<String> {
     }
 
     // Check for alloc crate patterns - handle "alloc/src/" pattern
-    if let Some(pos) = filename.find(ALLOC_SRC_PREFIX) {
-        debug!(
-            "Found alloc library pattern in filename at position: {}",
-            pos
-        );
-        let after_src = &filename[pos + ALLOC_SRC_PREFIX.len()..]; // Skip "alloc/src/"
+    if let Some(after_src) = stdlib_path_after_src(filename, ALLOC_SRC_PREFIX) {
+        debug!("Found alloc library pattern in filename");
         debug!("Path after 'alloc/src/': {}", after_src);
 
         let path_parts = process_path_parts(after_src);
@@ -584,12 +2054,8 @@ fn extract_module_path_from_filename(filename: &str) -> Option<String> {
     }
 
     // Check for core crate patterns - handle "core/src/" pattern
-    if let Some(pos) = filename.find(CORE_SRC_PREFIX) {
-        debug!(
-            "Found core library pattern in filename at position: {}",
-            pos
-        );
-        let after_src = &filename[pos + CORE_SRC_PREFIX.len()..]; // Skip "core/src/"
+    if let Some(after_src) = stdlib_path_after_src(filename, CORE_SRC_PREFIX) {
+        debug!("Found core library pattern in filename");
         debug!("Path after 'core/src/': {}", after_src);
 
         let path_parts = process_path_parts(after_src);
@@ -605,6 +2071,24 @@ fn extract_module_path_from_filename(filename: &str) -> Option<String> {
         }
     }
 
+    // Check for proc_macro crate patterns - handle "proc_macro/src/" pattern
+    if let Some(after_src) = stdlib_path_after_src(filename, PROC_MACRO_SRC_PREFIX) {
+        debug!("Found proc_macro library pattern in filename");
+        debug!("Path after 'proc_macro/src/': {}", after_src);
+
+        let path_parts = process_path_parts(after_src);
+        debug!("Filtered proc_macro path parts: {:?}", path_parts);
+
+        if !path_parts.is_empty() {
+            let module_path = format!("proc_macro::{}", path_parts.join("::"));
+            debug!("Constructed proc_macro module path: {}", module_path);
+            return Some(module_path);
+        } else {
+            debug!("No proc_macro path parts found, using 'proc_macro' as module path");
+            return Some("proc_macro".to_string());
+        }
+    }
+
     debug!(
         "No recognized library pattern found in filename: {}",
         filename
@@ -612,6 +2096,27 @@ fn extract_module_path_from_filename(filename: &str) -> Option<String> {
     None
 }
 
+/// Extract a module path for an item of a non-stdlib crate
+///
+/// Uses the last `src/` directory in the filename as the crate root, so both
+/// relative (`src/lib.rs`) and absolute (`/home/me/proj/src/lib.rs`) spans work.
+fn extract_crate_module_path(filename: &str, crate_name: &str) -> Option<String> {
+    let Some(pos) = filename.rfind(SRC_DIR) else {
+        debug!("No '{}' directory found in filename: {}", SRC_DIR, filename);
+        return None;
+    };
+    let path_parts = process_path_parts(&filename[pos + SRC_DIR.len()..]);
+    debug!("Filtered {} path parts: {:?}", crate_name, path_parts);
+
+    let module_path = if path_parts.is_empty() {
+        crate_name.to_string()
+    } else {
+        format!("{}::{}", crate_name, path_parts.join("::"))
+    };
+    debug!("Constructed {} module path: {}", crate_name, module_path);
+    Some(module_path)
+}
+
 /// Parse struct kind and extract field information
 ///
 /// This function analyzes the struct definition in rustdoc JSON to determine
@@ -635,9 +2140,7 @@ fn extract_module_path_from_filename(filename: &str) -> Option<String> {
 /// ```json
 /// {
 ///   "kind": {
-///     "tuple": {
-///       "fields": [1234, 1235]  // Array of field IDs for tuple elements
-///     }
+///     "tuple": [1234, 1235]  // Field IDs for tuple elements, `null` if stripped
 ///   }
 /// }
 /// ```
@@ -645,9 +2148,7 @@ fn extract_module_path_from_filename(filename: &str) -> Option<String> {
 /// ## Unit Struct (like `struct Unit;`)
 /// ```json
 /// {
-///   "kind": {
-///     "unit": {}
-///   }
+///   "kind": "unit"
 /// }
 /// ```
 ///
@@ -661,8 +2162,8 @@ fn extract_module_path_from_filename(filename: &str) -> Option<String> {
 /// # Arguments
 ///
 /// * `struct_info` - Mutable reference to the `StructInfo` being built
-/// * `struct_obj` - The struct definition JSON object
-/// * `full_json` - Complete rustdoc JSON for field lookups
+/// * `struct_data` - The parsed struct definition
+/// * `krate` - Parsed rustdoc JSON for field lookups
 ///
 /// # Returns
 ///
@@ -670,41 +2171,39 @@ fn extract_module_path_from_filename(filename: &str) -> Option<String> {
 /// * `Err(QuarryError)` - Error occurred during field parsing
 fn parse_struct_kind_and_fields(
     struct_info: &mut StructInfo,
-    struct_obj: &serde_json::Map<String, Value>,
-    full_json: &Value,
+    struct_data: &rustdoc_json::Struct,
+    krate: &Crate,
 ) -> Result<()> {
     debug!("Parsing struct kind for: {}", struct_info.name);
 
-    // Check the struct kind in the rustdoc format: {"kind": {"plain": {"fields": [id1, id2, ...]}}}
-    if let Some(kind) = struct_obj.get("kind") {
-        if let Some(kind_obj) = kind.as_object() {
-            if let Some(plain) = kind_obj.get("plain") {
-                debug!("Found plain struct type for: {}", struct_info.name);
-                if let Some(plain_obj) = plain.as_object() {
-                    if let Some(field_ids) = plain_obj.get("fields").and_then(|f| f.as_array()) {
-                        debug!(
-                            "Found {} field IDs for struct: {}",
-                            field_ids.len(),
-                            struct_info.name
-                        );
-                        // Parse fields by looking up their IDs in the index
-                        struct_info.fields =
-                            parse_fields_by_ids(field_ids, full_json, &struct_info.simple_name)?;
-                    }
-                }
-            } else if let Some(tuple) = kind_obj.get("tuple") {
-                debug!("Found tuple struct type for: {}", struct_info.name);
-                struct_info.is_tuple_struct = true;
-                if let Some(tuple_obj) = tuple.as_object() {
-                    if let Some(field_ids) = tuple_obj.get("fields").and_then(|f| f.as_array()) {
-                        struct_info.fields =
-                            parse_fields_by_ids(field_ids, full_json, &struct_info.simple_name)?;
-                    }
-                }
-            } else if kind_obj.get("unit").is_some() {
-                struct_info.is_unit_struct = true;
-            }
-        } else if kind.as_str() == Some("unit") {
+    // Capture the declared parameters and where-clause bounds, e.g. "S: BuildHasher"
+    struct_info.generic_params = parse_generic_params(&struct_data.generics);
+    struct_info.where_predicates = parse_where_predicates(&struct_data.generics);
+    debug!(
+        "Found {} where predicates for struct: {}",
+        struct_info.where_predicates.len(),
+        struct_info.name
+    );
+
+    match &struct_data.kind {
+        rustdoc_json::StructKind::Plain { fields } => {
+            debug!("Found plain struct type for: {}", struct_info.name);
+            debug!(
+                "Found {} field IDs for struct: {}",
+                fields.len(),
+                struct_info.name
+            );
+            // Parse fields by looking up their IDs in the index
+            struct_info.fields =
+                parse_fields_by_ids(fields, krate, &struct_info.simple_name, false)?;
+        }
+        rustdoc_json::StructKind::Tuple(field_ids) => {
+            debug!("Found tuple struct type for: {}", struct_info.name);
+            struct_info.is_tuple_struct = true;
+            struct_info.fields =
+                parse_fields_by_ids(&field_ids.ids(), krate, &struct_info.simple_name, true)?;
+        }
+        rustdoc_json::StructKind::Unit => {
             struct_info.is_unit_struct = true;
         }
     }
@@ -769,29 +2268,35 @@ fn parse_struct_kind_and_fields(
 ///
 /// ## Visibility Parsing
 ///
-/// - `"public"` → field is public
-/// - `{"restricted": {...}}` → field is private/restricted
-/// - Missing → defaults to private
+/// Visibility is parsed by `parse_visibility`:
+///
+/// - `"public"` → `Visibility::Public`
+/// - `"crate"` → `Visibility::Crate`
+/// - `{"restricted": {"path": "::string", ...}}` → `Visibility::Restricted("::string")`
+/// - `"default"` or missing → `Visibility::Private`
 ///
 /// # Usage
 ///
 /// - **Plain structs**: Fields have actual names like "vec", "len", etc.
-/// - **Tuple structs**: Fields are numbered like "0", "1", "2", etc.
+/// - **Tuple structs**: Fields are numbered like "0", "1", "2", etc. When the
+///   field item carries no name, its position is used instead.
 ///
 /// # Arguments
 ///
-/// * `field_ids` - Array of field ID values from the struct definition
-/// * `full_json` - Complete rustdoc JSON containing the index
+/// * `field_ids` - Field IDs from the struct definition
+/// * `krate` - Parsed rustdoc JSON containing the index
 /// * `struct_name` - Name of the parent struct (for field association)
+/// * `is_tuple` - Whether the fields are positional (tuple struct or variant)
 ///
 /// # Returns
 ///
 /// * `Ok(Vec<FieldInfo>)` - Successfully parsed field information
 /// * `Err(QuarryError)` - Error during field lookup or parsing
 fn parse_fields_by_ids(
-    field_ids: &[Value],
-    full_json: &Value,
+    field_ids: &[Id],
+    krate: &Crate,
     struct_name: &str,
+    is_tuple: bool,
 ) -> Result<Vec<FieldInfo>> {
     debug!(
         "Parsing {} field IDs for struct: {}",
@@ -800,788 +2305,2815 @@ fn parse_fields_by_ids(
     );
     let mut fields = Vec::new();
 
-    if let Some(index) = full_json.get("index").and_then(|i| i.as_object()) {
-        for (i, field_id) in field_ids.iter().enumerate() {
-            if let Some(field_id_num) = field_id.as_u64() {
-                let field_id_str = field_id_num.to_string();
-                debug!(
-                    "Looking up field {} (ID: {}) for struct {}",
-                    i + 1,
-                    field_id_str,
-                    struct_name
-                );
+    for (i, field_id) in field_ids.iter().enumerate() {
+        debug!(
+            "Looking up field {} (ID: {:?}) for struct {}",
+            i + 1,
+            field_id,
+            struct_name
+        );
 
-                if let Some(field_item) = index.get(&field_id_str).and_then(|f| f.as_object()) {
-                    let field_name = field_item
-                        .get("name")
-                        .and_then(|n| n.as_str())
-                        .unwrap_or("unknown")
-                        .to_string();
+        let Some(field_item) = krate.index.get(field_id) else {
+            debug!("Could not find field item for ID: {:?}", field_id);
+            continue;
+        };
 
-                    let visibility = field_item
-                        .get("visibility")
-                        .and_then(|v| v.as_str())
-                        .unwrap_or("private");
+        let field_name = match &field_item.name {
+            Some(name) => name.clone(),
+            None if is_tuple => i.to_string(),
+            None => "unknown".to_string(),
+        };
 
-                    let is_public = visibility == "public";
-                    debug!(
-                        "Field '{}' visibility: {} (public: {})",
-                        field_name, visibility, is_public
-                    );
+        let visibility = parse_visibility(&field_item.visibility);
+        debug!("Field '{}' visibility: {:?}", field_name, visibility);
 
-                    // Get field type from the struct_field inner data
-                    let field_type = if let Some(field_inner) =
-                        field_item.get("inner").and_then(|i| i.as_object())
-                    {
-                        if let Some(struct_field) = field_inner.get("struct_field") {
-                            // The struct_field directly contains the type information
-                            extract_type_name_from_json(struct_field)
-                                .unwrap_or("unknown".to_string())
-                        } else {
-                            "unknown".to_string()
-                        }
-                    } else {
-                        "unknown".to_string()
-                    };
+        // The struct_field inner data directly contains the type information
+        let ty = field_item
+            .inner
+            .struct_field
+            .as_ref()
+            .and_then(Lenient::get)
+            .and_then(extract_type_from_json);
+        let field_type = ty
+            .as_ref()
+            .map_or_else(|| "unknown".to_string(), Type::to_string);
 
-                    debug!(
-                        "Parsed field: {} -> {} (public: {})",
-                        field_name, field_type, is_public
-                    );
+        debug!(
+            "Parsed field: {} -> {} ({:?})",
+            field_name, field_type, visibility
+        );
 
-                    fields.push(FieldInfo {
-                        name: field_name,
-                        type_name: field_type,
-                        is_public,
-                        struct_name: struct_name.to_string(),
-                    });
-                } else {
-                    debug!("Could not find field item for ID: {}", field_id_str);
-                }
-            } else {
-                debug!("Field ID is not a valid number: {:?}", field_id);
-            }
-        }
-    } else {
-        debug!("No index found in rustdoc JSON for field lookup");
+        fields.push(FieldInfo {
+            name: field_name,
+            type_name: field_type.into(),
+            ty,
+            visibility,
+            struct_name: struct_name.to_string(),
+            docs: field_item.docs.clone(),
+            stability: parse_stability(&field_item.attrs),
+            deprecation: parse_deprecation(field_item.deprecation.as_ref()),
+            cfg: parse_cfg(&field_item.attrs),
+        });
     }
 
     debug!("Parsed {} fields for struct: {}", fields.len(), struct_name);
     Ok(fields)
 }
 
-/// Insert a struct with its full name as the key
+/// Find the full definition path of the item a resolved path refers to
 ///
-/// Adds a struct to the cache using only its complete module path as the key.
-/// This enforces the requirement for users to specify exact paths when querying.
+/// The path is taken from the top-level `paths` map, which also covers items
+/// from other crates (e.g. `core` types used by `std`):
+/// ```json
+/// { "paths": { "52": { "crate_id": 1, "path": ["core", "result", "Result"], "kind": "enum" } } }
+/// ```
 ///
-/// # Arguments
+/// Local items missing from `paths` are named like the cache keys.
 ///
-/// * `types` - Mutable reference to the HashMap cache
-/// * `struct_info` - The struct information to insert
-fn insert_struct_with_full_name(types: &mut HashMap<String, StructInfo>, struct_info: StructInfo) {
-    // Insert only with the full path - no variations
-    debug!("Inserting struct with full name: {}", struct_info.name);
-    types.insert(struct_info.name.clone(), struct_info);
+/// # Returns
+///
+/// The full path (e.g., "core::result::Result"), or `None` if the path carries
+/// no ID or the ID is unknown
+fn resolve_path_target(
+    path: &rustdoc_json::Path,
+    krate: &Crate,
+    crate_name: &str,
+) -> Option<String> {
+    let id = path.id.as_ref()?;
+
+    if let Some(summary) = krate.paths.get(id) {
+        return Some(summary.path.join("::"));
+    }
+
+    let item = krate.index.get(id).filter(|item| item.name.is_some())?;
+    Some(get_full_path_for_item(item, krate, crate_name))
 }
 
-/// Extract type name from rustdoc JSON type definition
+/// Parse an item's rustdoc span into a `SourceSpan`
 ///
-/// This function parses the complex type structures in rustdoc JSON to extract
-/// readable type names. It handles resolved paths, primitives, and generic types.
-///
-/// # JSON Type Examples
+/// # JSON Structure Example
 ///
-/// ## Resolved Path Type (e.g., Vec<u8>)
 /// ```json
 /// {
-///   "resolved_path": {
-///     "path": "crate::vec::Vec",
-///     "id": 241,
-///     "args": {
-///       "angle_bracketed": {
-///         "args": [
-///           {
-///             "type": {
-///               "primitive": "u8"
-///             }
-///           }
-///         ]
-///       }
-///     }
-///   }
+///   "filename": "alloc/src/string.rs",
+///   "begin": [360, 1],
+///   "end": [362, 2]
 /// }
 /// ```
-/// Extracted as: "Vec<u8>"
 ///
-/// ## Primitive Type (e.g., usize)
+/// # Returns
+///
+/// `None` if the span is missing or `null` (synthetic items)
+fn parse_span(span: Option<&rustdoc_json::Span>) -> Option<SourceSpan> {
+    let span = span?;
+    Some(SourceSpan {
+        filename: span.filename.clone(),
+        begin: span.begin,
+        end: span.end,
+    })
+}
+
+/// Parse the `#[repr(...)]` hints out of an item's attributes
+///
+/// # JSON Structure Examples
+///
+/// Current formats merge all `repr` attributes into one object:
 /// ```json
-/// {
-///   "primitive": "usize"
-/// }
+/// { "repr": { "kind": "c", "align": 8, "packed": null, "int": null } }
 /// ```
-/// Extracted as: "usize"
 ///
-/// ## Generic Type (e.g., T)
+/// Older formats keep the attribute's source text:
 /// ```json
-/// {
-///   "generic": "T"
-/// }
+/// "#[repr(C, align(8))]"
 /// ```
-/// Extracted as: "T"
 ///
-/// ## Tuple Type (e.g., (i32, String))
-/// ```json
-/// {
-///   "tuple": [
-///     {"primitive": "i32"},
-///     {"resolved_path": {"path": "std::string::String"}}
-///   ]
-/// }
-/// ```
-/// Extracted as: "(i32, String)"
+/// # Returns
 ///
-/// # Type Extraction Rules
+/// The hints in source spelling (e.g., `["C", "align(8)"]`). The default
+/// `Rust` representation is omitted, so ordinary structs yield an empty vector.
+fn parse_reprs(attrs: &[Lenient<rustdoc_json::Attribute>]) -> Vec<String> {
+    let mut reprs = Vec::new();
+
+    for attr in attrs.iter().filter_map(Lenient::get) {
+        match attr {
+            rustdoc_json::Attribute::Structured(rustdoc_json::StructuredAttribute::Repr(repr)) => {
+                match repr.kind.as_str() {
+                    "rust" => {}
+                    "c" => reprs.push("C".to_string()),
+                    kind => reprs.push(kind.to_string()),
+                }
+                if let Some(int) = &repr.int {
+                    reprs.push(int.clone());
+                }
+                match repr.packed {
+                    Some(1) => reprs.push("packed".to_string()),
+                    Some(n) => reprs.push(format!("packed({})", n)),
+                    None => {}
+                }
+                if let Some(align) = repr.align {
+                    reprs.push(format!("align({})", align));
+                }
+            }
+            rustdoc_json::Attribute::Structured(_) => {}
+            rustdoc_json::Attribute::Legacy(text) => {
+                let Some(hints) = text
+                    .strip_prefix("#[repr(")
+                    .and_then(|rest| rest.strip_suffix(")]"))
+                else {
+                    continue;
+                };
+                reprs.extend(
+                    hints
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|hint| !hint.is_empty() && *hint != "Rust")
+                        .map(str::to_string),
+                );
+            }
+        }
+    }
+
+    reprs
+}
+
+/// Parse an item's `#[stable]`/`#[unstable]` annotation
 ///
-/// 1. **resolved_path**: Extract last segment of path + format generic args
-/// 2. **primitive**: Use primitive type name directly
-/// 3. **generic**: Use generic parameter name
-/// 4. **tuple**: Format as parenthesized comma-separated list
-/// 5. **Unknown**: Return None for unhandled structures
+/// # JSON Structure Examples
 ///
-/// # Arguments
+/// Current formats print the compiler's parsed form of the attribute:
+/// ```json
+/// { "other": "#[attr = Stability {stability: Stability {level: Stable {since: Version(RustcVersion { major: 1, minor: 0, patch: 0 })},\nfeature: \"rust1\"}}]" }
+/// { "other": "#[attr = Stability {stability: Stability {level: Unstable {reason: None,\nissue: 123}, feature: \"foo\"}}]" }
+/// ```
 ///
-/// * `type_value` - JSON value containing the type definition
+/// Older formats keep the attribute's source text:
+/// ```json
+/// "#[stable(feature = \"rust1\", since = \"1.0.0\")]"
+/// "#[unstable(feature = \"foo\", issue = \"123\")]"
+/// ```
 ///
 /// # Returns
 ///
-/// * `Some(String)` - Successfully extracted type name
-/// * `None` - Unable to extract type (unhandled JSON structure)
-fn extract_type_name_from_json(type_value: &Value) -> Option<String> {
-    // Handle primitive types directly
-    if let Some(primitive) = type_value.get("primitive").and_then(|p| p.as_str()) {
-        return Some(primitive.to_string());
+/// `None` if the item has no stability annotation. `since` is `None` when it
+/// is not a concrete version (e.g., `CURRENT_RUSTC_VERSION` placeholders).
+fn parse_stability(attrs: &[Lenient<rustdoc_json::Attribute>]) -> Option<Stability> {
+    for attr in attrs.iter().filter_map(Lenient::get) {
+        let text = match attr {
+            rustdoc_json::Attribute::Structured(rustdoc_json::StructuredAttribute::Other(text))
+            | rustdoc_json::Attribute::Legacy(text) => text,
+            _ => continue,
+        };
+
+        if let Some(parsed) = text.strip_prefix("#[attr = Stability ") {
+            let level = if parsed.contains("level: Stable") {
+                StabilityLevel::Stable
+            } else {
+                StabilityLevel::Unstable
+            };
+            let since = match level {
+                StabilityLevel::Stable => parse_rustc_version(parsed),
+                StabilityLevel::Unstable => None,
+            };
+            return Some(Stability { level, since });
+        }
+
+        if text.starts_with("#[stable(") {
+            let since = text
+                .split_once("since = \"")
+                .and_then(|(_, rest)| rest.split_once('"'))
+                .map(|(version, _)| version.to_string())
+                .filter(|version| version.chars().all(|c| c.is_ascii_digit() || c == '.'));
+            return Some(Stability {
+                level: StabilityLevel::Stable,
+                since,
+            });
+        }
+
+        if text.starts_with("#[unstable(") {
+            return Some(Stability {
+                level: StabilityLevel::Unstable,
+                since: None,
+            });
+        }
     }
 
-    // Handle resolved_path types (like Vec<T>, RawVec<T, A>, etc.)
-    if let Some(resolved_path) = type_value
-        .get("resolved_path")
-        .and_then(|rp| rp.as_object())
-    {
-        let path = resolved_path
-            .get("path")
-            .and_then(|p| p.as_str())
-            .unwrap_or("UnknownPath");
-
-        // Clean up the path - remove "crate::" prefix and convert to std:: if appropriate
-        let clean_path = if path.starts_with(CRATE_PREFIX) {
-            let without_crate = &path[CRATE_PREFIX.len()..];
-            // Convert common crate paths to std equivalents
-            match without_crate {
-                "vec::Vec" => "Vec",
-                "string::String" => "String",
-                "collections::hash_map::HashMap" => "HashMap",
-                "collections::hash_set::HashSet" => "HashSet",
-                _ => without_crate,
+    None
+}
+
+/// Convert an item's rustdoc deprecation object into a `Deprecation`
+///
+/// # JSON Structure Example
+///
+/// ```json
+/// { "since": "1.5.0", "note": "use `A` instead" }
+/// ```
+///
+/// Both keys are `null` for a bare `#[deprecated]`.
+///
+/// # Returns
+///
+/// `None` if the item is not deprecated (`"deprecation": null`)
+fn parse_deprecation(deprecation: Option<&rustdoc_json::Deprecation>) -> Option<Deprecation> {
+    let deprecation = deprecation?;
+    Some(Deprecation {
+        since: deprecation.since.clone(),
+        note: deprecation.note.clone(),
+    })
+}
+
+/// Extract the version from a printed `RustcVersion { major: 1, minor: 0, patch: 0 }`
+///
+/// # Returns
+///
+/// The dotted version (e.g., "1.0.0"), or `None` if no version is present
+fn parse_rustc_version(text: &str) -> Option<String> {
+    let (_, rest) = text.split_once("RustcVersion {")?;
+    let (fields, _) = rest.split_once('}')?;
+
+    let parts = fields
+        .split(',')
+        .map(|field| field.split_once(':').map(|(_, value)| value.trim()))
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(parts.join("."))
+}
+
+/// Render the `#[cfg(...)]` predicate gating an item
+///
+/// # JSON Structure Examples
+///
+/// Current formats print the compiler's parsed form of the attribute, with a
+/// source span after every predicate:
+/// ```json
+/// { "other": "#[attr = CfgTrace([NameValue { name: \"unix\", value: None, span: src/lib.rs:1:7: 1:11 (#0) }])]" }
+/// { "other": "#[attr = CfgTrace([Not(NameValue { name: \"feature\", value: Some(\"small\"), span: ... }, ...)])]" }
+/// ```
+///
+/// Older formats keep the attribute's source text:
+/// ```json
+/// "#[cfg(unix)]"
+/// ```
+///
+/// # Returns
+///
+/// The predicate in source syntax (e.g., `not(feature = "small")`), or `None`
+/// if the item is not cfg-gated. Several cfg attributes are combined with
+/// `all(...)`. Predicates in a shape Quarry does not recognize are skipped.
+fn parse_cfg(attrs: &[Lenient<rustdoc_json::Attribute>]) -> Option<String> {
+    let mut predicates = Vec::new();
+
+    for attr in attrs.iter().filter_map(Lenient::get) {
+        let text = match attr {
+            rustdoc_json::Attribute::Structured(rustdoc_json::StructuredAttribute::Other(text))
+            | rustdoc_json::Attribute::Legacy(text) => text,
+            _ => continue,
+        };
+
+        if let Some(trace) = text
+            .strip_prefix("#[attr = CfgTrace([")
+            .and_then(|rest| rest.strip_suffix("])]"))
+        {
+            let mut rest = trace;
+            while let Some(predicate) = parse_cfg_trace_predicate(&mut rest) {
+                predicates.push(predicate);
+                rest = rest.trim_start_matches(", ");
             }
+        } else if let Some(predicate) = text
+            .strip_prefix("#[cfg(")
+            .and_then(|rest| rest.strip_suffix(")]"))
+        {
+            predicates.push(predicate.trim().to_string());
+        }
+    }
+
+    match predicates.len() {
+        0 => None,
+        1 => predicates.pop(),
+        _ => Some(format!("all({})", predicates.join(", "))),
+    }
+}
+
+/// Parse one predicate from the front of a printed `CfgTrace` list
+///
+/// Recognizes `NameValue { .. }`, `Any([..], span)`, `All([..], span)`,
+/// `Not(.., span)`, `True(span)` and `False(span)`, and advances `input`
+/// past the predicate.
+///
+/// # Returns
+///
+/// The predicate in source syntax, or `None` if `input` does not start with
+/// a recognized predicate
+fn parse_cfg_trace_predicate(input: &mut &str) -> Option<String> {
+    if let Some(rest) = input.strip_prefix("NameValue { name: \"") {
+        let (name, rest) = rest.split_once('"')?;
+        let rest = rest.strip_prefix(", value: ")?;
+        let (predicate, rest) = if let Some(rest) = rest.strip_prefix("None") {
+            (name.to_string(), rest)
         } else {
-            path
+            let rest = rest.strip_prefix("Some(\"")?;
+            let (value, rest) = rest.split_once("\")")?;
+            (format!("{} = \"{}\"", name, value), rest)
         };
+        // Skip the span up to the closing brace
+        let (_, rest) = rest.split_once('}')?;
+        *input = rest;
+        return Some(predicate);
+    }
 
-        // Handle generic arguments
-        if let Some(args) = resolved_path.get("args") {
-            if let Some(angle_bracketed) = args.get("angle_bracketed").and_then(|ab| ab.as_object())
-            {
-                if let Some(args_array) = angle_bracketed.get("args").and_then(|a| a.as_array()) {
-                    let type_args: Vec<String> = args_array
-                        .iter()
-                        .filter_map(|arg| {
-                            if let Some(type_obj) = arg.get("type") {
-                                extract_type_name_from_json(type_obj)
-                            } else {
-                                None
-                            }
-                        })
-                        .collect();
-
-                    if !type_args.is_empty() {
-                        return Some(format!("{}<{}>", clean_path, type_args.join(", ")));
-                    }
-                }
+    for (prefix, combinator) in [("Any([", "any"), ("All([", "all")] {
+        if let Some(rest) = input.strip_prefix(prefix) {
+            let mut rest = rest;
+            let mut predicates = Vec::new();
+            while !rest.starts_with(']') {
+                predicates.push(parse_cfg_trace_predicate(&mut rest)?);
+                rest = rest.trim_start_matches(", ");
             }
+            *input = skip_cfg_trace_span(&rest[1..])?;
+            return Some(format!("{}({})", combinator, predicates.join(", ")));
         }
+    }
 
-        return Some(clean_path.to_string());
+    if let Some(rest) = input.strip_prefix("Not(") {
+        let mut rest = rest;
+        let predicate = parse_cfg_trace_predicate(&mut rest)?;
+        *input = skip_cfg_trace_span(rest)?;
+        return Some(format!("not({})", predicate));
     }
 
-    // Handle generic types
-    if let Some(generic) = type_value.get("generic").and_then(|g| g.as_str()) {
-        return Some(generic.to_string());
+    for (prefix, literal) in [("True(", "true"), ("False(", "false")] {
+        if let Some(rest) = input.strip_prefix(prefix) {
+            *input = skip_cfg_trace_span(rest)?;
+            return Some(literal.to_string());
+        }
     }
 
-    // No matching type pattern found
     None
 }
 
-/// Get struct information for a standard library type
+/// Skip a trailing `, span)` (or bare `span)`) and return what follows it
 ///
-/// This function retrieves detailed information about a Rust standard library struct,
-/// including its fields and their types. It supports both exact module paths and 
-/// common std:: aliases.
+/// Spans end in a parenthesized syntax context such as `(#0)`, so nesting is
+/// tracked to find the parenthesis that closes the enclosing predicate.
+fn skip_cfg_trace_span(input: &str) -> Option<&str> {
+    let mut depth = 0usize;
+    for (i, c) in input.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 0 => return Some(&input[i + 1..]),
+            ')' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Check whether an item's attributes include `#[non_exhaustive]`
 ///
-/// # Alias Support
+/// # JSON Structure Examples
 ///
-/// The function automatically resolves common std:: aliases to their actual definitions:
-/// - `std::string::String` → `alloc::string::String`  
-/// - `std::vec::Vec` → `alloc::vec::Vec`
-/// - `std::boxed::Box` → `alloc::boxed::Box`
-/// - And other common std:: re-exports
+/// ```json
+/// "non_exhaustive"
+/// "#[non_exhaustive]"
+/// ```
+///
+/// The first form is used by current formats, the second by older ones.
+fn is_non_exhaustive(attrs: &[Lenient<rustdoc_json::Attribute>]) -> bool {
+    attrs
+        .iter()
+        .filter_map(Lenient::get)
+        .any(|attr| match attr {
+            rustdoc_json::Attribute::Structured(
+                rustdoc_json::StructuredAttribute::NonExhaustive,
+            ) => true,
+            rustdoc_json::Attribute::Legacy(text) => text == "#[non_exhaustive]",
+            _ => false,
+        })
+}
+
+/// Collect the names of the traits a struct implements through `#[derive(...)]`
+///
+/// rustdoc expands derives before documenting, so the `#[derive]` attribute
+/// itself never reaches the struct's `attrs`. Instead each generated impl is
+/// listed in the struct's `impls` and carries the `automatically_derived`
+/// attribute (`"#[automatically_derived]"` in older formats).
+///
+/// # JSON Structure Example
+///
+/// ```json
+/// {
+///   "attrs": ["automatically_derived"],
+///   "inner": {
+///     "impl": {
+///       "trait": { "path": "Clone", "id": 7, "args": null },
+///       "for": { "resolved_path": { "path": "Plain", "id": 0, "args": null } },
+///       ...
+///     }
+///   }
+/// }
+/// ```
+///
+/// # Returns
+///
+/// The trait names in impl order (e.g., `["Debug", "Clone"]`), without the
+/// compiler-internal `StructuralPartialEq` marker that `PartialEq` derives add
+fn parse_derives(impl_ids: &[Id], krate: &Crate) -> Vec<String> {
+    let mut derives: Vec<String> = Vec::new();
+
+    for impl_id in impl_ids {
+        let Some(impl_item) = krate.index.get(impl_id) else {
+            continue;
+        };
+        let Some(trait_path) = impl_item
+            .inner
+            .impl_
+            .as_ref()
+            .and_then(|i| i.trait_.as_ref())
+        else {
+            continue;
+        };
+
+        let is_derived = impl_item
+            .attrs
+            .iter()
+            .filter_map(Lenient::get)
+            .any(|attr| match attr {
+                rustdoc_json::Attribute::Structured(
+                    rustdoc_json::StructuredAttribute::AutomaticallyDerived,
+                ) => true,
+                rustdoc_json::Attribute::Legacy(text) => text == "#[automatically_derived]",
+                _ => false,
+            });
+        if !is_derived {
+            continue;
+        }
+
+        let name = trait_path
+            .path
+            .rsplit("::")
+            .next()
+            .unwrap_or(&trait_path.path);
+        if name != "StructuralPartialEq" && !derives.iter().any(|d| d == name) {
+            derives.push(name.to_string());
+        }
+    }
+
+    derives
+}
+
+/// Collect the functions and constants defined in a struct's inherent impl blocks
+///
+/// The struct's `impls` list every impl block for it. Inherent ones have a
+/// `null` trait, and their `items` point at the functions, associated
+/// constants and associated types (which are skipped). A function whose first
+/// input is named `self` is a method; any other is an associated function.
+///
+/// # JSON Structure Example
+///
+/// ```json
+/// [
+///   {
+///     "name": "push_str",
+///     "visibility": "public",
+///     "inner": {
+///       "function": {
+///         "sig": { "inputs": [["self", { "borrowed_ref": {...} }], ["string", {...}]], ... },
+///         "header": { "is_const": false, "is_unsafe": false, "is_async": false, "abi": "Rust" },
+///         ...
+///       }
+///     }
+///   },
+///   {
+///     "name": "SECOND",
+///     "inner": {
+///       "assoc_const": {
+///         "type": { "resolved_path": { "path": "Duration", ... } },
+///         "value": "_"
+///       }
+///     }
+///   }
+/// ]
+/// ```
+///
+/// # Returns
+///
+/// The functions and the constants, each in impl block order, then
+/// declaration order within a block. A constant whose type cannot be rendered
+/// gets the type `unknown`.
+fn parse_inherent_impl_items(impl_ids: &[Id], krate: &Crate) -> (Vec<MethodInfo>, Vec<AssocConst>) {
+    let inherent_items = impl_ids
+        .iter()
+        .filter_map(|impl_id| krate.index.get(impl_id)?.inner.impl_.as_ref())
+        .filter(|impl_| impl_.trait_.is_none())
+        .flat_map(|impl_| impl_.items.iter());
+
+    let mut methods = Vec::new();
+    let mut constants = Vec::new();
+    for item_id in inherent_items {
+        let Some(item) = krate.index.get(item_id) else {
+            continue;
+        };
+        let Some(name) = &item.name else {
+            continue;
+        };
+
+        if let Some(constant) = &item.inner.assoc_const {
+            constants.push(AssocConst {
+                name: name.clone(),
+                type_name: constant
+                    .type_
+                    .get()
+                    .and_then(extract_type_name_from_json)
+                    .unwrap_or_else(|| "unknown".to_string()),
+                value: constant.value.clone(),
+            });
+            continue;
+        }
+        let Some(function) = &item.inner.function else {
+            continue;
+        };
+
+        let header = function.header.get();
+        let takes_self = function
+            .sig
+            .get()
+            .and_then(|sig| sig.inputs.first())
+            .is_some_and(|(input, _)| input == "self");
+        methods.push(MethodInfo {
+            name: name.clone(),
+            visibility: parse_visibility(&item.visibility),
+            is_const: header.is_some_and(|header| header.is_const),
+            is_unsafe: header.is_some_and(|header| header.is_unsafe),
+            is_async: header.is_some_and(|header| header.is_async),
+            is_associated_function: !takes_self,
+        });
+    }
+
+    (methods, constants)
+}
+
+/// Collect the traits a struct has an impl block for
+///
+/// Every impl block with a trait counts, including derived ones, except the
+/// auto trait impls rustdoc synthesizes (`is_synthetic`), blanket impls that
+/// cover every type (`blanket_impl`) and negative impls (`impl !Send`). Trait
+/// paths are rendered with `extract_path_name`, so `crate::` prefixes are
+/// cleaned up and generic arguments kept.
+///
+/// Only impls recorded in the JSON the struct was parsed from are seen: an
+/// impl of a std trait for an `alloc` type lives in `std.json`, whose copy of
+/// the struct the defining crate's entry replaces.
+///
+/// # JSON Structure Example
+///
+/// ```json
+/// {
+///   "impl": {
+///     "trait": { "path": "From", "id": 24, "args": { "angle_bracketed": {...} } },
+///     "for": { "resolved_path": { "path": "String", "id": 0, "args": null } },
+///     "is_synthetic": false,
+///     "blanket_impl": null,
+///     "is_negative": false,
+///     ...
+///   }
+/// }
+/// ```
+///
+/// # Returns
+///
+/// The rendered trait paths in impl order, without duplicates
+fn parse_implemented_traits(impl_ids: &[Id], krate: &Crate) -> Vec<String> {
+    let mut traits: Vec<String> = Vec::new();
+
+    for impl_id in impl_ids {
+        let Some(impl_) = krate
+            .index
+            .get(impl_id)
+            .and_then(|item| item.inner.impl_.as_ref())
+        else {
+            continue;
+        };
+        if impl_.is_synthetic || impl_.blanket_impl.is_some() || impl_.is_negative {
+            continue;
+        }
+        let Some(trait_path) = &impl_.trait_ else {
+            continue;
+        };
+
+        let name = extract_path_name(trait_path);
+        if !traits.contains(&name) {
+            traits.push(name);
+        }
+    }
+
+    traits
+}
+
+/// Parse an item's rustdoc visibility value
+///
+/// rustdoc encodes visibility either as a plain string or as an object for
+/// `pub(in path)` style restrictions.
+///
+/// # JSON Structure Examples
+///
+/// ```json
+/// "public"
+/// "crate"
+/// "default"
+/// { "restricted": { "parent": 5298, "path": "::string" } }
+/// ```
+///
+/// # Returns
+///
+/// The corresponding `Visibility`, defaulting to `Visibility::Private` for
+/// `"default"`, missing, or unrecognized values
+fn parse_visibility(value: &Lenient<rustdoc_json::RawVisibility>) -> Visibility {
+    use rustdoc_json::RawVisibility;
+
+    match value.get() {
+        Some(RawVisibility::Public) => Visibility::Public,
+        Some(RawVisibility::Crate) => Visibility::Crate,
+        Some(RawVisibility::Restricted { path }) => Visibility::Restricted(path.clone()),
+        Some(RawVisibility::Default) | None => Visibility::Private,
+    }
+}
+
+/// Whether a crate is the one defining the item at `path`
+///
+/// Defining paths are rooted at the defining crate, so a struct parsed from
+/// `std.json` under `alloc::string::String` is a re-exported copy. An unknown
+/// crate name (`""`) is treated as defining everything it documents.
+fn defines_path(crate_name: &str, path: &str) -> bool {
+    crate_name.is_empty() || path.split("::").next() == Some(crate_name)
+}
+
+/// Insert a struct with its full name as the key
+///
+/// Adds a struct to the cache using only its complete module path as the key.
+/// This enforces the requirement for users to specify exact paths when querying.
+///
+/// # Precedence
+///
+/// The same path can be parsed from several crates, for instance when `std`
+/// documents a copy of a type it re-exports from `alloc`. The entry parsed
+/// from the defining crate (see `defines_path`) always wins:
+///
+/// - A definition replaces whatever is cached under its path
+/// - A re-exported copy is only inserted if nothing is cached under its path,
+///   and is itself replaced by a later definition
+///
+/// Between two entries of the same standing, the later one wins, and crates
+/// are merged in the fixed `STDLIB_CRATES` order.
+///
+/// # Arguments
+///
+/// * `types` - The types being collected
+/// * `struct_info` - The struct information to insert
+/// * `is_definition` - Whether `struct_info` was parsed from its defining crate
+fn insert_struct_with_full_name(
+    types: &mut StdlibTypes,
+    struct_info: Arc<StructInfo>,
+    is_definition: bool,
+) -> Result<()> {
+    let name = struct_info.name.clone();
+    if is_definition {
+        types.reexported_structs.remove(&name);
+    } else if types.structs.contains_key(&name) {
+        debug!("Keeping existing entry over re-exported copy: {}", name);
+        return Ok(());
+    } else {
+        types.reexported_structs.insert(name.clone());
+    }
+
+    // Insert only with the full path - no variations
+    debug!("Inserting struct with full name: {}", name);
+    types.structs.insert(name, struct_info)?;
+    Ok(())
+}
+
+/// Extract a structured type from a rustdoc JSON type definition
+///
+/// This function converts the complex type structures in rustdoc JSON into a
+/// [`Type`] tree. It handles resolved paths, primitives, generic types, and tuples;
+/// the tree's `Display` output is the readable type name stored in `type_name`.
+///
+/// # JSON Type Examples
+///
+/// ## Resolved Path Type (e.g., Vec<u8>)
+/// ```json
+/// {
+///   "resolved_path": {
+///     "path": "crate::vec::Vec",
+///     "id": 241,
+///     "args": {
+///       "angle_bracketed": {
+///         "args": [
+///           {
+///             "type": {
+///               "primitive": "u8"
+///             }
+///           }
+///         ]
+///       }
+///     }
+///   }
+/// }
+/// ```
+/// Extracted as: "Vec<u8>"
+///
+/// ## Primitive Type (e.g., usize)
+/// ```json
+/// {
+///   "primitive": "usize"
+/// }
+/// ```
+/// Extracted as: "usize"
+///
+/// ## Generic Type (e.g., T)
+/// ```json
+/// {
+///   "generic": "T"
+/// }
+/// ```
+/// Extracted as: "T"
+///
+/// ## Tuple Type (e.g., (i32, String))
+/// ```json
+/// {
+///   "tuple": [
+///     {"primitive": "i32"},
+///     {"resolved_path": {"path": "std::string::String"}}
+///   ]
+/// }
+/// ```
+/// Extracted as: "(i32, String)"
+///
+/// ## Reference Type (e.g., &'a mut T)
+/// ```json
+/// {
+///   "borrowed_ref": {
+///     "lifetime": "'a",
+///     "is_mutable": true,
+///     "type": { "generic": "T" }
+///   }
+/// }
+/// ```
+/// Extracted as: "&'a mut T"
+///
+/// ## Raw Pointer Type (e.g., *const u8)
+/// ```json
+/// {
+///   "raw_pointer": {
+///     "is_mutable": false,
+///     "type": { "primitive": "u8" }
+///   }
+/// }
+/// ```
+/// Extracted as: "*const u8"
+///
+/// ## Slice and Array Types (e.g., [u8] and [u8; 16])
+/// ```json
+/// { "slice": { "primitive": "u8" } }
+/// { "array": { "type": { "primitive": "u8" }, "len": "16" } }
+/// ```
+/// Extracted as: "[u8]" and "[u8; 16]"
+///
+/// ## Trait Object Type (e.g., dyn Error + Send)
+/// ```json
+/// {
+///   "dyn_trait": {
+///     "traits": [
+///       { "trait": { "path": "Error", "id": 12, "args": null }, "generic_params": [] },
+///       { "trait": { "path": "Send", "id": 13, "args": null }, "generic_params": [] }
+///     ],
+///     "lifetime": null
+///   }
+/// }
+/// ```
+/// Extracted as: "dyn Error + Send"
+///
+/// ## Qualified Path Type (e.g., <T as IntoIterator>::Item)
+/// ```json
+/// {
+///   "qualified_path": {
+///     "name": "Item",
+///     "args": null,
+///     "self_type": { "generic": "T" },
+///     "trait": { "path": "IntoIterator", "id": 53, "args": null }
+///   }
+/// }
+/// ```
+/// Extracted as: "<T as IntoIterator>::Item"
+///
+/// # Type Extraction Rules
+///
+/// 1. **resolved_path**: `Type::Path` with the cleaned path + generic args
+/// 2. **primitive**: `Type::Primitive` with the primitive type name
+/// 3. **generic**: `Type::Generic` with the generic parameter name
+/// 4. **tuple**: `Type::Tuple` with each element extracted recursively
+/// 5. **borrowed_ref**: `Type::Reference`, rendered as `&'a T` or `&mut T`
+/// 6. **raw_pointer**: `Type::RawPointer`, rendered as `*const T` or `*mut T`
+/// 7. **slice**: `Type::Slice`, rendered as `[T]`
+/// 8. **array**: `Type::Array`, rendered as `[T; N]` with the length expression
+/// 9. **dyn_trait**: `Type::DynTrait`, rendered as `dyn Trait + Send + 'a`
+/// 10. **impl_trait**: `Type::ImplTrait`, rendered as `impl Trait + 'a`
+/// 11. **qualified_path**: `Type::QualifiedPath`, rendered as `<SelfType as Trait>::Name`
+///     (or `SelfType::Name` for inherent associated types)
+/// 12. **Unknown**: Return None for unhandled structures
+///
+/// # Arguments
+///
+/// * `type_value` - The parsed type definition
+///
+/// # Returns
+///
+/// * `Some(Type)` - Successfully extracted type
+/// * `None` - Unable to extract type (unhandled JSON structure)
+fn extract_type_from_json(type_value: &rustdoc_json::Type) -> Option<Type> {
+    match type_value {
+        // Handle primitive types directly
+        rustdoc_json::Type::Primitive(primitive) => Some(Type::Primitive(primitive.clone())),
+        // Handle resolved_path types (like Vec<T>, RawVec<T, A>, etc.)
+        rustdoc_json::Type::ResolvedPath(resolved_path) => Some(extract_path_type(resolved_path)),
+        // Handle generic types
+        rustdoc_json::Type::Generic(generic) => Some(Type::Generic(generic.clone())),
+        // Handle tuples; every element must be understood to render the tuple
+        rustdoc_json::Type::Tuple(elements) => elements
+            .iter()
+            .map(|element| extract_type_from_json(element.get()?))
+            .collect::<Option<Vec<_>>>()
+            .map(Type::Tuple),
+        // Handle references, keeping the lifetime when rustdoc provides one
+        rustdoc_json::Type::BorrowedRef {
+            lifetime,
+            is_mutable,
+            type_,
+        } => Some(Type::Reference {
+            lifetime: lifetime.clone(),
+            mutable: *is_mutable,
+            inner: Box::new(extract_type_from_json(type_.get()?)?),
+        }),
+        // Handle raw pointers
+        rustdoc_json::Type::RawPointer { is_mutable, type_ } => Some(Type::RawPointer {
+            mutable: *is_mutable,
+            inner: Box::new(extract_type_from_json(type_.get()?)?),
+        }),
+        // Handle slices and fixed-size arrays
+        rustdoc_json::Type::Slice(element) => Some(Type::Slice(Box::new(extract_type_from_json(
+            element.get()?,
+        )?))),
+        rustdoc_json::Type::Array { type_, len } => Some(Type::Array {
+            inner: Box::new(extract_type_from_json(type_.get()?)?),
+            len: len.clone(),
+        }),
+        // Handle trait objects and impl-trait positions
+        rustdoc_json::Type::DynTrait(dyn_trait) => Some(Type::DynTrait {
+            traits: dyn_trait
+                .traits
+                .iter()
+                .map(|poly_trait| extract_path_type(&poly_trait.trait_))
+                .collect(),
+            lifetime: dyn_trait.lifetime.clone(),
+        }),
+        rustdoc_json::Type::ImplTrait(bounds) => {
+            let mut traits = Vec::new();
+            let mut lifetime = None;
+            for bound in bounds.iter().filter_map(Lenient::get) {
+                match bound {
+                    GenericBound::TraitBound { trait_, .. } => {
+                        traits.push(extract_path_type(trait_))
+                    }
+                    GenericBound::Outlives(outlives) => lifetime = Some(outlives.clone()),
+                }
+            }
+            Some(Type::ImplTrait { traits, lifetime })
+        }
+        // Handle associated type projections
+        rustdoc_json::Type::QualifiedPath {
+            name,
+            self_type,
+            trait_,
+        } => Some(Type::QualifiedPath {
+            self_type: Box::new(extract_type_from_json(self_type.get()?)?),
+            trait_: trait_
+                .as_ref()
+                .map(|path| Box::new(extract_path_type(path))),
+            name: name.clone(),
+        }),
+    }
+}
+
+/// Extract the readable type name from a rustdoc JSON type definition
+///
+/// Shorthand for rendering the result of `extract_type_from_json`.
+fn extract_type_name_from_json(type_value: &rustdoc_json::Type) -> Option<String> {
+    extract_type_from_json(type_value).map(|ty| ty.to_string())
+}
+
+/// Render a rustdoc path object (as found in a trait bound)
+///
+/// Shorthand for rendering the result of `extract_path_type`, e.g. `"BuildHasher"`.
+fn extract_path_name(path_obj: &rustdoc_json::Path) -> String {
+    extract_path_type(path_obj).to_string()
+}
+
+/// Convert a rustdoc path object (as found in `resolved_path` or a trait bound)
+///
+/// The path is cleaned up by removing the `crate::` prefix and mapping common
+/// crate-internal paths to their familiar names, then any angle-bracketed
+/// generic arguments are extracted: types through `extract_type_from_json`,
+/// const arguments through `const_arg_name`. Lifetime arguments are dropped.
+///
+/// # Arguments
+///
+/// * `path_obj` - Parsed path with a `path` string and optional `args`
+///
+/// # Returns
+///
+/// A `Type::Path`, rendering e.g. as `"Vec<u8>"` or `"BuildHasher"`
+fn extract_path_type(path_obj: &rustdoc_json::Path) -> Type {
+    let path = path_obj.path.as_str();
+
+    // Clean up the path - remove "crate::" prefix and convert to std:: if appropriate
+    let clean_path = if let Some(without_crate) = path.strip_prefix(CRATE_PREFIX) {
+        // Convert common crate paths to std equivalents
+        match without_crate {
+            "vec::Vec" => "Vec",
+            "string::String" => "String",
+            "collections::hash_map::HashMap" => "HashMap",
+            "collections::hash_set::HashSet" => "HashSet",
+            _ => without_crate,
+        }
+    } else {
+        path
+    };
+
+    // Handle generic arguments
+    let mut type_args = Vec::new();
+    if let Some(args) = &path_obj.args
+        && let Some(rustdoc_json::GenericArgs::AngleBracketed { args: args_array }) = args.get()
+    {
+        type_args = args_array
+            .iter()
+            .filter_map(|arg| match arg.get()? {
+                rustdoc_json::GenericArg::Type(type_obj) => extract_type_from_json(type_obj),
+                rustdoc_json::GenericArg::Const(constant) => {
+                    Some(Type::Const(const_arg_name(constant)))
+                }
+            })
+            .collect();
+    }
+
+    Type::Path {
+        name: clean_path.to_string(),
+        args: type_args,
+    }
+}
+
+/// Render a const generic argument
+///
+/// Uses the expression as written, such as a literal (`3`) or a const parameter
+/// (`N`). When rustdoc left the expression opaque (`_`), the evaluated value is
+/// used instead if there is one.
+fn const_arg_name(constant: &rustdoc_json::Constant) -> String {
+    match (constant.expr.as_str(), &constant.value) {
+        ("_" | "", Some(value)) => value.clone(),
+        ("", None) => "_".to_string(),
+        (expr, _) => expr.to_string(),
+    }
+}
+
+/// Convert the declared parameters of a generics object
+///
+/// # JSON Structure Example
+///
+/// ```json
+/// {
+///   "params": [
+///     { "name": "'a", "kind": { "lifetime": { "outlives": [] } } },
+///     { "name": "T", "kind": { "type": { "bounds": [], "default": null, "is_synthetic": false } } },
+///     { "name": "N", "kind": { "const": { "type": { "primitive": "usize" }, "default": "3" } } },
+///     {
+///       "name": "A",
+///       "kind": {
+///         "type": {
+///           "bounds": [],
+///           "default": { "resolved_path": { "path": "Global", "args": null } },
+///           "is_synthetic": false
+///         }
+///       }
+///     }
+///   ]
+/// }
+/// ```
+///
+/// Parsed as `'a`, `T`, `const N: usize = 3` and `A = Global`. Type defaults
+/// are rendered with `extract_type_name_from_json`.
+///
+/// # Arguments
+///
+/// * `generics` - The `generics` object of an item
+///
+/// # Returns
+///
+/// The parameters in declaration order. Synthetic parameters standing for
+/// `impl Trait` arguments and parameters of an unrecognized kind are skipped;
+/// a const parameter whose type cannot be rendered gets the type `unknown`,
+/// and a type default that cannot be rendered is dropped.
+fn parse_generic_params(generics: &rustdoc_json::Generics) -> Vec<GenericParam> {
+    use rustdoc_json::GenericParamDefKind;
+
+    generics
+        .params
+        .iter()
+        .filter_map(|param| {
+            let (kind, default) = match param.kind.get()? {
+                GenericParamDefKind::Lifetime {} => (GenericParamKind::Lifetime, None),
+                GenericParamDefKind::Type {
+                    is_synthetic: true, ..
+                } => return None,
+                GenericParamDefKind::Type { default, .. } => (
+                    GenericParamKind::Type,
+                    default
+                        .as_ref()
+                        .and_then(Lenient::get)
+                        .and_then(extract_type_name_from_json),
+                ),
+                GenericParamDefKind::Const { type_, default } => {
+                    let ty = type_
+                        .get()
+                        .and_then(extract_type_name_from_json)
+                        .unwrap_or_else(|| "unknown".to_string());
+                    (GenericParamKind::Const { ty }, default.clone())
+                }
+            };
+            Some(GenericParam {
+                name: param.name.clone(),
+                kind,
+                default,
+            })
+        })
+        .collect()
+}
+
+/// Render the where-clause predicates of a generics object as readable strings
+///
+/// rustdoc records bounds under `generics.where_predicates`, with inline bounds
+/// such as `T: Clone` normalized into the same list.
+///
+/// # JSON Structure Example
+///
+/// ```json
+/// {
+///   "where_predicates": [
+///     {
+///       "bound_predicate": {
+///         "type": { "generic": "S" },
+///         "bounds": [
+///           { "trait_bound": { "trait": { "path": "BuildHasher", "args": null }, "modifier": "none" } },
+///           { "outlives": "'a" }
+///         ],
+///         "generic_params": []
+///       }
+///     },
+///     { "lifetime_predicate": { "lifetime": "'a", "outlives": ["'b"] } }
+///   ]
+/// }
+/// ```
+///
+/// Rendered as: `["S: BuildHasher + 'a", "'a: 'b"]`
+///
+/// # Arguments
+///
+/// * `generics` - The `generics` object of an item
+///
+/// # Returns
+///
+/// One string per predicate; predicates that cannot be rendered are skipped
+fn parse_where_predicates(generics: &rustdoc_json::Generics) -> Vec<String> {
+    use rustdoc_json::{Term, WherePredicate};
+
+    generics
+        .where_predicates
+        .iter()
+        .filter_map(|predicate| match predicate.get() {
+            Some(WherePredicate::Bound {
+                type_,
+                bounds,
+                generic_params,
+            }) => {
+                let ty = extract_type_name_from_json(type_.get()?)?;
+                let bounds = format_generic_bounds(bounds);
+                let binder = format_higher_ranked_params(generic_params);
+                Some(format!("{}{}: {}", binder, ty, bounds))
+            }
+            Some(WherePredicate::Lifetime { lifetime, outlives }) => {
+                Some(format!("{}: {}", lifetime, outlives.join(" + ")))
+            }
+            Some(WherePredicate::Eq { lhs, rhs }) => {
+                let lhs = extract_type_name_from_json(lhs.get()?)?;
+                let rhs = match rhs.get()? {
+                    Term::Type(ty) => extract_type_name_from_json(ty)?,
+                    Term::Constant(constant) => constant.expr.clone(),
+                };
+                Some(format!("{} == {}", lhs, rhs))
+            }
+            None => {
+                debug!("Unrecognized where predicate");
+                None
+            }
+        })
+        .collect()
+}
+
+/// Render a list of generic bounds joined with `+`
+///
+/// Handles trait bounds (including `?Sized` and `~const` modifiers) and
+/// lifetime bounds such as `'a`.
+fn format_generic_bounds(bounds: &[Lenient<GenericBound>]) -> String {
+    let rendered: Vec<String> = bounds
+        .iter()
+        .filter_map(|bound| match bound.get()? {
+            GenericBound::TraitBound {
+                trait_,
+                generic_params,
+                modifier,
+            } => {
+                let trait_path = extract_path_name(trait_);
+                let modifier = match modifier.as_str() {
+                    "maybe" => "?",
+                    "maybe_const" => "~const ",
+                    _ => "",
+                };
+                let binder = format_higher_ranked_params(generic_params);
+                Some(format!("{}{}{}", binder, modifier, trait_path))
+            }
+            GenericBound::Outlives(lifetime) => Some(lifetime.clone()),
+        })
+        .collect();
+
+    rendered.join(" + ")
+}
+
+/// Render a higher-ranked binder such as `for<'a> ` from a `generic_params` array
+///
+/// Returns an empty string when there are no higher-ranked parameters.
+fn format_higher_ranked_params(params: &[rustdoc_json::GenericParamDef]) -> String {
+    let names: Vec<&str> = params.iter().map(|p| p.name.as_str()).collect();
+
+    if names.is_empty() {
+        String::new()
+    } else {
+        format!("for<{}> ", names.join(", "))
+    }
+}
+
+/// Get struct information for a standard library type
+///
+/// This function retrieves detailed information about a Rust standard library struct,
+/// including its fields and their types. It supports both exact module paths and
+/// common std:: aliases.
+///
+/// # Alias Support
+///
+/// The function automatically resolves common std:: aliases to their actual definitions:
+/// - `std::string::String` → `alloc::string::String`  
+/// - `std::vec::Vec` → `alloc::vec::Vec`
+/// - `std::boxed::Box` → `alloc::boxed::Box`
+/// - And other common std:: re-exports
+///
+/// # Examples
+///
+/// ```ignore
+/// use quarry::mine_stdlib_struct_info;
+///
+/// // ✅ Both of these work - std:: alias and exact path
+/// let string_info1 = mine_stdlib_struct_info("std::string::String")?;
+/// let string_info2 = mine_stdlib_struct_info("alloc::string::String")?;
+/// // Both return the same information
+///
+/// let vec_info = mine_stdlib_struct_info("std::vec::Vec")?;
+/// let hashmap_info = mine_stdlib_struct_info("std::collections::HashMap")?;
+/// ```
+///
+/// # Arguments
+///
+/// * `name` - The full module path or std:: alias (e.g., "std::string::String")
+/// * `config` - The configuration used to build the cache if it isn't ready yet
+///
+/// # Returns
+///
+/// * `Ok(StructInfo)` - Detailed information about the struct including fields
+/// * `Err(QuarryError::TypeNotFound)` - If the type name is not found
+///
+/// # Cache Behavior
+///
+/// The function uses a global cache that is initialized on first use. The cache
+/// contains structs from the std, alloc, and core crates with their exact paths
+/// as keys. An `alloc::` or `core::` path only requires that crate's JSON to be
+/// parsed; the other crates are parsed when a later lookup needs them.
+pub(crate) fn mine_stdlib_struct_info(name: &str, config: &QuarryConfig) -> Result<StructInfo> {
+    debug!("Mining stdlib struct info for: '{}'", name);
+
+    with_stdlib_types_for_path(config, name, |stdlib_types| {
+        find_struct_info(stdlib_types, name)
+    })?
+}
+
+/// Get a shared handle to the cached struct information for a type
+///
+/// Exact matches hand out a clone of the cached `Arc`. Alias matches and
+/// errors go through `find_struct_info`, so they behave exactly like
+/// `mine_stdlib_struct_info`.
+pub(crate) fn mine_stdlib_struct_info_arc(
+    name: &str,
+    config: &QuarryConfig,
+) -> Result<Arc<StructInfo>> {
+    debug!("Mining shared stdlib struct info for: '{}'", name);
+
+    with_stdlib_types_for_path(config, name, |stdlib_types| {
+        match stdlib_types.structs.get(name)? {
+            Some(info) => Ok(info),
+            None => find_struct_info(stdlib_types, name).map(Arc::new),
+        }
+    })?
+}
+
+/// Get struct information for several standard library types at once
+///
+/// The cache lock is taken once for the whole batch. Each name is resolved
+/// independently (including std:: aliases), so a missing type only affects its
+/// own entry. The output order matches `names`.
+///
+/// If the cache cannot be initialized, every entry carries that error.
+pub(crate) fn mine_stdlib_struct_infos(
+    names: &[&str],
+    config: &QuarryConfig,
+) -> Vec<(String, Result<StructInfo>)> {
+    debug!("Mining stdlib struct info for {} names", names.len());
+
+    let results = with_stdlib_types(config, |stdlib_types| {
+        names
+            .iter()
+            .map(|name| (name.to_string(), find_struct_info(stdlib_types, name)))
+            .collect()
+    });
+
+    results.unwrap_or_else(|e| {
+        debug!("Failed to initialize cache for batch lookup: {:?}", e);
+        names
+            .iter()
+            .map(|name| (name.to_string(), Err(duplicate_error(&e))))
+            .collect()
+    })
+}
+
+/// Look up a struct by exact path or std:: alias and return an owned copy
+///
+/// When found through an alias, the copy reports the requested alias name
+/// instead of the internal path name.
+fn find_struct_info(stdlib_types: &StdlibTypes, name: &str) -> Result<StructInfo> {
+    match find_type_info(stdlib_types, name)? {
+        TypeInfo::Struct(info) => Ok(info),
+        other => Err(not_a_struct(name, other.kind())),
+    }
+}
+
+/// Get information about a standard library type of any kind
+///
+/// Supports the same exact-path and std:: alias lookups as
+/// `mine_stdlib_struct_info`.
+///
+/// # Returns
+///
+/// * `Ok(TypeInfo)` - The struct, enum, union or type alias the path names
+/// * `Err(QuarryError::TypeNotFound)` - If no type with that name exists
+pub(crate) fn mine_stdlib_type_info(name: &str, config: &QuarryConfig) -> Result<TypeInfo> {
+    debug!("Mining stdlib type info for: '{}'", name);
+
+    with_stdlib_types_for_path(config, name, |stdlib_types| {
+        find_type_info(stdlib_types, name)
+    })?
+}
+
+/// Look up a type of any kind by exact path or std:: alias and return an owned copy
+///
+/// Type names share a single namespace, so at most one kind can match. When
+/// found through an alias, the copy reports the requested alias name instead of
+/// the internal path name; a struct keeps the latter as its `canonical_name`.
+fn find_type_info(stdlib_types: &StdlibTypes, name: &str) -> Result<TypeInfo> {
+    // A spilled struct that cannot be read back is an error, not a miss
+    let found = if let Some((info, alias)) = lookup_with_alias(
+        |path| stdlib_types.structs.get(path).transpose(),
+        stdlib_types,
+        name,
+    ) {
+        Some((TypeInfo::Struct(Arc::unwrap_or_clone(info?)), alias))
+    } else if let Some((info, alias)) =
+        lookup_with_alias(|path| stdlib_types.enums.get(path), stdlib_types, name)
+    {
+        Some((TypeInfo::Enum(info.clone()), alias))
+    } else if let Some((info, alias)) =
+        lookup_with_alias(|path| stdlib_types.unions.get(path), stdlib_types, name)
+    {
+        Some((TypeInfo::Union(info.clone()), alias))
+    } else {
+        lookup_with_alias(|path| stdlib_types.aliases.get(path), stdlib_types, name)
+            .map(|(info, alias)| (TypeInfo::TypeAlias(info.clone()), alias))
+    };
+
+    let (mut info, alias) = found.ok_or_else(|| struct_not_found(name, stdlib_types))?;
+    if alias {
+        // Report the alias name (what the user requested) instead of the internal path name
+        let kind = info.kind();
+        if let TypeInfo::Struct(i) = &mut info {
+            i.canonical_name = i.name.clone();
+        }
+        let (full_name, module_path, simple_name) = match &mut info {
+            TypeInfo::Struct(i) => (&mut i.name, &mut i.module_path, &mut i.simple_name),
+            TypeInfo::Enum(i) => (&mut i.name, &mut i.module_path, &mut i.simple_name),
+            TypeInfo::Union(i) => (&mut i.name, &mut i.module_path, &mut i.simple_name),
+            TypeInfo::TypeAlias(i) => (&mut i.name, &mut i.module_path, &mut i.simple_name),
+        };
+        *full_name = name.to_string();
+        (*module_path, *simple_name) = crate::split_path(name);
+        debug!(
+            "Created aliased {}: '{}' -> module: '{}', simple: '{}'",
+            kind, name, module_path, simple_name
+        );
+    }
+
+    Ok(info)
+}
+
+/// Run `f` on the cached struct information for a type, without cloning it
+///
+/// Supports the same exact-path and std:: alias lookups as
+/// `mine_stdlib_struct_info`. For aliases, `f` sees the cached entry itself, so
+/// its `name` is the defining path rather than the alias. The cache lock is held
+/// while `f` runs.
+pub(crate) fn with_stdlib_struct_info<R>(
+    name: &str,
+    config: &QuarryConfig,
+    f: impl FnOnce(&StructInfo) -> R,
+) -> Result<R> {
+    debug!("Borrowing stdlib struct info for: '{}'", name);
+
+    with_stdlib_types(config, |stdlib_types| {
+        let structs = &stdlib_types.structs;
+        match lookup_with_alias(|path| structs.get(path).transpose(), stdlib_types, name) {
+            Some((info, _)) => {
+                let info = info?;
+                Ok(f(&info))
+            }
+            None => Err(match find_type_info(stdlib_types, name) {
+                Ok(other) => not_a_struct(name, other.kind()),
+                Err(e) => e,
+            }),
+        }
+    })?
+}
+
+/// The error reported when no type matches `name`
+///
+/// A bare name matching the simple name of cached types is reported as
+/// `QuarryError::Ambiguous` with their full paths. Otherwise up to
+/// `MAX_SUGGESTIONS` cached paths within `MAX_SUGGESTION_DISTANCE` edits of
+/// `name` are appended as suggestions.
+fn struct_not_found(name: &str, stdlib_types: &StdlibTypes) -> QuarryError {
+    debug!(
+        "No match found for '{}' (tried exact match and alias resolution)",
+        name
+    );
+    if !name.contains("::") {
+        let candidates = paths_with_simple_name(name, stdlib_types);
+        if !candidates.is_empty() {
+            debug!(
+                "'{}' is a bare name matching {} types",
+                name,
+                candidates.len()
+            );
+            return QuarryError::Ambiguous {
+                simple_name: name.to_string(),
+                candidates,
+            };
+        }
+    }
+
+    let mut message = format!(
+        "Type '{}' not found. Please provide the full module path (e.g., 'std::string::String', 'alloc::string::String')",
+        name
+    );
+
+    let suggestions = suggest_similar_paths(name, stdlib_types);
+    match suggestions.as_slice() {
+        [] => {}
+        [suggestion] => message.push_str(&format!(". Did you mean '{}'?", suggestion)),
+        _ => message.push_str(&format!(
+            ". Did you mean one of: {}?",
+            suggestions
+                .iter()
+                .map(|s| format!("'{}'", s))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    }
+
+    QuarryError::TypeNotFound(message)
+}
+
+/// Find the full paths of the cached types of any kind whose simple name is `simple_name`
+///
+/// Each type is listed under its preferred std:: re-export path when it has
+/// one (e.g., `std::collections::HashMap` rather than
+/// `std::collections::hash::map::HashMap`), so every candidate can be passed
+/// back as is.
+///
+/// # Returns
+///
+/// The paths in sorted order, without duplicates
+fn paths_with_simple_name(simple_name: &str, stdlib_types: &StdlibTypes) -> Vec<String> {
+    let suffix = format!("::{}", simple_name);
+    let std_aliases = stdlib_types.std_aliases();
+    let candidates: BTreeSet<String> = stdlib_types
+        .structs
+        .keys()
+        .chain(stdlib_types.enums.keys())
+        .chain(stdlib_types.unions.keys())
+        .chain(stdlib_types.aliases.keys())
+        .filter(|path| path.ends_with(&suffix))
+        .map(|path| {
+            std_aliases
+                .get(path)
+                .and_then(|paths| paths.first())
+                .unwrap_or(path)
+                .clone()
+        })
+        .collect();
+    candidates.into_iter().collect()
+}
+
+/// Find the cached paths closest to a misspelled `name`
+///
+/// Both the defining paths and their preferred std:: re-export paths are
+/// considered, so `std::collections::Hashmap` suggests `std::collections::HashMap`.
+///
+/// # Returns
+///
+/// At most `MAX_SUGGESTIONS` paths within `MAX_SUGGESTION_DISTANCE` edits,
+/// closest first and alphabetically among equal distances
+fn suggest_similar_paths(name: &str, stdlib_types: &StdlibTypes) -> Vec<String> {
+    let candidates = stdlib_types
+        .structs
+        .keys()
+        .chain(stdlib_types.enums.keys())
+        .chain(stdlib_types.unions.keys())
+        .chain(stdlib_types.aliases.keys())
+        .chain(
+            stdlib_types
+                .std_aliases()
+                .values()
+                .filter_map(|paths| paths.first()),
+        );
+
+    let mut scored: Vec<(usize, &String)> = candidates
+        // The length difference is a lower bound on the distance
+        .filter(|candidate| candidate.len().abs_diff(name.len()) <= MAX_SUGGESTION_DISTANCE)
+        .map(|candidate| (levenshtein_distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= MAX_SUGGESTION_DISTANCE)
+        .collect();
+    scored.sort();
+    scored.dedup_by(|a, b| a.1 == b.1);
+
+    let suggestions: Vec<String> = scored
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, candidate)| candidate.clone())
+        .collect();
+    debug!("Suggestions for '{}': {:?}", name, suggestions);
+    suggestions
+}
+
+/// Compute the Levenshtein edit distance between two strings
+///
+/// Counts the single-character insertions, deletions and substitutions needed
+/// to turn `a` into `b`. Runs in O(len(a) * len(b)) time with a single row of
+/// O(len(b)) memory.
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(a_char != *b_char);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+
+    row[b.len()]
+}
+
+/// The error reported when `name` resolves to a type that is not a struct
+///
+/// A struct lookup that finds another kind is a miss like any other; the
+/// message names the kind so callers can switch to `mine_type_info`.
+fn not_a_struct(name: &str, kind: TypeKind) -> QuarryError {
+    debug!("'{}' is a {}, not a struct", name, kind);
+    QuarryError::TypeNotFound(format!(
+        "Struct '{}' not found: it is a {}. Use mine_type_info to look up types of any kind",
+        name, kind
+    ))
+}
+
+/// Produce an equivalent copy of an error for reporting it more than once
+///
+/// `QuarryError` is not `Clone` because `std::io::Error` and `serde_json::Error`
+/// aren't; I/O errors are recreated from their kind and message, JSON errors
+/// from their message (which already carries the line and column).
+fn duplicate_error(error: &QuarryError) -> QuarryError {
+    match error {
+        QuarryError::TypeNotFound(msg) => QuarryError::TypeNotFound(msg.clone()),
+        QuarryError::Io(e) => QuarryError::Io(std::io::Error::new(e.kind(), e.to_string())),
+        QuarryError::StdlibAnalysis(msg) => QuarryError::StdlibAnalysis(msg.clone()),
+        QuarryError::NightlyMissing(toolchain) => QuarryError::NightlyMissing(toolchain.clone()),
+        QuarryError::RustSrcMissing(toolchain) => QuarryError::RustSrcMissing(toolchain.clone()),
+        QuarryError::RustdocFailed { status, stderr } => QuarryError::RustdocFailed {
+            status: *status,
+            stderr: stderr.clone(),
+        },
+        QuarryError::OfflineCacheUnavailable(toolchain) => {
+            QuarryError::OfflineCacheUnavailable(toolchain.clone())
+        }
+        QuarryError::InvalidConfig(msg) => QuarryError::InvalidConfig(msg.clone()),
+        QuarryError::UnsupportedRustdocFormat { found, supported } => {
+            QuarryError::UnsupportedRustdocFormat {
+                found: *found,
+                supported: supported.clone(),
+            }
+        }
+        QuarryError::MalformedRustdocJson(e) => QuarryError::MalformedRustdocJson(
+            <serde_json::Error as serde::de::Error>::custom(e.to_string()),
+        ),
+        QuarryError::Ambiguous {
+            simple_name,
+            candidates,
+        } => QuarryError::Ambiguous {
+            simple_name: simple_name.clone(),
+            candidates: candidates.clone(),
+        },
+    }
+}
+
+/// Get enum information for a standard library type
+///
+/// This is the enum counterpart of `mine_stdlib_struct_info` and supports the
+/// same exact-path and std:: alias lookups.
+///
+/// # Returns
+///
+/// * `Ok(EnumInfo)` - Detailed information about the enum including variants
+/// * `Err(QuarryError::TypeNotFound)` - If no enum with that name exists
+pub(crate) fn mine_stdlib_enum_info(name: &str) -> Result<EnumInfo> {
+    debug!("Mining stdlib enum info for: '{}'", name);
+
+    let found = with_stdlib_types(&QuarryConfig::default(), |stdlib_types| {
+        let enums = |path: &str| stdlib_types.enums.get(path);
+        lookup_with_alias(enums, stdlib_types, name).map(|(info, alias)| {
+            let mut info = info.clone();
+            if alias {
+                info.name = name.to_string();
+                (info.module_path, info.simple_name) = crate::split_path(name);
+            }
+            info
+        })
+    })?;
+
+    found.ok_or_else(|| {
+        debug!("No enum found for '{}'", name);
+        QuarryError::TypeNotFound(format!(
+            "Enum '{}' not found. Please provide the full module path (e.g., 'core::option::Option')",
+            name
+        ))
+    })
+}
+
+/// Resolve a standard library type alias to the full path of its target
+///
+/// With `recursive`, targets that are themselves cached aliases are resolved
+/// in turn. Cycles cannot occur in valid Rust, but the chain is still cut off
+/// if a path repeats.
+///
+/// # Returns
+///
+/// * `Ok(String)` - The target's full path, or the rendered aliased type if it
+///   is not a path (e.g., a tuple)
+/// * `Err(QuarryError::TypeNotFound)` - If no type alias with that name exists
+pub(crate) fn resolve_stdlib_type_alias(name: &str, recursive: bool) -> Result<String> {
+    debug!(
+        "Resolving stdlib type alias: '{}' (recursive: {})",
+        name, recursive
+    );
+
+    let resolved = with_stdlib_types(&QuarryConfig::default(), |stdlib_types| {
+        let aliases = |path: &str| stdlib_types.aliases.get(path);
+        let (alias, _) = lookup_with_alias(aliases, stdlib_types, name)?;
+        let mut target = alias
+            .target
+            .clone()
+            .unwrap_or_else(|| alias.type_name.clone());
+        debug!("'{}' is an alias for '{}'", name, target);
+
+        let mut visited = vec![name.to_string()];
+        while recursive && !visited.contains(&target) {
+            let Some((next, _)) = lookup_with_alias(aliases, stdlib_types, &target) else {
+                break;
+            };
+            visited.push(target);
+            target = next
+                .target
+                .clone()
+                .unwrap_or_else(|| next.type_name.clone());
+            debug!("Followed alias chain to '{}'", target);
+        }
+
+        Some(target)
+    })?;
+
+    resolved.ok_or_else(|| {
+        debug!("No type alias found for '{}'", name);
+        QuarryError::TypeNotFound(format!(
+            "Type alias '{}' not found. Please provide the full module path (e.g., 'std::io::error::Result')",
+            name
+        ))
+    })
+}
+
+/// Get union information for a standard library type
+///
+/// This is the union counterpart of `mine_stdlib_struct_info` and supports the
+/// same exact-path and std:: alias lookups.
+///
+/// # Returns
+///
+/// * `Ok(UnionInfo)` - Detailed information about the union including fields
+/// * `Err(QuarryError::TypeNotFound)` - If no union with that name exists
+pub(crate) fn mine_stdlib_union_info(name: &str) -> Result<UnionInfo> {
+    debug!("Mining stdlib union info for: '{}'", name);
+
+    let found = with_stdlib_types(&QuarryConfig::default(), |stdlib_types| {
+        let unions = |path: &str| stdlib_types.unions.get(path);
+        lookup_with_alias(unions, stdlib_types, name).map(|(info, alias)| {
+            let mut info = info.clone();
+            if alias {
+                info.name = name.to_string();
+                (info.module_path, info.simple_name) = crate::split_path(name);
+            }
+            info
+        })
+    })?;
+
+    found.ok_or_else(|| {
+        debug!("No union found for '{}'", name);
+        QuarryError::TypeNotFound(format!(
+            "Union '{}' not found. Please provide the full module path (e.g., 'core::mem::maybe_uninit::MaybeUninit')",
+            name
+        ))
+    })
+}
+
+/// Look up a type by exact path, falling back to std:: alias resolution
+///
+/// `get` looks up one kind of type by exact path. Returns the matching entry
+/// together with a flag indicating whether it was found through an alias rather
+/// than an exact match.
+fn lookup_with_alias<T>(
+    get: impl Fn(&str) -> Option<T>,
+    stdlib_types: &StdlibTypes,
+    name: &str,
+) -> Option<(T, bool)> {
+    // Try exact match first
+    debug!("Looking for exact match for: '{}'", name);
+    if let Some(info) = get(name) {
+        debug!("Found exact match for: '{}'", name);
+        return Some((info, false));
+    }
+
+    // Try alias resolution
+    debug!(
+        "No exact match found, trying alias resolution for: '{}'",
+        name
+    );
+    let actual_path = resolve_std_alias(name, stdlib_types)?;
+    debug!("Resolved '{}' to actual path: '{}'", name, actual_path);
+    match get(&actual_path) {
+        Some(info) => {
+            debug!("Found type via alias resolution: '{}'", name);
+            Some((info, true))
+        }
+        None => {
+            debug!(
+                "Alias resolved but actual type not found: '{}'",
+                actual_path
+            );
+            None
+        }
+    }
+}
+
+/// Resolve a std:: path to the path its type is defined under
+///
+/// The re-export table is derived from the `use` items of the parsed crates
+/// (see `collect_reexports`). A path can reach its definition through several
+/// re-exports, e.g. `std::collections::HashMap` is re-exported from the
+/// `hash_map` module, which glob-imports the private `hash::map` module, so
+/// re-exports are followed for up to `MAX_REEXPORT_HOPS` steps until a path that
+/// is present in the cache is reached.
+///
+/// # Examples
+///
+/// - `std::string::String` → `alloc::string::String` (through `pub use alloc_crate::string;`)
+/// - `std::vec::Vec` → `alloc::vec::Vec`
+/// - `std::collections::HashMap` → `std::collections::hash::map::HashMap`
+///
+/// # Arguments
+///
+/// * `name` - The std:: path to resolve
+/// * `stdlib_types` - The cached types, including the derived re-export table
+///
+/// # Returns
+///
+/// * `Some(String)` - The path of a cached type the name re-exports
+/// * `None` - If the name does not lead to any cached type
+fn resolve_std_alias(name: &str, stdlib_types: &StdlibTypes) -> Option<String> {
+    debug!("Resolving std alias for: '{}'", name);
+
+    let mut visited = vec![name.to_string()];
+    let mut frontier = vec![name.to_string()];
+
+    for _ in 0..MAX_REEXPORT_HOPS {
+        let mut next = Vec::new();
+        for path in &frontier {
+            for candidate in reexport_candidates(path, stdlib_types) {
+                if stdlib_types.contains_path(&candidate) {
+                    debug!("Resolved std alias '{}' -> '{}'", name, candidate);
+                    return Some(candidate);
+                }
+                if !visited.contains(&candidate) {
+                    visited.push(candidate.clone());
+                    next.push(candidate);
+                }
+            }
+        }
+        if next.is_empty() {
+            break;
+        }
+        frontier = next;
+    }
+
+    debug!("No alias mapping found for: '{}'", name);
+    None
+}
+
+/// Resolve a std:: path through the cached re-export table
+///
+/// Initializes the cache for the default configuration if needed. A failure to
+/// initialize is logged and reported as no alias.
+pub(crate) fn resolve_alias(name: &str) -> Option<String> {
+    with_stdlib_types(&QuarryConfig::default(), |stdlib_types| {
+        resolve_std_alias(name, stdlib_types)
+    })
+    .unwrap_or_else(|e| {
+        debug!("Failed to initialize cache for alias resolution: {:?}", e);
+        None
+    })
+}
+
+/// Find the preferred std:: path for a type's defining path
+///
+/// This inverts `resolve_std_alias` using the same re-export table, so the
+/// result resolves back to `name`. Initializes the cache for the default
+/// configuration if needed.
+///
+/// # Returns
+///
+/// `None` if `name` is not the defining path of a cached type, no re-export of
+/// it exists, or the cache could not be initialized
+pub(crate) fn canonical_to_std_alias(name: &str) -> Option<String> {
+    with_stdlib_types(&QuarryConfig::default(), |stdlib_types| {
+        stdlib_types
+            .std_aliases()
+            .get(name)
+            .and_then(|paths| paths.first())
+            .cloned()
+    })
+    .unwrap_or_else(|e| {
+        debug!(
+            "Failed to initialize cache for reverse alias lookup: {:?}",
+            e
+        );
+        None
+    })
+}
+
+/// Find every re-export path that resolves to a type's defining path
+///
+/// The multi-valued form of `canonical_to_std_alias`. Candidates come from the
+/// same reverse map, and each is kept only if `resolve_std_alias` leads it back
+/// to `canonical`, so every returned path is accepted by the lookups.
+/// Initializes the cache for the default configuration if needed.
+///
+/// # Returns
+///
+/// The paths in order of preference (`std::` first, then shortest, then
+/// alphabetical); empty if `canonical` is not re-exported, is not the defining
+/// path of a cached type, or the cache could not be initialized
+pub(crate) fn aliases_for(canonical: &str) -> Vec<String> {
+    let aliases = with_stdlib_types(&QuarryConfig::default(), |stdlib_types| {
+        stdlib_types
+            .std_aliases()
+            .get(canonical)
+            .into_iter()
+            .flatten()
+            .filter(|path| resolve_std_alias(path, stdlib_types).as_deref() == Some(canonical))
+            .cloned()
+            .collect::<Vec<_>>()
+    })
+    .unwrap_or_else(|e| {
+        debug!(
+            "Failed to initialize cache for reverse alias lookup: {:?}",
+            e
+        );
+        Vec::new()
+    });
+
+    debug!("Found {} aliases for '{}'", aliases.len(), canonical);
+    aliases
+}
+
+/// Build the map from each cached type's defining path to its re-export paths
+///
+/// Re-exports are followed backwards from every cached path: a re-export of the
+/// path itself or of one of its parent modules yields another public path for
+/// the type, which may in turn be re-exported. The public paths found are
+/// ordered by preference: those under `std::` first, then the shortest, then
+/// alphabetically, so the order does not depend on hash order. Types without
+/// any re-export are left out.
+fn build_std_aliases(stdlib_types: &StdlibTypes) -> HashMap<String, Vec<String>> {
+    // Index every re-export by the path it points at
+    let mut by_target: HashMap<&str, Vec<&str>> = HashMap::new();
+    for (alias, target) in &stdlib_types.reexports {
+        by_target.entry(target).or_default().push(alias);
+    }
+    for (module_path, source) in &stdlib_types.glob_reexports {
+        by_target.entry(source).or_default().push(module_path);
+    }
+    for (alias, target) in STD_ALIAS_OVERRIDES {
+        by_target.entry(target).or_default().push(alias);
+    }
+
+    let canonical_paths = stdlib_types
+        .structs
+        .keys()
+        .chain(stdlib_types.enums.keys())
+        .chain(stdlib_types.unions.keys())
+        .chain(stdlib_types.aliases.keys());
+
+    let mut std_aliases = HashMap::new();
+    for canonical in canonical_paths {
+        let mut public_paths = vec![canonical.clone()];
+        let mut frontier = vec![canonical.clone()];
+
+        for _ in 0..MAX_REEXPORT_HOPS {
+            let mut next = Vec::new();
+            for path in &frontier {
+                let mut prefix = path.as_str();
+                loop {
+                    for alias in by_target.get(prefix).into_iter().flatten() {
+                        let candidate = format!("{}{}", alias, &path[prefix.len()..]);
+                        if !public_paths.contains(&candidate) {
+                            public_paths.push(candidate.clone());
+                            next.push(candidate);
+                        }
+                    }
+                    match prefix.rsplit_once("::") {
+                        Some((parent, _)) => prefix = parent,
+                        None => break,
+                    }
+                }
+            }
+            if next.is_empty() {
+                break;
+            }
+            frontier = next;
+        }
+
+        let mut aliases = public_paths.split_off(1);
+        if !aliases.is_empty() {
+            aliases.sort_by_cached_key(|path| {
+                (
+                    !path.starts_with("std::"),
+                    path.matches("::").count(),
+                    path.clone(),
+                )
+            });
+            std_aliases.insert(canonical.clone(), aliases);
+        }
+    }
+
+    debug!("Built reverse aliases for {} types", std_aliases.len());
+    std_aliases
+}
+
+/// The paths one re-export step away from `name`
+///
+/// An override pins a single result. Otherwise the longest prefix of `name`
+/// that is itself re-exported is rewritten (so `std::option::Option` follows
+/// `pub use core::option;`), and every glob re-export of an enclosing module
+/// contributes a candidate.
+fn reexport_candidates(name: &str, stdlib_types: &StdlibTypes) -> Vec<String> {
+    if let Some((_, target)) = STD_ALIAS_OVERRIDES.iter().find(|(alias, _)| *alias == name) {
+        return vec![target.to_string()];
+    }
+
+    let mut candidates = Vec::new();
+
+    let mut prefix = name;
+    loop {
+        if let Some(target) = stdlib_types.reexports.get(prefix) {
+            candidates.push(format!("{}{}", target, &name[prefix.len()..]));
+            break;
+        }
+        match prefix.rsplit_once("::") {
+            Some((parent, _)) => prefix = parent,
+            None => break,
+        }
+    }
+
+    for (module_path, source) in &stdlib_types.glob_reexports {
+        if let Some(rest) = name
+            .strip_prefix(module_path.as_str())
+            .and_then(|rest| rest.strip_prefix("::"))
+        {
+            candidates.push(format!("{}::{}", source, rest));
+        }
+    }
+
+    candidates
+}
+
+/// Get a list of all available standard library struct types
+///
+/// Returns a sorted list of all struct types found in the std, alloc, and core crates.
+/// All names are returned with their full module paths for use with `mine_stdlib_struct_info`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use quarry::list_stdlib_structs;
+///
+/// let structs = list_stdlib_structs()?;
+/// for struct_name in structs {
+///     println!("{}", struct_name);
+///     // Example output:
+///     // alloc::string::String
+///     // alloc::vec::Vec
+///     // std::collections::HashMap
+///     // core::option::Option
+/// }
+/// # Ok::<(), quarry::QuarryError>(())
+/// ```
+///
+/// # Returns
+///
+/// * `Ok(Vec<String>)` - Sorted list of all available struct types with full paths
+/// * `Err(QuarryError)` - If the standard library cache cannot be initialized
+///
+/// # Performance
+///
+/// This function may take some time on first call as it initializes the cache by
+/// parsing rustdoc JSON from the standard library. Subsequent calls are fast.
+pub(crate) fn list_stdlib_structs() -> Result<Vec<String>> {
+    debug!("Listing all stdlib structs");
+
+    let mut names: Vec<String> = with_stdlib_types(&QuarryConfig::default(), |stdlib_types| {
+        stdlib_types.structs.keys().cloned().collect()
+    })?;
+    names.sort();
+
+    debug!("Found {} stdlib struct names", names.len());
+    Ok(names)
+}
+
+/// Get every cached struct, sorted as `order` describes
+///
+/// # Returns
+///
+/// * `Ok(Vec<StructInfo>)` - All cached structs; ties are broken by full path
+/// * `Err(QuarryError)` - If the standard library cache cannot be initialized
+pub(crate) fn list_stdlib_structs_sorted(order: SortOrder) -> Result<Vec<StructInfo>> {
+    debug!("Listing all stdlib structs in {:?} order", order);
+
+    let mut structs: Vec<StructInfo> =
+        with_stdlib_types(&QuarryConfig::default(), |stdlib_types| {
+            stdlib_types.structs.cloned_where(|_| true)
+        })??;
+    sort_structs(&mut structs, order);
+
+    debug!("Sorted {} stdlib structs", structs.len());
+    Ok(structs)
+}
+
+/// Sort structs by `order`, falling back to the full path for ties
+fn sort_structs(structs: &mut [StructInfo], order: SortOrder) {
+    fn crate_then_name(info: &StructInfo) -> (&str, &str, &str) {
+        let crate_name = info.name.split("::").next().unwrap_or_default();
+        (crate_name, &info.simple_name, &info.name)
+    }
+
+    match order {
+        SortOrder::ByPath => structs.sort_by(|a, b| a.name.cmp(&b.name)),
+        SortOrder::ByCrateThenName => {
+            structs.sort_by(|a, b| crate_then_name(a).cmp(&crate_then_name(b)))
+        }
+        SortOrder::ByFieldCountDesc => structs.sort_by(|a, b| {
+            b.fields
+                .len()
+                .cmp(&a.fields.len())
+                .then_with(|| a.name.cmp(&b.name))
+        }),
+        SortOrder::BySimpleName => structs.sort_by(|a, b| {
+            a.simple_name
+                .cmp(&b.simple_name)
+                .then_with(|| a.name.cmp(&b.name))
+        }),
+    }
+}
+
+/// List the full paths of cached structs under a module prefix
+///
+/// The prefix is matched on whole path segments, so `"std::collections"` and
+/// `"std::collections::"` are equivalent and neither matches `"std::collectionsx::Foo"`.
+///
+/// # Returns
+///
+/// * `Ok(Vec<String>)` - Sorted paths of matching structs; empty if none match
+/// * `Err(QuarryError)` - If the standard library cache cannot be initialized
+pub(crate) fn list_stdlib_structs_under(module_prefix: &str) -> Result<Vec<String>> {
+    let prefix = format!("{}::", module_prefix.trim_end_matches("::"));
+    debug!("Listing stdlib structs under: '{}'", prefix);
+
+    let mut names: Vec<String> = with_stdlib_types(&QuarryConfig::default(), |stdlib_types| {
+        stdlib_types
+            .structs
+            .keys()
+            .filter(|name| name.starts_with(&prefix))
+            .cloned()
+            .collect()
+    })?;
+    names.sort();
+
+    debug!("Found {} stdlib structs under '{}'", names.len(), prefix);
+    Ok(names)
+}
+
+/// Get every cached struct defined directly in a module
+///
+/// Matches `module_path` exactly, so structs in submodules are not included.
+///
+/// # Returns
+///
+/// * `Ok(Vec<StructInfo>)` - The module's structs, sorted by simple name
+/// * `Err(QuarryError)` - If the standard library cache cannot be initialized
+pub(crate) fn mine_stdlib_module(module_path: &str) -> Result<Vec<StructInfo>> {
+    let module_path = module_path.trim_end_matches("::");
+    debug!("Mining stdlib module: '{}'", module_path);
+
+    let mut structs: Vec<StructInfo> =
+        with_stdlib_types(&QuarryConfig::default(), |stdlib_types| {
+            stdlib_types
+                .structs
+                .cloned_where(|info| info.module_path == module_path)
+        })??;
+    structs.sort_by(|a, b| a.simple_name.cmp(&b.simple_name));
+
+    debug!(
+        "Found {} stdlib structs in module '{}'",
+        structs.len(),
+        module_path
+    );
+    Ok(structs)
+}
+
+/// Group the cached struct paths by their crate
+///
+/// The crate is the first segment of each path (`std`, `alloc`, `core`, ...).
+///
+/// # Returns
+///
+/// * `Ok(HashMap<String, Vec<String>>)` - Sorted struct paths for each crate
+/// * `Err(QuarryError)` - If the standard library cache cannot be initialized
+pub(crate) fn stdlib_structs_by_crate() -> Result<HashMap<String, Vec<String>>> {
+    debug!("Grouping stdlib structs by crate");
+
+    let mut groups: HashMap<String, Vec<String>> =
+        with_stdlib_types(&QuarryConfig::default(), |stdlib_types| {
+            let mut groups: HashMap<String, Vec<String>> = HashMap::new();
+            for name in stdlib_types.structs.keys() {
+                let crate_name = name.split("::").next().unwrap_or(name);
+                groups
+                    .entry(crate_name.to_string())
+                    .or_default()
+                    .push(name.clone());
+            }
+            groups
+        })?;
+    for names in groups.values_mut() {
+        names.sort();
+    }
+
+    debug!("Grouped stdlib structs into {} crates", groups.len());
+    Ok(groups)
+}
+
+/// Group the cached struct paths by their module
+///
+/// The module is everything before the last `::` of each path, so structs in
+/// submodules are grouped separately from their parents.
+///
+/// # Returns
+///
+/// * `Ok(BTreeMap<String, Vec<String>>)` - Sorted struct paths for each module
+/// * `Err(QuarryError)` - If the standard library cache cannot be initialized
+pub(crate) fn stdlib_structs_by_module() -> Result<BTreeMap<String, Vec<String>>> {
+    debug!("Grouping stdlib structs by module");
+
+    let mut groups: BTreeMap<String, Vec<String>> =
+        with_stdlib_types(&QuarryConfig::default(), |stdlib_types| {
+            let mut groups: BTreeMap<String, Vec<String>> = BTreeMap::new();
+            for name in stdlib_types.structs.keys() {
+                let module = name.rsplit_once("::").map_or("", |(module, _)| module);
+                groups
+                    .entry(module.to_string())
+                    .or_default()
+                    .push(name.clone());
+            }
+            groups
+        })?;
+    for names in groups.values_mut() {
+        names.sort();
+    }
+
+    debug!("Grouped stdlib structs into {} modules", groups.len());
+    Ok(groups)
+}
+
+/// How many entries [`stdlib_cache_statistics`] keeps in `most_common_field_types`
+const MOST_COMMON_FIELD_TYPES: usize = 10;
+
+/// Aggregate counts over every cached struct
+///
+/// # Returns
+///
+/// A [`CacheStatistics`] describing struct kinds, field counts and the most
+/// common field types. Ties in field type frequency are broken by name so the
+/// result is deterministic.
+pub(crate) fn stdlib_cache_statistics() -> Result<CacheStatistics> {
+    debug!("Computing stdlib cache statistics");
+
+    let stats = with_stdlib_types(&QuarryConfig::default(), |stdlib_types| -> Result<_> {
+        let mut stats = CacheStatistics::default();
+        let mut total_fields = 0;
+        let mut type_counts: HashMap<Arc<str>, usize> = HashMap::new();
+        let mut largest: Option<(usize, &String)> = None;
+
+        for entry in stdlib_types.structs.iter() {
+            let (name, info) = entry?;
+            stats.total_structs += 1;
+            if info.is_unit_struct {
+                stats.unit_structs += 1;
+            } else if info.is_tuple_struct {
+                stats.tuple_structs += 1;
+            } else {
+                stats.named_structs += 1;
+            }
+
+            total_fields += info.fields.len();
+            for field in &info.fields {
+                *type_counts.entry(Arc::clone(&field.type_name)).or_default() += 1;
+            }
+
+            // Prefer the alphabetically first path among equally large structs
+            let len = info.fields.len();
+            if largest.is_none_or(|(max, max_name)| len > max || (len == max && name < max_name)) {
+                largest = Some((len, name));
+            }
+        }
+        if let Some((max_fields, name)) = largest {
+            stats.max_fields = max_fields;
+            stats.largest_struct = Some(name.clone());
+        }
+
+        if stats.total_structs > 0 {
+            stats.average_fields = total_fields as f64 / stats.total_structs as f64;
+        }
+
+        let mut type_counts: Vec<(Arc<str>, usize)> = type_counts.into_iter().collect();
+        type_counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        stats.most_common_field_types = type_counts
+            .into_iter()
+            .take(MOST_COMMON_FIELD_TYPES)
+            .map(|(type_name, count)| (type_name.to_string(), count))
+            .collect();
+
+        Ok(stats)
+    })??;
+
+    debug!(
+        "Computed statistics over {} stdlib structs ({} fields on average)",
+        stats.total_structs, stats.average_fields
+    );
+    Ok(stats)
+}
+
+/// The number of cached structs, initializing the cache if needed
+pub(crate) fn stdlib_type_count() -> Result<usize> {
+    let count = with_stdlib_types(&QuarryConfig::default(), |stdlib_types| {
+        stdlib_types.structs.len()
+    })?;
+
+    debug!("Counted {} stdlib structs", count);
+    Ok(count)
+}
+
+/// Invoke a callback for every cached struct
+///
+/// Walks the cache in place, so neither a name list nor per-type lookups are
+/// needed. The cache lock is held for the whole walk.
+///
+/// # Returns
+///
+/// * `Ok(())` - After the callback has seen every cached struct
+/// * `Err(QuarryError)` - If the standard library cache cannot be initialized,
+///   or a spilled struct cannot be read back; the callback may have seen some
+///   structs by then
+pub(crate) fn for_each_stdlib_struct(mut f: impl FnMut(&StructInfo)) -> Result<()> {
+    debug!("Walking all cached stdlib structs");
+
+    let count = with_stdlib_types(&QuarryConfig::default(), |stdlib_types| {
+        for info in stdlib_types.structs.values() {
+            let info = info?;
+            f(&info);
+        }
+        Ok::<_, std::io::Error>(stdlib_types.structs.len())
+    })??;
+
+    debug!("Visited {} stdlib structs", count);
+    Ok(())
+}
+
+/// Get every cached struct that satisfies a predicate
+///
+/// The predicate sees the cached entries by reference; only matches are cloned.
+/// The cache lock is held while it runs.
+///
+/// # Returns
+///
+/// * `Ok(Vec<StructInfo>)` - The matching structs, sorted by full path
+/// * `Err(QuarryError)` - If the standard library cache cannot be initialized
+pub(crate) fn list_stdlib_structs_where(
+    pred: impl Fn(&StructInfo) -> bool,
+) -> Result<Vec<StructInfo>> {
+    debug!("Listing stdlib structs matching a predicate");
+
+    let mut matches: Vec<StructInfo> =
+        with_stdlib_types(&QuarryConfig::default(), |stdlib_types| {
+            stdlib_types.structs.cloned_where(pred)
+        })??;
+    matches.sort_by(|a, b| a.name.cmp(&b.name));
+
+    debug!(
+        "Found {} stdlib structs matching the predicate",
+        matches.len()
+    );
+    Ok(matches)
+}
+
+/// Get every cached struct with the given simple name
 ///
-/// # Examples
+/// Initializes the cache for the default configuration if needed. A failure to
+/// initialize or to read a spilled struct is logged and reported as no matches.
 ///
-/// ```
-/// use quarry::mine_stdlib_struct_info;
+/// # Returns
 ///
-/// // ✅ Both of these work - std:: alias and exact path
-/// let string_info1 = mine_stdlib_struct_info("std::string::String")?;
-/// let string_info2 = mine_stdlib_struct_info("alloc::string::String")?;
-/// // Both return the same information
+/// Owned copies of the matching structs, sorted by full path
+pub(crate) fn mine_stdlib_structs_by_simple_name(simple_name: &str) -> Vec<StructInfo> {
+    debug!("Mining stdlib structs with simple name: '{}'", simple_name);
+
+    let found = with_stdlib_types(&QuarryConfig::default(), |stdlib_types| {
+        stdlib_types
+            .structs
+            .cloned_where(|info| info.simple_name == simple_name)
+    })
+    .and_then(|found| found.map_err(QuarryError::Io));
+
+    let mut matches = found.unwrap_or_else(|e| {
+        debug!("Failed to read the cache for simple name lookup: {:?}", e);
+        Vec::new()
+    });
+    matches.sort_by(|a, b| a.name.cmp(&b.name));
+
+    debug!("Found {} structs named '{}'", matches.len(), simple_name);
+    matches
+}
+
+/// Get the cached struct paths that a bare or partial path could refer to
 ///
-/// let vec_info = mine_stdlib_struct_info("std::vec::Vec")?;
-/// let hashmap_info = mine_stdlib_struct_info("std::collections::HashMap")?;
-/// ```
+/// A path matches if `partial` appears in it as a whole run of segments, so
+/// `"HashMap"` and `"map::HashMap"` match `std::collections::hash::map::HashMap`
+/// but `"Map"` does not. The preferred re-export path of each struct (e.g.,
+/// `std::collections::HashMap`) is matched as well, while the defining path is
+/// what gets returned.
 ///
-/// # Arguments
+/// # Returns
 ///
-/// * `name` - The full module path or std:: alias (e.g., "std::string::String")
+/// * `Ok(Vec<String>)` - Sorted defining paths of the matching structs
+/// * `Err(QuarryError)` - If the standard library cache cannot be initialized
+pub(crate) fn candidate_paths(partial: &str) -> Result<Vec<String>> {
+    let partial = partial.trim_matches(':');
+    debug!("Finding candidate paths for: '{}'", partial);
+
+    let needle = format!("::{}::", partial);
+    let contains_segments = |path: &str| format!("::{}::", path).contains(&needle);
+
+    let mut candidates: Vec<String> =
+        with_stdlib_types(&QuarryConfig::default(), |stdlib_types| {
+            // Only paths are compared, so spilled structs are never read back
+            let std_aliases = stdlib_types.std_aliases();
+            stdlib_types
+                .structs
+                .keys()
+                .filter(|name| {
+                    name.rsplit("::").next() == Some(partial)
+                        || contains_segments(name)
+                        || std_aliases
+                            .get(*name)
+                            .and_then(|paths| paths.first())
+                            .is_some_and(|alias| contains_segments(alias))
+                })
+                .cloned()
+                .collect()
+        })?;
+    candidates.sort();
+
+    debug!(
+        "Found {} candidate paths for '{}'",
+        candidates.len(),
+        partial
+    );
+    Ok(candidates)
+}
+
+/// Search cached struct names, ranking exact, then prefix, then substring matches
 ///
 /// # Returns
 ///
-/// * `Ok(StructInfo)` - Detailed information about the struct including fields
-/// * `Err(QuarryError::TypeNotFound)` - If the type name is not found
+/// * `Ok(Vec<String>)` - Full paths of the matches, best rank first and
+///   sorted by path within a rank
+/// * `Err(QuarryError)` - If the standard library cache cannot be initialized
+pub(crate) fn search_stdlib_structs(query: &str, opts: SearchOptions) -> Result<Vec<String>> {
+    debug!("Searching stdlib structs for '{}' with {:?}", query, opts);
+
+    let fold = |text: &str| {
+        if opts.case_sensitive {
+            text.to_string()
+        } else {
+            text.to_lowercase()
+        }
+    };
+    let query = fold(query);
+
+    let mut ranked: Vec<(u8, String)> =
+        with_stdlib_types(&QuarryConfig::default(), |stdlib_types| {
+            stdlib_types
+                .structs
+                .keys()
+                .filter_map(|name| {
+                    let simple = fold(name.rsplit("::").next().unwrap_or(name));
+                    let target = if opts.match_full_path {
+                        fold(name)
+                    } else {
+                        simple.clone()
+                    };
+                    let rank = if simple == query || target == query {
+                        0
+                    } else if target.starts_with(&query) {
+                        1
+                    } else if target.contains(&query) {
+                        2
+                    } else {
+                        return None;
+                    };
+                    Some((rank, name.clone()))
+                })
+                .collect()
+        })?;
+    ranked.sort();
+
+    debug!("Found {} structs matching '{}'", ranked.len(), query);
+    Ok(ranked.into_iter().map(|(_, name)| name).collect())
+}
+
+/// Get the cached structs with a field whose `type_name` contains a substring
 ///
-/// # Cache Behavior
+/// # Returns
 ///
-/// The function uses a global cache that is initialized on first use. The cache
-/// contains structs from the std, alloc, and core crates with their exact paths
-/// as keys.
-pub(crate) fn mine_stdlib_struct_info(name: &str) -> Result<StructInfo> {
-    debug!("Mining stdlib struct info for: '{}'", name);
+/// * `Ok(Vec<String>)` - Sorted full paths of the matching structs
+/// * `Err(QuarryError)` - If the standard library cache cannot be initialized
+pub(crate) fn structs_with_field_type(type_substring: &str) -> Result<Vec<String>> {
+    debug!(
+        "Finding stdlib structs with a field type containing '{}'",
+        type_substring
+    );
 
-    // Get or initialize the cache
-    let cache = STDLIB_CACHE.get_or_init(|| Mutex::new(None));
-    let mut cache_guard = cache.lock().unwrap();
-
-    // Initialize the cache if it's empty
-    if cache_guard.is_none() {
-        debug!("Cache not initialized, initializing stdlib types cache");
-        match init_stdlib_types() {
-            Ok(types) => {
-                debug!("Successfully initialized cache with {} types", types.len());
-                *cache_guard = Some(types);
-            }
-            Err(e) => {
-                debug!("Failed to initialize stdlib types cache: {:?}", e);
-                return Err(e);
+    let mut matches: Vec<String> = with_stdlib_types(&QuarryConfig::default(), |stdlib_types| {
+        let mut matches = Vec::new();
+        for info in stdlib_types.structs.values() {
+            let info = info?;
+            if info
+                .fields
+                .iter()
+                .any(|field| field.type_name.contains(type_substring))
+            {
+                matches.push(info.name.clone());
             }
         }
-    } else {
-        debug!("Using existing initialized cache");
-    }
-
-    let stdlib_types = cache_guard.as_ref().unwrap();
+        Ok::<_, std::io::Error>(matches)
+    })??;
+    matches.sort();
 
-    // Try exact match first
-    debug!("Looking for exact match for: '{}'", name);
-    if let Some(info) = stdlib_types.get(name) {
-        debug!("Found exact match for: '{}'", name);
-        return Ok(info.clone());
-    }
+    debug!(
+        "Found {} structs with a field type containing '{}'",
+        matches.len(),
+        type_substring
+    );
+    Ok(matches)
+}
 
-    // Try alias resolution
-    debug!("No exact match found, trying alias resolution for: '{}'", name);
-    if let Some(actual_path) = resolve_std_alias(name) {
-        debug!("Resolved '{}' to actual path: '{}'", name, actual_path);
-        if let Some(info) = stdlib_types.get(&actual_path) {
-            debug!("Found struct via alias resolution: '{}'", name);
-            
-            // Create a new StructInfo with the alias name (what the user requested)
-            // instead of the internal path name
-            let mut aliased_info = info.clone();
-            aliased_info.name = name.to_string();
-            
-            // Update the module path to match the alias
-            if let Some(pos) = name.rfind("::") {
-                aliased_info.module_path = name[..pos].to_string();
+/// Collect the path names mentioned anywhere in a type
+fn collect_type_paths<'a>(ty: &'a Type, paths: &mut Vec<&'a str>) {
+    match ty {
+        Type::Path { name, args } => {
+            paths.push(name);
+            for arg in args {
+                collect_type_paths(arg, paths);
             }
-            
-            // Update the simple name (should be the same, but just to be consistent)
-            if let Some(pos) = name.rfind("::") {
-                aliased_info.simple_name = name[pos + 2..].to_string();
+        }
+        Type::Tuple(elements) => {
+            for element in elements {
+                collect_type_paths(element, paths);
+            }
+        }
+        Type::Reference { inner, .. }
+        | Type::RawPointer { inner, .. }
+        | Type::Slice(inner)
+        | Type::Array { inner, .. } => collect_type_paths(inner, paths),
+        Type::DynTrait { traits, .. } | Type::ImplTrait { traits, .. } => {
+            for bound in traits {
+                collect_type_paths(bound, paths);
             }
-            
-            debug!("Created aliased StructInfo: '{}' -> module: '{}', simple: '{}'", 
-                   aliased_info.name, aliased_info.module_path, aliased_info.simple_name);
-            
-            return Ok(aliased_info);
-        } else {
-            debug!("Alias resolved but actual type not found: '{}'", actual_path);
         }
+        Type::QualifiedPath {
+            self_type, trait_, ..
+        } => {
+            collect_type_paths(self_type, paths);
+            if let Some(trait_) = trait_ {
+                collect_type_paths(trait_, paths);
+            }
+        }
+        Type::Primitive(_) | Type::Generic(_) | Type::Const(_) => {}
     }
-
-    debug!(
-        "No match found for '{}' (tried exact match and alias resolution)",
-        name
-    );
-    Err(QuarryError::TypeNotFound(format!(
-        "Type '{}' not found. Please provide the full module path (e.g., 'std::string::String', 'alloc::string::String')",
-        name
-    )))
 }
 
-/// Resolve std:: aliases to their actual module paths
-///
-/// This function provides comprehensive std:: alias resolution based on the official
-/// Rust documentation from https://doc.rust-lang.org/nightly/std/index.html
-///
-/// # Examples
+/// Resolve a path name as written in a field type to a cached struct path
 ///
-/// - `std::string::String` → `alloc::string::String`
-/// - `std::vec::Vec` → `alloc::vec::Vec`
-/// - `std::boxed::Box` → `alloc::boxed::Box`
+/// `by_simple_name` maps simple names to the cached paths carrying them. The
+/// candidates are those ending in `written`; among several, one in the module
+/// of `owner` wins, then a unique one in the crate of `owner`.
+fn resolve_written_path<'a>(
+    written: &str,
+    owner: &StructInfo,
+    by_simple_name: &HashMap<&str, Vec<&'a str>>,
+) -> Option<&'a str> {
+    // Types named inside macros are written relative to `$crate`
+    let written = written.strip_prefix('$').unwrap_or(written);
+    let written = written.strip_prefix(CRATE_PREFIX).unwrap_or(written);
+    let simple = written.rsplit("::").next().unwrap_or(written);
+    let suffix = format!("::{}", written);
+    let candidates: Vec<&str> = by_simple_name
+        .get(simple)?
+        .iter()
+        .copied()
+        .filter(|path| *path == written || path.ends_with(&suffix))
+        .collect();
+
+    if let [only] = candidates.as_slice() {
+        return Some(only);
+    }
+    let in_module = format!("{}::{}", owner.module_path, simple);
+    if let Some(path) = candidates.iter().find(|path| **path == in_module) {
+        return Some(path);
+    }
+    let owner_crate = owner.name.split("::").next().unwrap_or_default();
+    let mut in_crate = candidates
+        .iter()
+        .filter(|path| path.split("::").next() == Some(owner_crate));
+    match (in_crate.next(), in_crate.next()) {
+        (Some(path), None) => Some(path),
+        _ => None,
+    }
+}
+
+/// The path a field's type is named by, without generic arguments
 ///
-/// # Arguments
+/// Only named types have one; primitives, generic parameters, references,
+/// tuples and the like return `None`. Fields without a structured `ty` fall
+/// back to the part of `type_name` before any `<`.
+fn field_base_path(field: &FieldInfo) -> Option<&str> {
+    match &field.ty {
+        Some(Type::Path { name, .. }) => Some(name),
+        Some(_) => None,
+        None => {
+            let base = field.type_name.split('<').next().unwrap_or_default().trim();
+            let is_path = !base.is_empty()
+                && base
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || "_:$".contains(c));
+            is_path.then_some(base)
+        }
+    }
+}
+
+/// Find the cached struct that the type of `field` (a field of `owner`) names
 ///
-/// * `name` - The std:: path to resolve
+/// A path that is cached as is, or that resolves through a std:: re-export,
+/// is used directly. Otherwise the path is resolved the way rustdoc wrote it,
+/// relative to `owner` (see `resolve_written_path`).
 ///
 /// # Returns
 ///
-/// * `Some(String)` - The actual module path if an alias is found
-/// * `None` - If no alias mapping exists for the given path
-fn resolve_std_alias(name: &str) -> Option<String> {
-    debug!("Resolving std alias for: '{}'", name);
+/// * `Ok(Some(StructInfo))` - The struct the field's type names
+/// * `Ok(None)` - If the base type is not a cached struct
+/// * `Err(QuarryError)` - If the standard library cache cannot be initialized
+pub(crate) fn resolve_field_struct(
+    owner: &StructInfo,
+    field: &FieldInfo,
+) -> Result<Option<StructInfo>> {
+    let Some(written) = field_base_path(field) else {
+        debug!("Field type '{}' has no base path", field.type_name);
+        return Ok(None);
+    };
 
-    let alias = match name {
-        // Module alloc (see https://doc.rust-lang.org/nightly/std/alloc/index.html)
-        "std::alloc::Layout" => Some("core::alloc::layout::Layout"),
-        "std::alloc::LayoutError" => Some("core::alloc::layout::LayoutError"),
-        "std::alloc::System" => Some("std::alloc::System"), // Not aliased
-
-        // Module any (see https://doc.rust-lang.org/nightly/std/any/index.html)
-        "std::any::TypeId" => Some("core::any::TypeId"),
-
-        // Module array (see https://doc.rust-lang.org/nightly/std/array/index.html)
-        "std::array::IntoIter" => Some("core::array::iter::IntoIter"),
-        "std::array::TryFromSliceError" => Some("core::array::TryFromSliceError"),
-
-        // Module ascii (see https://doc.rust-lang.org/nightly/std/ascii/index.html)
-        "std::ascii::EscapeDefault" => Some("core::ascii::EscapeDefault"),
-
-        // Module backtrace (see https://doc.rust-lang.org/nightly/std/backtrace/index.html)
-        "std::backtrace::Backtrace" => Some("std::backtrace::Backtrace"), // Not aliased
-
-        // Module boxed (see https://doc.rust-lang.org/nightly/std/boxed/index.html)
-        "std::boxed::Box" => Some("alloc::boxed::Box"),
-
-        // Module cell (https://doc.rust-lang.org/nightly/std/cell/index.html)
-        "std::cell::BorrowError" => Some("core::cell::BorrowError"),
-        "std::cell::BorrowMutError" => Some("core::cell::BorrowMutError"),
-        "std::cell::Cell" => Some("core::cell::Cell"),
-        "std::cell::LazyCell" => Some("core::cell::lazy::LazyCell"),
-        "std::cell::OnceCell" => Some("core::cell::once::OnceCell"),
-        "std::cell::Ref" => Some("core::cell::Ref"),
-        "std::cell::RefCell" => Some("core::cell::RefCell"),
-        "std::cell::RefMut" => Some("core::cell::RefMut"),
-        "std::cell::UnsafeCell" => Some("core::cell::UnsafeCell"),
-
-        // Module char (see https://doc.rust-lang.org/nightly/std/char/index.html)
-        "std::char::CharTryFromError" => Some("core::char::convert::CharTryFromError"),
-        "std::char::DecodeUtf16" => Some("core::char::decode::DecodeUtf16"),
-        "std::char::DecodeUtf16Error" => Some("core::char::decode::DecodeUtf16Error"),
-        "std::char::EscapeDebug" => Some("core::char::EscapeDebug"),
-        "std::char::EscapeDefault" => Some("core::char::EscapeDefault"),
-        "std::char::EscapeUnicode" => Some("core::char::EscapeUnicode"),
-        "std::char::ParseCharError" => Some("core::char::convert::ParseCharError"),
-        "std::char::ToLowercase" => Some("core::char::ToLowercase"),
-        "std::char::ToUppercase" => Some("core::char::ToUppercase"),
-        "std::char::TryFromCharError" => Some("core::char::TryFromCharError"),
-
-        // Module cmp (see https://doc.rust-lang.org/nightly/std/cmp/index.html)
-        "std::cmp::Reverse" => Some("core::cmp::Reverse"),
-
-        // Module collections (see https://doc.rust-lang.org/nightly/std/collections/index.html)
-        "std::collections::BTreeMap" => Some("alloc::collections::btree::map::BTreeMap"),
-        "std::collections::BTreeSet" => Some("alloc::collections::btree::set::BTreeSet"),
-        "std::collections::BinaryHeap" => Some("alloc::collections::binary_heap::BinaryHeap"),
-        "std::collections::HashMap" => Some("std::collections::hash::map::HashMap"),
-        "std::collections::HashSet" => Some("std::collections::hash::set::HashSet"),
-        "std::collections::LinkedList" => Some("alloc::collections::linked_list::LinkedList"),
-        "std::collections::TryReserveError" => Some("alloc::collections::TryReserveError"),
-        "std::collections::VecDeque" => Some("alloc::collections::vec_deque::VecDeque"),
-
-        // Module ffi (see https://doc.rust-lang.org/nightly/std/ffi/index.html)
-        "std::ffi::CStr" => Some("core::ffi::c_str::CStr"),
-        "std::ffi::CString" => Some("alloc::ffi::c_str::CString"),
-        "std::ffi::FromBytesUntilNulError" => Some("core::ffi::c_str::FromBytesUntilNulError"),
-        "std::ffi::FromVecWithNulError" => Some("alloc::ffi::c_str::FromVecWithNulError"),
-        "std::ffi::IntoStringError" => Some("alloc::ffi::c_str::IntoStringError"),
-        "std::ffi::NulError" => Some("alloc::ffi::c_str::NulError"),
-        "std::ffi::OsStr" => Some("std::ffi::os_str::OsStr"),
-        "std::ffi::OsString" => Some("std::ffi::os_str::OsString"),
-
-        // Module fmt (see https://doc.rust-lang.org/nightly/std/fmt/index.html)
-        "std::fmt::Arguments" => Some("core::fmt::Arguments"),
-        "std::fmt::DebugList" => Some("core::fmt::builder::DebugList"),
-        "std::fmt::DebugMap" => Some("core::fmt::builder::DebugMap"),
-        "std::fmt::DebugSet" => Some("core::fmt::builder::DebugSet"),
-        "std::fmt::DebugStruct" => Some("core::fmt::builder::DebugStruct"),
-        "std::fmt::DebugTuple" => Some("core::fmt::builder::DebugTuple"),
-        "std::fmt::Error" => Some("core::fmt::Error"),
-        "std::fmt::Formatter" => Some("core::fmt::Formatter"),
-
-        // Module fs (see https://doc.rust-lang.org/nightly/std/fs/index.html)
-        "std::fs::DirBuilder" => Some("std::fs::DirBuilder"), // Not aliased
-        "std::fs::DirEntry" => Some("std::fs::DirEntry"), // Not aliased
-        "std::fs::File" => Some("std::fs::File"), // Not aliased
-        "std::fs::FileTimes" => Some("std::fs::FileTimes"), // Not aliased
-        "std::fs::FileType" => Some("std::fs::FileType"), // Not aliased
-        "std::fs::Metadata" => Some("std::fs::Metadata"), // Not aliased
-        "std::fs::OpenOptions" => Some("std::fs::OpenOptions"), // Not aliased
-        "std::fs::Permissions" => Some("std::fs::Permissions"), // Not aliased
-        "std::fs::ReadDir" => Some("std::fs::ReadDir"), // Not aliased
-
-        // Module future (see https://doc.rust-lang.org/nightly/std/future/index.html)
-        "std::future::Pending" => Some("core::future::pending::Pending"),
-        "std::future::PollFn" => Some("core::future::poll_fn::PollFn"),
-        "std::future::Ready" => Some("core::future::ready::Ready"),
-
-        // Module hash (see https://doc.rust-lang.org/nightly/std/hash/index.html)
-        "std::hash::BuildHasherDefault" => Some("core::hash::BuildHasherDefault"),
-        "std::hash::DefaultHasher" => Some("std::hash::random::DefaultHasher"),
-        "std::hash::RandomState" => Some("std::hash::random::RandomState"),
-
-        // Module io (see https://doc.rust-lang.org/nightly/std/io/index.html)
-        "std::io::BufReader" => Some("std::io::buffered::bufreader::BufReader"),
-        "std::io::BufWriter" => Some("std::io::buffered::bufwriter::BufWriter"),
-        "std::io::Bytes" => Some("std::io::Bytes"), // Not aliased
-        "std::io::Chain" => Some("std::io::Chain"), // Not aliased
-        "std::io::Cursor" => Some("std::io::cursor::Cursor"),
-        "std::io::Empty" => Some("std::io::util::Empty"),
-        "std::io::Error" => Some("std::io::error::Error"),
-        "std::io::IntoInnerError" => Some("std::io::buffered::IntoInnerError"),
-        "std::io::IoSlice" => Some("std::io::IoSlice"), // Not aliased
-        "std::io::IoSliceMut" => Some("std::io::IoSliceMut"), // Not aliased
-        "std::io::LineWriter" => Some("std::io::buffered::linewriter::LineWriter"),
-        "std::io::Lines" => Some("std::io::Lines"), // Not aliased
-        "std::io::PipeReader" => Some("std::io::pipe::PipeReader"),
-        "std::io::PipeWriter" => Some("std::io::pipe::PipeWriter"),
-        "std::io::Repeat" => Some("std::io::util::Repeat"),
-        "std::io::Sink" => Some("std::io::util::Sink"),
-        "std::io::Split" => Some("std::io::Split"), // Not aliased
-        "std::io::Stderr" => Some("std::io::stdio::Stderr"),
-        "std::io::StderrLock" => Some("std::io::stdio::StderrLock"),
-        "std::io::Stdin" => Some("std::io::stdio::Stdin"),
-        "std::io::StdinLock" => Some("std::io::stdio::StdinLock"),
-        "std::io::Stdout" => Some("std::io::stdio::Stdout"),
-        "std::io::StdoutLock" => Some("std::io::StdoutLock"),
-        "std::io::Take" => Some("std::io::Take"), // Not aliased
-        "std::io::WriterPanicked" => Some("std::io::buffered::bufwriter::WriterPanicked"),
-
-        // Module iter (see https://doc.rust-lang.org/nightly/std/iter/index.html)
-        "std::iter::Chain" => Some("core::iter::adapters::chain::Chain"),
-        "std::iter::Cloned" => Some("core::iter::adapters::cloned::Cloned"),
-        "std::iter::Copied" => Some("core::iter::adapters::copied::Copied"),
-        "std::iter::Cycle" => Some("core::iter::adapters::cycle::Cycle"),
-        "std::iter::Empty" => Some("core::iter::sources::empty::Empty"),
-        "std::iter::Enumerate" => Some("core::iter::adapters::enumerate::Enumerate"),
-        "std::iter::Filter" => Some("core::iter::adapters::filter::Filter"),
-        "std::iter::FilterMap" => Some("core::iter::adapters::filter_map::FilterMap"),
-        "std::iter::FlatMap" => Some("core::iter::adapters::flatten::FlatMap"),
-        "std::iter::Flatten" => Some("core::iter::adapters::flatten::Flatten"),
-        "std::iter::FromFn" => Some("core::iter::sources::from_fn::FromFn"),
-        "std::iter::Fuse" => Some("core::iter::adapters::fuse::Fuse"),
-        "std::iter::Inspect" => Some("core::iter::adapters::inspect::Inspect"),
-        "std::iter::Map" => Some("core::iter::adapters::map::Map"),
-        "std::iter::MapWhile" => Some("core::iter::adapters::map_while::MapWhile"),
-        "std::iter::Once" => Some("core::iter::sources::once::Once"),
-        "std::iter::OnceWith" => Some("core::iter::sources::once_with::OnceWith"),
-        "std::iter::Peekable" => Some("core::iter::adapters::peekable::Peekable"),
-        "std::iter::Repeat" => Some("core::iter::sources::repeat::Repeat"),
-        "std::iter::RepeatN" => Some("core::iter::sources::repeat_n::RepeatN"),
-        "std::iter::RepeatWith" => Some("core::iter::sources::repeat_with::RepeatWith"),
-        "std::iter::Rev" => Some("core::iter::adapters::rev::Rev"),
-        "std::iter::Scan" => Some("core::iter::adapters::scan::Scan"),
-        "std::iter::Skip" => Some("core::iter::adapters::skip::Skip"),
-        "std::iter::SkipWhile" => Some("core::iter::adapters::skip_while::SkipWhile"),
-        "std::iter::StepBy" => Some("core::iter::adapters::step_by::StepBy"),
-        "std::iter::Successors" => Some("core::iter::sources::successors::Successors"),
-        "std::iter::Take" => Some("core::iter::adapters::take::Take"),
-        "std::iter::TakeWhile" => Some("core::iter::adapters::take_while::TakeWhile"),
-        "std::iter::Zip" => Some("core::iter::adapters::zip::Zip"),
-
-        // Module marker (see https://doc.rust-lang.org/nightly/std/marker/index.html)
-        "std::marker::PhantomData" => Some("core::marker::PhantomData"),
-        "std::marker::PhantomPinned" => Some("core::marker::PhantomPinned"),
-
-        // Module mem (see https://doc.rust-lang.org/nightly/std/mem/index.html)
-        "std::mem::Discriminant" => Some("core::mem::Discriminant"),
-        "std::mem::ManuallyDrop" => Some("core::mem::manually_drop::ManuallyDrop"),
-
-        // Module net (see https://doc.rust-lang.org/nightly/std/net/index.html)
-        "std::net::AddrParseError" => Some("core::net::parser::AddrParseError"),
-        "std::net::Incoming" => Some("std::net::tcp::Incoming"),
-        "std::net::Ipv4Addr" => Some("core::net::ip_addr::Ipv4Addr"),
-        "std::net::Ipv6Addr" => Some("core::net::ip_addr::Ipv6Addr"),
-        "std::net::SocketAddrV4" => Some("core::net::socket_addr::SocketAddrV4"),
-        "std::net::SocketAddrV6" => Some("core::net::socket_addr::SocketAddrV6"),
-        "std::net::TcpListener" => Some("std::net::tcp::TcpListener"),
-        "std::net::TcpStream" => Some("std::net::tcp::TcpStream"),
-        "std::net::UdpSocket" => Some("std::net::udp::UdpSocket"),
-
-        // Module num (see https://doc.rust-lang.org/nightly/std/num/index.html)
-        "std::num::NonZero" => Some("core::num::nonzero::NonZero"),
-        "std::num::ParseFloatError" => Some("core::num::dec2flt::ParseFloatError"),
-        "std::num::ParseIntError" => Some("core::num::error::ParseIntError"),
-        "std::num::Saturating" => Some("core::num::saturating::Saturating"),
-        "std::num::TryFromIntError" => Some("core::num::error::TryFromIntError"),
-        "std::num::Wrapping" => Some("core::num::wrapping::Wrapping"),
-
-        // Module ops (see https://doc.rust-lang.org/nightly/std/ops/index.html)
-        "std::ops::Range" => Some("core::ops::range::Range"),
-        "std::ops::RangeFrom" => Some("core::ops::range::RangeFrom"),
-        "std::ops::RangeFull" => Some("core::ops::range::RangeFull"),
-        "std::ops::RangeInclusive" => Some("core::ops::range::RangeInclusive"),
-        "std::ops::RangeTo" => Some("core::ops::range::RangeTo"),
-        "std::ops::RangeToInclusive" => Some("core::ops::range::RangeToInclusive"),
-
-        // Module option (see https://doc.rust-lang.org/nightly/std/option/index.html)
-        "std::option::IntoIter" => Some("core::option::IntoIter"),
-        "std::option::Iter" => Some("core::option::Iter"),
-        "std::option::IterMut" => Some("core::option::IterMut"),
-
-        // Module fd (see https://doc.rust-lang.org/nightly/std/os/fd/index.html)
-        "std::os::fd::BorrowedFd" => Some("std::os::fd::owned::BorrowedFd"),
-        "std::os::fd::OwnedFd" => Some("std::os::fd::owned::OwnedFd"),
-
-        // Module panic (see https://doc.rust-lang.org/nightly/std/panic/index.html)
-        "std::panic::AssertUnwindSafe" => Some("core::panic::unwind_safe::AssertUnwindSafe"),
-        "std::panic::Location" => Some("core::panic::location::Location"),
-        "std::panic::PanicHookInfo" => Some("std::panic::PanicHookInfo"), // Not aliased
-
-        // Module path (see https://doc.rust-lang.org/nightly/std/path/index.html)
-        "std::path::Ancestors" => Some("std::path::Ancestors"), // Not aliased
-        "std::path::Components" => Some("std::path::Components"), // Not aliased
-        "std::path::Display" => Some("std::path::Display"), // Not aliased
-        "std::path::Iter" => Some("std::path::Iter"), // Not aliased
-        "std::path::Path" => Some("std::path::Path"), // Not aliased
-        "std::path::PathBuf" => Some("std::path::PathBuf"), // Not aliased
-        "std::path::PrefixComponent" => Some("std::path::PrefixComponent"), // Not aliased
-        "std::path::StripPrefixError" => Some("std::path::StripPrefixError"), // Not aliased
-
-        // Module pin (see https://doc.rust-lang.org/nightly/std/pin/index.html)
-        "std::pin::Pin" => Some("core::pin::Pin"),
-
-        // Module process (see https://doc.rust-lang.org/nightly/std/process/index.html)
-        "std::process::Child" => Some("std::process::Child"), // Not aliased
-        "std::process::ChildStderr" => Some("std::process::ChildStderr"), // Not aliased
-        "std::process::ChildStdin" => Some("std::process::ChildStdin"), // Not aliased
-        "std::process::ChildStdout" => Some("std::process::ChildStdout"), // Not aliased
-        "std::process::Command" => Some("std::process::Command"), // Not aliased
-        "std::process::CommandArgs" => Some("std::process::CommandArgs"), // Not aliased
-        "std::process::CommandEnvs" => Some("std::process::CommandEnvs"), // Not aliased
-        "std::process::ExitCode" => Some("std::process::ExitCode"), // Not aliased
-        "std::process::ExitStatus" => Some("std::process::ExitStatus"), // Not aliased
-        "std::process::Output" => Some("std::process::Output"), // Not aliased
-        "std::process::Stdio" => Some("std::process::Stdio"), // Not aliased
-
-        // Module ptr (see https://doc.rust-lang.org/nightly/std/ptr/index.html)
-        "std::ptr::NonNull" => Some("core::ptr::non_null::NonNull"),
-
-        // Module rc (see https://doc.rust-lang.org/nightly/std/rc/index.html)
-        "std::rc::Rc" => Some("alloc::rc::Rc"),
-        "std::rc::Weak" => Some("alloc::rc::Weak"),
-
-        // Module result (see https://doc.rust-lang.org/nightly/std/result/index.html)
-        "std::result::IntoIter" => Some("core::result::IntoIter"),
-        "std::result::Iter" => Some("core::result::Iter"),
-        "std::result::IterMut" => Some("core::result::IterMut"),
-
-        // Module slice (see https://doc.rust-lang.org/nightly/std/slice/index.html)
-        "std::slice::ChunkBy" => Some("core::slice::iter::ChunkBy"),
-        "std::slice::ChunkByMut" => Some("core::slice::iter::ChunkByMut"),
-        "std::slice::Chunks" => Some("core::slice::iter::Chunks"),
-        "std::slice::ChunksExact" => Some("core::slice::iter::ChunksExact"),
-        "std::slice::ChunksExactMut" => Some("core::slice::iter::ChunksExactMut"),
-        "std::slice::ChunksMut" => Some("core::slice::iter::ChunksMut"),
-        "std::slice::EscapeAscii" => Some("core::slice::ascii::EscapeAscii"),
-        "std::slice::Iter" => Some("core::slice::iter::Iter"),
-        "std::slice::IterMut" => Some("core::slice::iter::IterMut"),
-        "std::slice::RChunks" => Some("core::slice::iter::RChunks"),
-        "std::slice::RChunksExact" => Some("core::slice::iter::RChunksExact"),
-        "std::slice::RChunksExactMut" => Some("core::slice::iter::RChunksExactMut"),
-        "std::slice::RChunksMut" => Some("core::slice::iter::RChunksMut"),
-        "std::slice::RSplit" => Some("core::slice::iter::RSplit"),
-        "std::slice::RSplitMut" => Some("core::slice::iter::RSplitMut"),
-        "std::slice::RSplitN" => Some("core::slice::iter::RSplitN"),
-        "std::slice::RSplitNMut" => Some("core::slice::iter::RSplitNMut"),
-        "std::slice::Split" => Some("core::slice::iter::Split"),
-        "std::slice::SplitInclusive" => Some("core::slice::iter::SplitInclusive"),
-        "std::slice::SplitInclusiveMut" => Some("core::slice::iter::SplitInclusiveMut"),
-        "std::slice::SplitMut" => Some("core::slice::iter::SplitMut"),
-        "std::slice::SplitN" => Some("core::slice::iter::SplitN"),
-        "std::slice::SplitNMut" => Some("core::slice::iter::SplitNMut"),
-        "std::slice::Windows" => Some("core::slice::iter::Windows"),
-
-        // Module str (see https://doc.rust-lang.org/nightly/std/str/index.html)
-        "std::str::Bytes" => Some("core::str::iter::Bytes"),
-        "std::str::CharIndices" => Some("core::str::iter::CharIndices"),
-        "std::str::Chars" => Some("core::str::iter::Chars"),
-        "std::str::EncodeUtf16" => Some("core::str::iter::EncodeUtf16"),
-        "std::str::EscapeDebug" => Some("core::str::iter::EscapeDebug"),
-        "std::str::EscapeDefault" => Some("core::str::iter::EscapeDefault"),
-        "std::str::EscapeUnicode" => Some("core::str::iter::EscapeUnicode"),
-        "std::str::Lines" => Some("core::str::iter::Lines"),
-        "std::str::MatchIndices" => Some("core::str::iter::MatchIndices"),
-        "std::str::Matches" => Some("core::str::iter::Matches"),
-        "std::str::ParseBoolError" => Some("core::str::error::ParseBoolError"),
-        "std::str::RMatchesIndices" => Some("core::str::iter::RMatchesIndices"),
-        "std::str::RMatches" => Some("core::str::iter::RMatches"),
-        "std::str::RSplit" => Some("core::str::iter::RSplit"),
-        "std::str::RSplitN" => Some("core::str::iter::RSplitN"),
-        "std::str::RSplitTerminator" => Some("core::str::iter::RSplitTerminator"),
-        "std::str::Split" => Some("core::str::iter::Split"),
-        "std::str::SplitAsciiWhitespace" => Some("core::str::iter::SplitAsciiWhitespace"),
-        "std::str::SplitInclusive" => Some("core::str::iter::SplitInclusive"),
-        "std::str::SplitN" => Some("core::str::iter::SplitN"),
-        "std::str::SplitTerminator" => Some("core::str::iter::SplitTerminator"),
-        "std::str::SplitWhitespace" => Some("core::str::iter::SplitWhitespace"),
-        "std::str::Utf8Chunk" => Some("core::str::lossy::Utf8Chunk"),
-        "std::str::Utf8Chunks" => Some("core::str::lossy::Utf8Chunks"),
-        "std::str::Utf8Error" => Some("core::str::error::Utf8Error"),
-
-        // Module string (see https://doc.rust-lang.org/nightly/std/string/index.html)
-        "std::string::Drain" => Some("alloc::string::Drain"),
-        "std::string::FromUtf8Error" => Some("alloc::string::FromUtf8Error"),
-        "std::string::FromUtf16Error" => Some("alloc::string::FromUtf16Error"),
-        "std::string::String" => Some("alloc::string::String"),
-
-        // Module sync (see https://doc.rust-lang.org/nightly/std/sync/index.html)
-        "std::sync::Arc" => Some("alloc::sync::Arc"),
-        "std::sync::Barrier" => Some("std::sync::Barrier"), // Not aliased
-        "std::sync::BarrierWaitResult" => Some("std::sync::BarrierWaitResult"), // Not aliased
-        "std::sync::Condvar" => Some("std::sync::poison::condvar::Condvar"),
-        "std::sync::LazyLock" => Some("std::sync::lazy_lock::LazyLock"),
-        "std::sync::Mutex" => Some("std::sync::poison::mutex::Mutex"),
-        "std::sync::MutexGuard" => Some("std::sync::poison::mutex::MutexGuard"),
-        "std::sync::Once" => Some("std::sync::poison::once::Once"),
-        "std::sync::OnceLock" => Some("std::sync::once_lock::OnceLock"),
-        "std::sync::OnceState" => Some("std::sync::poison::once::OnceState"),
-        "std::sync::PoisonError" => Some("std::sync::poison::PoisonError"),
-        "std::sync::RwLock" => Some("std::sync::poison::rwlock::RwLock"),
-        "std::sync::RwLockReadGuard" => Some("std::sync::poison::rwlock::RwLockReadGuard"),
-        "std::sync::RwLockWriteGuard" => Some("std::sync::poison::rwlock::RwLockWriteGuard"),
-        "std::sync::WaitTimeoutResult" => Some("std::sync::poison::condvar::WaitTimeoutResult"),
-        "std::sync::Weak" => Some("alloc::sync::Weak"),
-
-        // Module task (see https://doc.rust-lang.org/nightly/std/task/index.html)
-        "std::task::RawWakerVTable" => Some("core::task::wake::RawWakerVTable"),
-        "std::task::Waker" => Some("core::task::wake::Waker"),
-        "std::task::Context" => Some("core::task::wake::Context"),
-        "std::task::RawWaker" => Some("core::task::wake::RawWaker"),
-
-        // Module thread (see https://doc.rust-lang.org/nightly/std/thread/index.html)
-        "std::thread::AccessError" => Some("std::thread::local::AccessError"),
-        "std::thread::Builder" => Some("std::thread::Builder"), // Not aliased
-        "std::thread::JoinHandle" => Some("std::thread::JoinHandle"), // Not aliased
-        "std::thread::LocalKey" => Some("std::thread::local::LocalKey"),
-        "std::thread::Scope" => Some("std::thread::scoped::Scope"),
-        "std::thread::ScopedJoinHandle" => Some("std::thread::scoped::ScopedJoinHandle"),
-        "std::thread::Thread" => Some("std::thread::Thread"), // Not aliased
-        "std::thread::ThreadId" => Some("std::thread::ThreadId"), // Not aliased
-
-        // Module time (see https://doc.rust-lang.org/nightly/std/time/index.html)
-        "std::time::Duration" => Some("core::time::Duration"),
-        "std::time::Instant" => Some("std::time::Instant"), // Not aliased
-        "std::time::SystemTime" => Some("std::time::SystemTime"), // Not aliased
-        "std::time::SystemTimeError" => Some("std::time::SystemTimeError"), // Not aliased
-        "std::time::TryFromFloatSecsError" => Some("core::time::TryFromFloatSecsError"),
-
-        // Module vec (see https://doc.rust-lang.org/nightly/std/vec/index.html)
-        "std::vec::Drain" => Some("alloc::vec::Drain"),
-        "std::vec::ExtractIf" => Some("alloc::vec::ExtractIf"),
-        "std::vec::IntoIter" => Some("alloc::vec::IntoIter"),
-        "std::vec::Splice" => Some("alloc::vec::Splice"),
-        "std::vec::Vec" => Some("alloc::vec::Vec"),
+    with_stdlib_types(&QuarryConfig::default(), |stdlib_types| {
+        let structs = &stdlib_types.structs;
+        if let Some((info, _)) =
+            lookup_with_alias(|path| structs.get(path).transpose(), stdlib_types, written)
+        {
+            return info.map(|info| Some(Arc::unwrap_or_clone(info)));
+        }
 
-        _ => None,
-    };
-    
-    if let Some(resolved) = alias {
-        debug!("Resolved '{}' to '{}'", name, resolved);
-        Some(resolved.to_string())
-    } else {
-        debug!("No alias found for '{}'", name);
-        None
+        let simple = written.rsplit("::").next().unwrap_or(written);
+        let suffix = format!("::{}", simple);
+        let same_name: Vec<&str> = structs
+            .keys()
+            .filter(|path| path.ends_with(&suffix))
+            .map(String::as_str)
+            .collect();
+        let by_simple_name = HashMap::from([(simple, same_name)]);
+        let Some(path) = resolve_written_path(written, owner, &by_simple_name) else {
+            return Ok(None);
+        };
+        debug!("Resolved field type '{}' to '{}'", written, path);
+        Ok(structs.get(path)?.map(Arc::unwrap_or_clone))
+    })?
+    .map_err(QuarryError::Io)
+}
+
+/// Group the cached struct paths by simple name, as `resolve_written_path` expects
+fn struct_paths_by_simple_name(stdlib_types: &StdlibTypes) -> HashMap<&str, Vec<&str>> {
+    let mut by_simple_name: HashMap<&str, Vec<&str>> = HashMap::new();
+    for name in stdlib_types.structs.keys() {
+        let simple = name
+            .rsplit_once("::")
+            .map_or(name.as_str(), |(_, simple)| simple);
+        by_simple_name
+            .entry(simple)
+            .or_default()
+            .push(name.as_str());
     }
+    by_simple_name
 }
 
-/// Get a list of all available standard library struct types
+/// The cached structs mentioned anywhere in the field types of `info`
 ///
-/// Returns a sorted list of all struct types found in the std, alloc, and core crates.
-/// All names are returned with their full module paths for use with `mine_stdlib_struct_info`.
+/// A struct mentioned by several fields is listed once per mention. Fields
+/// without a structured `ty` are skipped.
+fn referenced_struct_paths<'a>(
+    info: &StructInfo,
+    by_simple_name: &HashMap<&str, Vec<&'a str>>,
+) -> Vec<&'a str> {
+    let mut written = Vec::new();
+    for ty in info.fields.iter().filter_map(|field| field.ty.as_ref()) {
+        collect_type_paths(ty, &mut written);
+    }
+    written
+        .into_iter()
+        .filter_map(|name| resolve_written_path(name, info, by_simple_name))
+        .collect()
+}
+
+/// Whether `info` transitively references itself through its field types
 ///
-/// # Examples
+/// Follows the same edges as `build_dependency_graph`, but only from `info`
+/// and without building the whole graph. Each struct is expanded once, so
+/// cycles that do not lead back to `info` cannot make the walk loop.
 ///
-/// ```
-/// use quarry::list_stdlib_structs;
+/// # Returns
 ///
-/// let structs = list_stdlib_structs()?;
-/// for struct_name in structs {
-///     println!("{}", struct_name);
-///     // Example output:
-///     // alloc::string::String
-///     // alloc::vec::Vec
-///     // std::collections::HashMap
-///     // core::option::Option
-/// }
-/// ```
+/// * `Ok(bool)` - Whether a chain of field references leads back to `info`
+/// * `Err(QuarryError)` - If the standard library cache cannot be initialized
+pub(crate) fn is_recursive_struct(info: &StructInfo) -> Result<bool> {
+    with_stdlib_types(&QuarryConfig::default(), |stdlib_types| {
+        let by_simple_name = struct_paths_by_simple_name(stdlib_types);
+        let start = info.canonical_name.as_str();
+
+        // The cached entry resolves paths relative to the defining module even
+        // when `info` was looked up through an alias
+        let mut pending = match stdlib_types.structs.get(start)? {
+            Some(cached) => referenced_struct_paths(&cached, &by_simple_name),
+            None => referenced_struct_paths(info, &by_simple_name),
+        };
+        let mut visited: HashSet<&str> = HashSet::new();
+        while let Some(path) = pending.pop() {
+            if path == start {
+                debug!("'{}' references itself", start);
+                return Ok(true);
+            }
+            if visited.insert(path)
+                && let Some(next) = stdlib_types.structs.get(path)?
+            {
+                pending.extend(referenced_struct_paths(&next, &by_simple_name));
+            }
+        }
+        Ok(false)
+    })?
+    .map_err(QuarryError::Io)
+}
+
+/// Build the graph of references between cached structs
 ///
 /// # Returns
 ///
-/// * `Ok(Vec<String>)` - Sorted list of all available struct types with full paths
+/// * `Ok(DependencyGraph)` - Every cached struct as a node, with its edges
 /// * `Err(QuarryError)` - If the standard library cache cannot be initialized
+pub(crate) fn build_dependency_graph() -> Result<DependencyGraph> {
+    debug!("Building stdlib struct dependency graph");
+
+    let graph = with_stdlib_types(&QuarryConfig::default(), |stdlib_types| {
+        let by_simple_name = struct_paths_by_simple_name(stdlib_types);
+
+        let mut graph = DependencyGraph::default();
+        for info in stdlib_types.structs.values() {
+            let info = info?;
+            graph.add_node(&info.name);
+            for target in referenced_struct_paths(&info, &by_simple_name) {
+                graph.add_edge(&info.name, target);
+            }
+        }
+        Ok::<_, std::io::Error>(graph)
+    })??;
+
+    debug!(
+        "Built dependency graph with {} nodes and {} edges",
+        graph.nodes().count(),
+        graph.edges().count()
+    );
+    Ok(graph)
+}
+
+/// Turn a struct path into an identifier usable in DOT and Mermaid diagrams
 ///
-/// # Performance
+/// Both formats only accept letters, digits and underscores in bare
+/// identifiers, so `::` becomes `__` and anything else becomes `_`.
+pub(crate) fn diagram_node_id(name: &str) -> String {
+    name.replace("::", "__")
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Render structs as a Mermaid `classDiagram`
 ///
-/// This function may take some time on first call as it initializes the cache by
-/// parsing rustdoc JSON from the standard library. Subsequent calls are fast.
-pub(crate) fn list_stdlib_structs() -> Result<Vec<String>> {
-    debug!("Listing all stdlib structs");
+/// Each struct becomes a class labelled with its simple name, listing its
+/// fields as `+name : Type` (public) or `-name : Type` (anything else). Field
+/// types mentioning another struct of the slice add a composition arrow,
+/// resolved the same way as [`build_dependency_graph`] resolves edges. Mermaid
+/// writes generics as `Vec~T~`, so angle brackets in field types are
+/// rewritten to `~`.
+pub(crate) fn render_mermaid(structs: &[StructInfo]) -> String {
+    debug!(
+        "Rendering {} structs as a Mermaid class diagram",
+        structs.len()
+    );
 
-    let cache = STDLIB_CACHE.get_or_init(|| Mutex::new(None));
-    let mut cache_guard = cache.lock().unwrap();
+    let mut by_simple_name: HashMap<&str, Vec<&str>> = HashMap::new();
+    for info in structs {
+        by_simple_name
+            .entry(info.simple_name.as_str())
+            .or_default()
+            .push(info.name.as_str());
+    }
 
-    // Initialize the cache if it's empty
-    if cache_guard.is_none() {
-        debug!("Cache not initialized, initializing for struct listing");
-        match init_stdlib_types() {
-            Ok(types) => {
-                debug!("Initialized cache with {} types for listing", types.len());
-                *cache_guard = Some(types);
+    let mut mermaid = String::from("classDiagram\n");
+    let mut arrows = BTreeSet::new();
+    for info in structs {
+        let id = diagram_node_id(&info.name);
+        let label = info.simple_name.replace('"', "#quot;");
+        if info.fields.is_empty() {
+            mermaid.push_str(&format!("    class {}[\"{}\"]\n", id, label));
+        } else {
+            mermaid.push_str(&format!("    class {}[\"{}\"] {{\n", id, label));
+            for field in &info.fields {
+                let marker = if field.is_public() { '+' } else { '-' };
+                let ty = field.type_name.replace(['<', '>'], "~");
+                mermaid.push_str(&format!("        {}{} : {}\n", marker, field.name, ty));
             }
-            Err(e) => {
-                debug!("Failed to initialize cache for listing: {:?}", e);
-                return Err(e);
+            mermaid.push_str("    }\n");
+        }
+
+        let mut written = Vec::new();
+        for ty in info.fields.iter().filter_map(|field| field.ty.as_ref()) {
+            collect_type_paths(ty, &mut written);
+        }
+        for name in written {
+            if let Some(target) = resolve_written_path(name, info, &by_simple_name) {
+                arrows.insert((id.clone(), diagram_node_id(target)));
             }
         }
     }
+    for (from, to) in &arrows {
+        mermaid.push_str(&format!("    {} *-- {}\n", from, to));
+    }
 
-    let stdlib_types = cache_guard.as_ref().unwrap();
-    let mut names: Vec<String> = stdlib_types.keys().cloned().collect();
-    names.sort();
-
-    debug!("Found {} stdlib struct names", names.len());
-    Ok(names)
+    debug!("Rendered {} composition arrows", arrows.len());
+    mermaid
 }
 
 /// Check if a type name refers to a standard library struct
@@ -1591,7 +5123,7 @@ pub(crate) fn list_stdlib_structs() -> Result<Vec<String>> {
 ///
 /// # Examples
 ///
-/// ```
+/// ```no_run
 /// use quarry::is_stdlib_struct;
 ///
 /// // ✅ These will return true (if std lib is available)
@@ -1615,16 +5147,35 @@ pub(crate) fn list_stdlib_structs() -> Result<Vec<String>> {
 /// * `false` - If the struct is not found or cache initialization fails
 pub(crate) fn is_stdlib_struct(name: &str) -> bool {
     debug!("Checking if '{}' is a stdlib struct", name);
-    let result = mine_stdlib_struct_info(name).is_ok();
+    let result = mine_stdlib_struct_info(name, &QuarryConfig::default()).is_ok();
     debug!("Result for '{}': {}", name, result);
     result
 }
 
+/// Bound the cached structs to `capacity`, or lift the bound with `None`
+///
+/// Applies to the current cache right away and to every cache built later.
+/// The setting is left unchanged if the current cache cannot be re-bounded.
+pub(crate) fn set_struct_cache_capacity(capacity: Option<usize>) -> Result<()> {
+    debug!("Setting struct cache capacity to {:?}", capacity);
+    let cache = STDLIB_CACHE.get_or_init(|| RwLock::new(None));
+    let mut cache_guard = cache.write().unwrap();
+    if let Some(loaded) = cache_guard.as_mut() {
+        loaded
+            .types
+            .structs
+            .set_capacity(capacity)
+            .map_err(QuarryError::Io)?;
+    }
+    *STRUCT_CACHE_CAPACITY.lock().unwrap() = capacity;
+    Ok(())
+}
+
 /// Clear the stdlib cache (useful for testing or if you want to refresh)
 pub(crate) fn clear_cache() {
     debug!("Clearing stdlib cache");
     if let Some(cache) = STDLIB_CACHE.get() {
-        let mut cache_guard = cache.lock().unwrap();
+        let mut cache_guard = cache.write().unwrap();
         *cache_guard = None;
         debug!("Stdlib cache cleared successfully");
     } else {
@@ -1632,16 +5183,27 @@ pub(crate) fn clear_cache() {
     }
 }
 
+/// The rustdoc JSON format version of the cached types
+///
+/// Only initializes the cache if it is empty; pending crates are not parsed,
+/// since they come from the same toolchain.
+pub(crate) fn rustdoc_format_version() -> Result<Option<u32>> {
+    debug!("Getting rustdoc JSON format version of the cache");
+    with_stdlib_crates(&QuarryConfig::default(), &[], |stdlib_types| {
+        stdlib_types.format_version
+    })
+}
+
 /// Get cache statistics
 pub(crate) fn cache_stats() -> Result<(usize, bool)> {
     debug!("Getting cache statistics");
-    let cache = STDLIB_CACHE.get_or_init(|| Mutex::new(None));
-    let cache_guard = cache.lock().unwrap();
+    let cache = STDLIB_CACHE.get_or_init(|| RwLock::new(None));
+    let cache_guard = cache.read().unwrap();
 
     let stats = match cache_guard.as_ref() {
-        Some(types) => {
-            debug!("Cache is initialized with {} types", types.len());
-            (types.len(), true)
+        Some(loaded) => {
+            debug!("Cache is initialized with {} types", loaded.types.len());
+            (loaded.types.len(), true)
         }
         None => {
             debug!("Cache is not initialized");
@@ -1651,3 +5213,421 @@ pub(crate) fn cache_stats() -> Result<(usize, bool)> {
 
     Ok(stats)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn string_definition() -> StructInfo {
+        let mut info = StructInfo::new("alloc::string::String");
+        info.fields.push(FieldInfo {
+            name: "vec".to_string(),
+            type_name: "Vec<u8>".into(),
+            ty: None,
+            visibility: Visibility::Private,
+            struct_name: "String".to_string(),
+            docs: None,
+            stability: None,
+            deprecation: None,
+            cfg: None,
+        });
+        info
+    }
+
+    fn parsed_crate(crate_name: &str, structs: Vec<StructInfo>) -> StdlibTypes {
+        let mut types = StdlibTypes::default();
+        for info in structs {
+            let is_definition = defines_path(crate_name, &info.name);
+            insert_struct_with_full_name(&mut types, Arc::new(info), is_definition).unwrap();
+        }
+        types
+    }
+
+    #[test]
+    fn definition_survives_reexported_copy() {
+        // Whichever order the crates are merged in, std's field-less copy of
+        // alloc::string::String must not replace alloc's definition
+        for std_first in [true, false] {
+            let std_types = parsed_crate("std", vec![StructInfo::new("alloc::string::String")]);
+            let alloc_types = parsed_crate("alloc", vec![string_definition()]);
+
+            let mut all_types = StdlibTypes::default();
+            if std_first {
+                all_types.merge(std_types).unwrap();
+                all_types.merge(alloc_types).unwrap();
+            } else {
+                all_types.merge(alloc_types).unwrap();
+                all_types.merge(std_types).unwrap();
+            }
+
+            let string = all_types
+                .structs
+                .get("alloc::string::String")
+                .unwrap()
+                .unwrap();
+            assert_eq!(string.fields.len(), 1);
+            assert_eq!(string.fields[0].name, "vec");
+        }
+    }
+
+    #[test]
+    fn module_path_without_library_prefix() {
+        assert_eq!(
+            extract_module_path_from_filename("std/src/string.rs", "std").as_deref(),
+            Some("std::string")
+        );
+    }
+
+    #[test]
+    fn module_path_with_library_prefix() {
+        // Some rust-src layouts record spans relative to the repository root
+        assert_eq!(
+            extract_module_path_from_filename("library/std/src/string.rs", "std").as_deref(),
+            Some("std::string")
+        );
+        assert_eq!(
+            extract_module_path_from_filename("library/alloc/src/vec/mod.rs", "").as_deref(),
+            Some("alloc::vec")
+        );
+        assert_eq!(
+            extract_module_path_from_filename(
+                "/rustc/abc123/library/core/src/ptr/non_null.rs",
+                "core"
+            )
+            .as_deref(),
+            Some("core::ptr::non_null")
+        );
+    }
+
+    #[test]
+    fn module_path_for_proc_macro() {
+        assert_eq!(
+            extract_module_path_from_filename("library/proc_macro/src/lib.rs", "proc_macro")
+                .as_deref(),
+            Some("proc_macro")
+        );
+        assert_eq!(
+            extract_module_path_from_filename("proc_macro/src/bridge/client.rs", "").as_deref(),
+            Some("proc_macro::bridge::client")
+        );
+    }
+
+    #[test]
+    fn module_path_requires_whole_crate_directory() {
+        assert_eq!(
+            extract_module_path_from_filename("my-std/src/lib.rs", ""),
+            None
+        );
+        assert_eq!(
+            extract_module_path_from_filename("library/my-core/src/lib.rs", ""),
+            None
+        );
+    }
+
+    const BASIC_FIXTURE: &str = include_str!("../tests/fixtures/basic.json");
+
+    #[test]
+    fn fixture_plain_struct() {
+        let structs = parse_rustdoc_json_str(BASIC_FIXTURE).unwrap();
+        let plain = &structs["fixture::Plain"];
+        assert!(!plain.is_tuple_struct);
+        assert!(!plain.is_unit_struct);
+        assert_eq!(plain.docs.as_deref(), Some("A struct with named fields"));
+
+        let names: Vec<_> = plain.fields.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, ["name", "count"]);
+        assert_eq!(&*plain.fields[0].type_name, "String");
+        assert_eq!(plain.fields[0].visibility, Visibility::Public);
+        assert_eq!(plain.fields[0].docs.as_deref(), Some("A public field"));
+        assert_eq!(&*plain.fields[1].type_name, "usize");
+        assert!(!plain.fields[1].visibility.is_public());
+    }
+
+    #[test]
+    fn fixture_tuple_struct() {
+        let structs = parse_rustdoc_json_str(BASIC_FIXTURE).unwrap();
+        let pair = &structs["fixture::Pair"];
+        assert!(pair.is_tuple_struct);
+        let fields: Vec<_> = pair
+            .fields
+            .iter()
+            .map(|f| (f.name.as_str(), &*f.type_name))
+            .collect();
+        assert_eq!(fields, [("0", "u8"), ("1", "Vec<u16>")]);
+    }
+
+    #[test]
+    fn fixture_unit_struct() {
+        let structs = parse_rustdoc_json_str(BASIC_FIXTURE).unwrap();
+        let marker = &structs["fixture::Marker"];
+        assert!(marker.is_unit_struct);
+        assert!(marker.fields.is_empty());
+        assert_eq!(structs.len(), 3);
+    }
+
+    #[test]
+    fn sort_orders_break_ties_by_path() {
+        let mut structs: Vec<StructInfo> = parse_rustdoc_json_str(BASIC_FIXTURE)
+            .unwrap()
+            .into_values()
+            .collect();
+        structs.push(StructInfo::new("alloc::fixture::Plain"));
+        let names = |structs: &[StructInfo]| -> Vec<String> {
+            structs.iter().map(|info| info.name.clone()).collect()
+        };
+
+        sort_structs(&mut structs, SortOrder::ByCrateThenName);
+        assert_eq!(
+            names(&structs),
+            [
+                "alloc::fixture::Plain",
+                "fixture::Marker",
+                "fixture::Pair",
+                "fixture::Plain"
+            ]
+        );
+        sort_structs(&mut structs, SortOrder::BySimpleName);
+        assert_eq!(
+            names(&structs),
+            [
+                "fixture::Marker",
+                "fixture::Pair",
+                "alloc::fixture::Plain",
+                "fixture::Plain"
+            ]
+        );
+        sort_structs(&mut structs, SortOrder::ByFieldCountDesc);
+        assert_eq!(
+            names(&structs),
+            [
+                "fixture::Pair",
+                "fixture::Plain",
+                "alloc::fixture::Plain",
+                "fixture::Marker"
+            ]
+        );
+    }
+
+    #[test]
+    fn const_generic_args_are_rendered() {
+        let path: rustdoc_json::Path = serde_json::from_str(
+            r#"{
+                "path": "Simd",
+                "args": { "angle_bracketed": { "args": [
+                    { "type": { "generic": "T" } },
+                    { "const": { "expr": "N", "value": null, "is_literal": false } },
+                    { "const": { "expr": "_", "value": "4usize", "is_literal": false } }
+                ], "constraints": [] } }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(extract_path_name(&path), "Simd<T, N, 4usize>");
+    }
+
+    #[test]
+    fn bounded_store_reads_back_evicted_structs() {
+        let mut structs = parse_rustdoc_json_bytes(BASIC_FIXTURE.as_bytes())
+            .unwrap()
+            .structs;
+        let originals: Vec<(String, Arc<StructInfo>)> = structs
+            .iter()
+            .map(|entry| entry.map(|(name, info)| (name.clone(), info)))
+            .collect::<std::io::Result<_>>()
+            .unwrap();
+
+        structs.set_capacity(Some(1)).unwrap();
+        assert_eq!(structs.len(), 3);
+        // Every lookup after the first evicts the previous struct
+        for _ in 0..2 {
+            for (name, original) in &originals {
+                assert_eq!(structs.get(name).unwrap().as_deref(), Some(&**original));
+            }
+        }
+        let reexported = Arc::new(StructInfo::new("fixture::Plain"));
+        structs
+            .insert("fixture::Plain".to_string(), Arc::clone(&reexported))
+            .unwrap();
+        assert_eq!(structs.get("fixture::Plain").unwrap(), Some(reexported));
+
+        structs.set_capacity(None).unwrap();
+        assert_eq!(structs.capacity(), None);
+        assert_eq!(structs.values().count(), 3);
+    }
+
+    #[test]
+    fn zero_capacity_store_reads_every_lookup_from_disk() {
+        let mut structs = parse_rustdoc_json_bytes(BASIC_FIXTURE.as_bytes())
+            .unwrap()
+            .structs;
+        structs.set_capacity(Some(0)).unwrap();
+
+        let pair = structs.get("fixture::Pair").unwrap().unwrap();
+        assert_eq!(pair.fields.len(), 2);
+        assert_eq!(structs.get("fixture::Missing").unwrap(), None);
+
+        // Nothing was kept in memory, so losing the file fails the next lookup
+        let spill_file = std::fs::OpenOptions::new()
+            .write(true)
+            .open(structs.spill_path().unwrap())
+            .unwrap();
+        spill_file.set_len(0).unwrap();
+        let err = structs.get("fixture::Pair").unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+        assert!(structs.values().any(|info| info.is_err()));
+
+        // Unbounding reads every struct back, so it fails and keeps the spill
+        assert!(structs.set_capacity(None).is_err());
+        assert_eq!(structs.capacity(), Some(0));
+        assert!(structs.contains_key("fixture::Pair"));
+    }
+
+    #[test]
+    fn bare_name_lists_full_paths() {
+        let types = parse_rustdoc_json_bytes(BASIC_FIXTURE.as_bytes()).unwrap();
+        match find_type_info(&types, "Pair") {
+            Err(QuarryError::Ambiguous {
+                simple_name,
+                candidates,
+            }) => {
+                assert_eq!(simple_name, "Pair");
+                assert_eq!(candidates, ["fixture::Pair"]);
+            }
+            other => panic!(
+                "expected Ambiguous, got {:?}",
+                other.map(|info| info.kind())
+            ),
+        }
+        assert!(matches!(
+            find_type_info(&types, "Missing"),
+            Err(QuarryError::TypeNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn malformed_json_is_reported() {
+        for json in ["{\"format_version\": 57}", "not json"] {
+            match parse_rustdoc_json_str(json) {
+                Err(QuarryError::MalformedRustdocJson(_)) => {}
+                other => panic!(
+                    "expected MalformedRustdocJson, got {:?}",
+                    other.map(|s| s.len())
+                ),
+            }
+        }
+    }
+
+    #[test]
+    fn levenshtein_known_distances() {
+        assert_eq!(levenshtein_distance("", ""), 0);
+        assert_eq!(levenshtein_distance("", "Vec"), 3);
+        assert_eq!(levenshtein_distance("Vec", ""), 3);
+        assert_eq!(levenshtein_distance("Hashmap", "HashMap"), 1);
+        assert_eq!(levenshtein_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn suggestions_are_closest_first_and_capped() {
+        let mut types = StdlibTypes::default();
+        for name in [
+            "fixture::Plain",
+            "fixture::Pair",
+            "fixture::Marker",
+            "fixture::Paid",
+            "fixture::Pain",
+        ] {
+            types
+                .structs
+                .insert(name.to_string(), Arc::new(StructInfo::new(name)))
+                .unwrap();
+        }
+
+        // Paid, Pain and Pair are one edit away; Plain (two edits) is cut by the cap
+        assert_eq!(
+            suggest_similar_paths("fixture::Paix", &types),
+            ["fixture::Paid", "fixture::Pain", "fixture::Pair"]
+        );
+        // Distance ranks before the alphabetical tie-break
+        assert_eq!(
+            suggest_similar_paths("fixture::Plai", &types),
+            ["fixture::Plain", "fixture::Paid", "fixture::Pain"]
+        );
+        assert!(suggest_similar_paths("fixture::Unrelated", &types).is_empty());
+    }
+
+    /// Parse attributes given as rustdoc JSON values and render their cfg
+    fn cfg_of(attrs: serde_json::Value) -> Option<String> {
+        let attrs: Vec<Lenient<rustdoc_json::Attribute>> = serde_json::from_value(attrs).unwrap();
+        parse_cfg(&attrs)
+    }
+
+    #[test]
+    fn cfg_trace_name_value() {
+        let unix = concat!(
+            "#[attr = CfgTrace([",
+            r#"NameValue { name: "unix", value: None, span: src/lib.rs:1:7: 1:11 (#0) }"#,
+            "])]",
+        );
+        assert_eq!(
+            cfg_of(serde_json::json!([{ "other": unix }])).as_deref(),
+            Some("unix")
+        );
+
+        let feature = concat!(
+            "#[attr = CfgTrace([",
+            r#"NameValue { name: "feature", value: Some("small"), "#,
+            "span: src/lib.rs:1:7: 1:24 (#0) }",
+            "])]",
+        );
+        assert_eq!(
+            cfg_of(serde_json::json!([{ "other": feature }])).as_deref(),
+            Some(r#"feature = "small""#)
+        );
+    }
+
+    #[test]
+    fn cfg_trace_nested_combinators() {
+        let trace = concat!(
+            "#[attr = CfgTrace([Not(Any([",
+            r#"NameValue { name: "unix", value: None, span: src/lib.rs:1:15: 1:19 (#0) }, "#,
+            r#"NameValue { name: "target_os", value: Some("wasi"), "#,
+            "span: src/lib.rs:1:21: 1:39 (#0) }",
+            "], src/lib.rs:1:11: 1:40 (#0)), src/lib.rs:1:7: 1:41 (#0))])]",
+        );
+        assert_eq!(
+            cfg_of(serde_json::json!([{ "other": trace }])).as_deref(),
+            Some(r#"not(any(unix, target_os = "wasi"))"#)
+        );
+    }
+
+    #[test]
+    fn cfg_legacy_and_combined_attributes() {
+        assert_eq!(
+            cfg_of(serde_json::json!(["#[cfg(unix)]"])).as_deref(),
+            Some("unix")
+        );
+        assert_eq!(cfg_of(serde_json::json!(["#[inline]"])), None);
+
+        let trace = concat!(
+            "#[attr = CfgTrace([",
+            r#"NameValue { name: "unix", value: None, span: src/lib.rs:1:7: 1:11 (#0) }, "#,
+            "True(src/lib.rs:2:7: 2:11 (#0))])]",
+        );
+        let attrs = serde_json::json!([{ "other": trace }, r#"#[cfg(feature = "std")]"#]);
+        assert_eq!(
+            cfg_of(attrs).as_deref(),
+            Some(r#"all(unix, true, feature = "std")"#)
+        );
+    }
+
+    #[test]
+    fn crate_target_dirs_are_per_manifest() {
+        let manifest = std::path::Path::new("/work/a/Cargo.toml");
+        let target_dir = crate_target_dir(manifest);
+        assert_eq!(target_dir, crate_target_dir(manifest));
+        assert_ne!(
+            target_dir,
+            crate_target_dir(std::path::Path::new("/work/b/Cargo.toml"))
+        );
+        assert!(target_dir.starts_with(std::env::temp_dir().join(CRATE_DOCS_DIR)));
+    }
+}
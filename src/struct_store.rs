@@ -0,0 +1,436 @@
+//! Storage for the cached struct definitions
+//!
+//! By default every parsed `StructInfo` stays in memory for the life of the
+//! cache. When `set_struct_cache_capacity` bounds it, the store writes
+//! the structs to a temporary spill file instead and keeps only the most
+//! recently used ones in memory; any other struct is read back from the file
+//! when it is looked up, and the least recently used one is evicted to make room.
+//!
+//! Only the struct definitions move to disk. Their names, and the enums, unions,
+//! aliases and re-exports of the cache, stay in memory, so listing paths and
+//! resolving aliases never touch the spill file. Reading a struct can fail if
+//! the file was removed or damaged behind the store's back, e.g. by a temp
+//! directory cleaner, so lookups return an `io::Result`.
+
+use crate::StructInfo;
+use log::debug;
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::{BTreeMap, HashMap};
+use std::fs::File;
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Distinguishes the spill files of several stores in one process
+static SPILL_FILE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// Cached structs keyed by full path, either all in memory or spilled to disk
+///
+/// Serializes as a plain map of every struct, so cache files do not depend on
+/// whether the store was bounded when it was written.
+#[derive(Default)]
+pub(crate) struct StructStore {
+    /// Every struct, while the store is unbounded; empty once spilled
+    resident: HashMap<String, Arc<StructInfo>>,
+    /// Every struct, once the store is bounded
+    spill: Option<Spill>,
+}
+
+impl StructStore {
+    /// The number of structs, whether in memory or spilled
+    pub(crate) fn len(&self) -> usize {
+        match &self.spill {
+            Some(spill) => spill.slots.len(),
+            None => self.resident.len(),
+        }
+    }
+
+    /// Whether a struct is stored under exactly this path
+    pub(crate) fn contains_key(&self, name: &str) -> bool {
+        match &self.spill {
+            Some(spill) => spill.slots.contains_key(name),
+            None => self.resident.contains_key(name),
+        }
+    }
+
+    /// Look up a struct by exact path
+    ///
+    /// In a bounded store this marks the struct as most recently used, reading
+    /// it from the spill file and evicting another one if it is not in memory.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the struct is spilled and cannot be read back.
+    pub(crate) fn get(&self, name: &str) -> io::Result<Option<Arc<StructInfo>>> {
+        match &self.spill {
+            Some(spill) => spill.get(name),
+            None => Ok(self.resident.get(name).cloned()),
+        }
+    }
+
+    /// The full paths of every struct, in no particular order
+    pub(crate) fn keys(&self) -> impl Iterator<Item = &String> {
+        self.resident
+            .keys()
+            .chain(self.spill.iter().flat_map(|spill| spill.slots.keys()))
+    }
+
+    /// Every struct, in no particular order
+    ///
+    /// Spilled structs are read one at a time and not kept, so a scan of a
+    /// bounded store neither grows its memory use nor displaces the structs
+    /// that were recently looked up. A struct that cannot be read back is
+    /// yielded as an error.
+    pub(crate) fn values(&self) -> impl Iterator<Item = io::Result<Arc<StructInfo>>> {
+        self.iter().map(|entry| entry.map(|(_, info)| info))
+    }
+
+    /// Every struct together with its full path, in no particular order
+    ///
+    /// Like `values`, a struct that cannot be read back is yielded as an error.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = io::Result<(&String, Arc<StructInfo>)>> {
+        let spilled = self.spill.iter().flat_map(|spill| {
+            spill
+                .slots
+                .iter()
+                .map(move |(name, slot)| Ok((name, spill.peek(name, *slot)?)))
+        });
+        self.resident
+            .iter()
+            .map(|(name, info)| Ok((name, Arc::clone(info))))
+            .chain(spilled)
+    }
+
+    /// Owned copies of the structs that satisfy `pred`, in no particular order
+    ///
+    /// Only the matches are cloned, and spilled structs are read like `values`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a spilled struct cannot be read back.
+    pub(crate) fn cloned_where(
+        &self,
+        mut pred: impl FnMut(&StructInfo) -> bool,
+    ) -> io::Result<Vec<StructInfo>> {
+        let mut matches = Vec::new();
+        for info in self.values() {
+            let info = info?;
+            if pred(&info) {
+                matches.push(Arc::unwrap_or_clone(info));
+            }
+        }
+        Ok(matches)
+    }
+
+    /// Mutable access to the structs held in memory
+    ///
+    /// Covers every struct of an unbounded store and none of a bounded one.
+    pub(crate) fn resident_values_mut(&mut self) -> impl Iterator<Item = &mut Arc<StructInfo>> {
+        self.resident.values_mut()
+    }
+
+    /// Store a struct under its full path, replacing any previous entry
+    ///
+    /// A bounded store appends the struct to its spill file.
+    pub(crate) fn insert(&mut self, name: String, info: Arc<StructInfo>) -> io::Result<()> {
+        match &mut self.spill {
+            Some(spill) => spill.insert(name, &info),
+            None => {
+                self.resident.insert(name, info);
+                Ok(())
+            }
+        }
+    }
+
+    /// Take every struct out of the store, reading spilled ones back into memory
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a spilled struct cannot be read back.
+    pub(crate) fn into_map(mut self) -> io::Result<HashMap<String, Arc<StructInfo>>> {
+        match self.spill.take() {
+            Some(spill) => spill.read_all(),
+            None => Ok(std::mem::take(&mut self.resident)),
+        }
+    }
+
+    /// The spill file of a bounded store
+    #[cfg(test)]
+    pub(crate) fn spill_path(&self) -> Option<&std::path::Path> {
+        self.spill.as_ref().map(|spill| spill.path.as_path())
+    }
+
+    /// How many structs a bounded store keeps in memory, or `None` if unbounded
+    pub(crate) fn capacity(&self) -> Option<usize> {
+        self.spill.as_ref().map(|spill| spill.capacity)
+    }
+
+    /// Bound the store to `capacity` structs in memory, or lift the bound with `None`
+    ///
+    /// Bounding an unbounded store moves every struct to a new spill file;
+    /// unbounding reads them all back and removes the file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the spill file cannot be created, written or read
+    /// back, in which case the store is left unchanged.
+    pub(crate) fn set_capacity(&mut self, capacity: Option<usize>) -> io::Result<()> {
+        match (capacity, &mut self.spill) {
+            (None, None) => {}
+            (Some(capacity), Some(spill)) => spill.set_capacity(capacity),
+            (Some(capacity), None) => {
+                let mut spill = Spill::create(capacity)?;
+                for (name, info) in &self.resident {
+                    spill.insert(name.clone(), info)?;
+                }
+                debug!(
+                    "Spilled {} structs to {:?}, keeping at most {} in memory",
+                    self.resident.len(),
+                    spill.path,
+                    capacity
+                );
+                self.resident = HashMap::new();
+                self.spill = Some(spill);
+            }
+            (None, Some(spill)) => {
+                self.resident = spill.read_all()?;
+                self.spill = None;
+                debug!("Restored {} spilled structs to memory", self.resident.len());
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for StructStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StructStore")
+            .field("len", &self.len())
+            .field("capacity", &self.capacity())
+            .finish()
+    }
+}
+
+impl Serialize for StructStore {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // The length is passed up front since formats like bincode require it
+        let mut map = serializer.serialize_map(Some(self.len()))?;
+        for entry in self.iter() {
+            let (name, info) = entry.map_err(serde::ser::Error::custom)?;
+            map.serialize_entry(name, &info)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for StructStore {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(StructStore {
+            resident: HashMap::deserialize(deserializer)?,
+            spill: None,
+        })
+    }
+}
+
+/// Where one struct's serialized JSON lives in the spill file
+#[derive(Debug, Clone, Copy)]
+struct Slot {
+    offset: u64,
+    len: usize,
+}
+
+/// The structs of a bounded store: all of them on disk, the recent ones also in memory
+struct Spill {
+    path: PathBuf,
+    /// The spill file, opened for reading and appending
+    file: Mutex<File>,
+    /// Where the next struct is appended
+    end: u64,
+    slots: HashMap<String, Slot>,
+    capacity: usize,
+    recent: Mutex<RecentStructs>,
+}
+
+impl Spill {
+    /// Create an empty spill file in the system temp directory
+    fn create(capacity: usize) -> io::Result<Spill> {
+        let path = std::env::temp_dir().join(format!(
+            "quarry_structs_{}_{}.spill",
+            std::process::id(),
+            SPILL_FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let file = std::fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create_new(true)
+            .open(&path)?;
+        Ok(Spill {
+            path,
+            file: Mutex::new(file),
+            end: 0,
+            slots: HashMap::new(),
+            capacity,
+            recent: Mutex::new(RecentStructs::default()),
+        })
+    }
+
+    /// Append a struct to the file, superseding any earlier copy
+    fn insert(&mut self, name: String, info: &StructInfo) -> io::Result<()> {
+        let bytes = serde_json::to_vec(info).map_err(io::Error::other)?;
+        let mut file = self.file.lock().unwrap();
+        file.seek(SeekFrom::Start(self.end))?;
+        file.write_all(&bytes)?;
+
+        self.recent.get_mut().unwrap().remove(&name);
+        self.slots.insert(
+            name,
+            Slot {
+                offset: self.end,
+                len: bytes.len(),
+            },
+        );
+        self.end += bytes.len() as u64;
+        Ok(())
+    }
+
+    /// Look up a struct, keeping it in memory as the most recently used
+    fn get(&self, name: &str) -> io::Result<Option<Arc<StructInfo>>> {
+        let Some(slot) = self.slots.get(name).copied() else {
+            return Ok(None);
+        };
+        let mut recent = self.recent.lock().unwrap();
+        if let Some(info) = recent.touch(name) {
+            return Ok(Some(info));
+        }
+        drop(recent);
+
+        debug!("Reading evicted struct from spill file: {}", name);
+        let info = self.read(slot)?;
+        self.recent
+            .lock()
+            .unwrap()
+            .insert(name.to_string(), Arc::clone(&info), self.capacity);
+        Ok(Some(info))
+    }
+
+    /// Fetch a struct without changing which ones are kept in memory
+    fn peek(&self, name: &str, slot: Slot) -> io::Result<Arc<StructInfo>> {
+        let recent = self.recent.lock().unwrap();
+        match recent.entries.get(name) {
+            Some((info, _)) => Ok(Arc::clone(info)),
+            None => {
+                drop(recent);
+                self.read(slot)
+            }
+        }
+    }
+
+    /// Read every struct back into memory, leaving the file as it is
+    fn read_all(&self) -> io::Result<HashMap<String, Arc<StructInfo>>> {
+        self.slots
+            .iter()
+            .map(|(name, slot)| Ok((name.clone(), self.peek(name, *slot)?)))
+            .collect()
+    }
+
+    /// Read and deserialize a struct from the spill file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the file if it can no longer be read or no
+    /// longer holds the struct where it was written.
+    fn read(&self, slot: Slot) -> io::Result<Arc<StructInfo>> {
+        let mut bytes = vec![0; slot.len];
+        {
+            let mut file = self.file.lock().unwrap();
+            file.seek(SeekFrom::Start(slot.offset))
+                .and_then(|_| file.read_exact(&mut bytes))
+                .map_err(|e| {
+                    io::Error::new(
+                        e.kind(),
+                        format!("struct spill file {:?} unreadable: {}", self.path, e),
+                    )
+                })?;
+        }
+        let info: StructInfo = serde_json::from_slice(&bytes).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("struct spill file {:?} corrupted: {}", self.path, e),
+            )
+        })?;
+        Ok(Arc::new(info))
+    }
+
+    /// Change how many structs are kept in memory, evicting any excess
+    fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        self.recent.get_mut().unwrap().evict_to(capacity);
+    }
+}
+
+impl Drop for Spill {
+    fn drop(&mut self) {
+        if let Err(e) = std::fs::remove_file(&self.path) {
+            debug!(
+                "Failed to remove struct spill file {:?}: {:?}",
+                self.path, e
+            );
+        }
+    }
+}
+
+/// The structs of a bounded store that are kept in memory, in least recently used order
+#[derive(Default)]
+struct RecentStructs {
+    /// Each struct with the tick of its last use
+    entries: HashMap<String, (Arc<StructInfo>, u64)>,
+    /// Ticks of last use mapped to the struct used then, oldest first
+    order: BTreeMap<u64, String>,
+    /// Incremented on every use
+    clock: u64,
+}
+
+impl RecentStructs {
+    /// Return a struct if it is in memory, marking it as most recently used
+    fn touch(&mut self, name: &str) -> Option<Arc<StructInfo>> {
+        self.clock += 1;
+        let (info, last_used) = self.entries.get_mut(name)?;
+        let key = self
+            .order
+            .remove(last_used)
+            .expect("entry without a use tick");
+        *last_used = self.clock;
+        self.order.insert(self.clock, key);
+        Some(Arc::clone(info))
+    }
+
+    /// Keep a struct in memory as the most recently used, evicting the least
+    /// recently used ones beyond `capacity`
+    fn insert(&mut self, name: String, info: Arc<StructInfo>, capacity: usize) {
+        self.remove(&name);
+        self.clock += 1;
+        self.order.insert(self.clock, name.clone());
+        self.entries.insert(name, (info, self.clock));
+        self.evict_to(capacity);
+    }
+
+    /// Forget a struct, e.g. because a newer copy was spilled
+    fn remove(&mut self, name: &str) {
+        if let Some((_, last_used)) = self.entries.remove(name) {
+            self.order.remove(&last_used);
+        }
+    }
+
+    /// Evict least recently used structs until at most `capacity` remain
+    fn evict_to(&mut self, capacity: usize) {
+        while self.entries.len() > capacity {
+            let Some((_, name)) = self.order.pop_first() else {
+                break;
+            };
+            debug!("Evicting least recently used struct: {}", name);
+            self.entries.remove(&name);
+        }
+    }
+}